@@ -32,6 +32,7 @@ fn get_episode_query(
             episode::Column::Image,
             episode::Column::FileSubPath,
             episode::Column::ImageSubPath,
+            episode::Column::RemovedFromFeedAt,
             episode::Column::Episode,
             episode::Column::Season,
             episode::Column::Kind,
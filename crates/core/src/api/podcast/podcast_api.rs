@@ -48,6 +48,7 @@ fn get_episodes_query(primary_key: u32) -> Selector<SelectModel<EpisodePartial>>
             episode::Column::Kind,
             episode::Column::FileSubPath,
             episode::Column::ImageSubPath,
+            episode::Column::RemovedFromFeedAt,
         ])
         .order_by_asc(episode::Column::PublishedAt)
         .into_model::<EpisodePartial>()
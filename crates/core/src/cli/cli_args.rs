@@ -39,4 +39,18 @@ pub enum CliSubcommand {
     Emulate(PodcastOptions),
     /// Download cover and banner images of a podcast.
     Cover(PodcastOptions),
+    /// Add podcasts from an OPML file.
+    Import(ImportOptions),
+    /// Export podcast subscriptions.
+    Export(ExportOptions),
+    /// Show the fetch history of a podcast.
+    History(HistoryOptions),
+    /// Check a feed against directory requirements without saving it.
+    Validate(ValidateOptions),
+    /// Change the settings of a podcast.
+    Configure(ConfigureOptions),
+    /// Delete downloaded episodes excluded by retention rules.
+    Prune(PruneOptions),
+    /// Move downloaded episodes to the paths of the current path template.
+    Relayout(RelayoutOptions),
 }
@@ -11,6 +11,13 @@ pub struct SubcommandHandler {
     download: Arc<DownloadCliCommand>,
     emulate: Arc<EmulateCliCommand>,
     cover: Arc<CoverCliCommand>,
+    import: Arc<ImportCliCommand>,
+    export: Arc<ExportCliCommand>,
+    history: Arc<HistoryCliCommand>,
+    validate: Arc<ValidateCliCommand>,
+    configure: Arc<ConfigureCliCommand>,
+    prune: Arc<PruneCliCommand>,
+    relayout: Arc<RelayoutCliCommand>,
 }
 
 impl SubcommandHandler {
@@ -33,6 +40,27 @@ impl SubcommandHandler {
             CliSubcommand::Cover(options) => {
                 self.cover.execute(options).await?;
             }
+            CliSubcommand::Import(options) => {
+                self.import.execute(options).await?;
+            }
+            CliSubcommand::Export(options) => {
+                self.export.execute(options).await?;
+            }
+            CliSubcommand::History(options) => {
+                self.history.execute(options).await?;
+            }
+            CliSubcommand::Validate(options) => {
+                self.validate.execute(options).await?;
+            }
+            CliSubcommand::Configure(options) => {
+                self.configure.execute(options).await?;
+            }
+            CliSubcommand::Prune(options) => {
+                self.prune.execute(options).await?;
+            }
+            CliSubcommand::Relayout(options) => {
+                self.relayout.execute(options).await?;
+            }
         }
         Ok(())
     }
@@ -3,7 +3,7 @@ use crate::prelude::*;
 /// Adds a new podcast from an RSS feed.
 ///
 /// - Fetches and parses the RSS feed
-/// - Stores the feed URL for future fetching
+/// - Stores the canonical feed URL and credentials for future fetching
 /// - Saves the podcast and episodes to the database
 #[derive(FromServicesAsync)]
 pub struct AddHandler {
@@ -15,14 +15,16 @@ pub struct AddHandler {
 impl Execute<AddRequest, AddResponse, Report<AddError>> for AddHandler {
     /// Execute the add handler.
     async fn execute(&self, request: &AddRequest) -> Result<AddResponse, Report<AddError>> {
-        trace!(slug = %request.slug, url = %request.feed_url, "Fetching podcast");
+        let (feed_url, url_credentials) = PodcastCredentials::from_url(&request.feed_url);
+        let credentials = request.credentials.clone().or(url_credentials);
+        trace!(slug = %request.slug, url = %feed_url.to_redacted_string(), "Fetching podcast");
         let mut feed = self
             .fetch
-            .fetch_feed(&request.slug, &request.feed_url)
+            .fetch_feed(&request.slug, &feed_url, credentials.as_ref())
             .await
             .change_context(AddError::Parse)?;
         trace!(slug = %request.slug, episodes = feed.episodes.len(), "Fetched feed");
-        feed.podcast.feed_url = Some(request.feed_url.clone());
+        feed.podcast.credentials = credentials;
         let feed = self
             .metadata
             .create_feed(feed)
@@ -38,6 +40,7 @@ impl Execute<AddRequest, AddResponse, Report<AddError>> for AddHandler {
 
 #[cfg(test)]
 mod tests {
+    #![allow(non_snake_case)]
     use super::*;
 
     #[tokio::test]
@@ -52,6 +55,7 @@ mod tests {
         let request = AddRequest {
             slug: MockFeeds::podcast_slug(),
             feed_url: MockServices::rss_url(),
+            credentials: None,
         };
 
         // Act
@@ -61,4 +65,38 @@ mod tests {
         let response = result.assert_ok_debug();
         assert!(response.episode_count > 0);
     }
+
+    #[tokio::test]
+    #[serial]
+    pub async fn add_handler__discovered_feed() {
+        // Arrange
+        let page = "https://example.com/";
+        let html = r#"<html><head><link rel="alternate" type="application/rss+xml" href="/mock-feed.xml"></head></html>"#;
+        let services = MockServices::new()
+            .with_rss_feed()
+            .with_http(|http| http.with_string(page, html.to_owned()))
+            .create()
+            .await;
+        let handler = services
+            .get_async::<AddHandler>()
+            .await
+            .expect("should be able to get handler");
+        let metadata = services
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let request = AddRequest {
+            slug: MockFeeds::podcast_slug(),
+            feed_url: UrlWrapper::from_str(page).expect("URL should parse"),
+            credentials: None,
+        };
+
+        // Act
+        let result = handler.execute(&request).await;
+
+        // Assert
+        result.assert_ok_debug();
+        let feed_url = metadata.get_feed_url(&request.slug).await.assert_ok_debug();
+        assert_eq!(feed_url, Some(Some(MockServices::rss_url())));
+    }
 }
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use std::collections::BTreeMap;
 
 /// CLI options for [`AddCliCommand`].
 #[derive(Clone, Debug, Args)]
@@ -9,13 +10,67 @@ pub struct AddOptions {
     pub slug: Slug,
     /// URL of the RSS feed.
     pub feed_url: UrlWrapper,
+    /// Username for HTTP basic authentication of a private feed.
+    #[arg(long)]
+    pub username: Option<String>,
+    /// Password for HTTP basic authentication of a private feed.
+    #[arg(long, requires = "username")]
+    pub password: Option<String>,
+    /// Bearer token for a private feed.
+    #[arg(long, conflicts_with = "username")]
+    pub bearer_token: Option<String>,
+    /// Additional header sent when requesting the feed and episodes.
+    ///
+    /// Formatted as `Name: value`. Can be repeated.
+    #[arg(long = "header", value_parser = parse_header)]
+    pub headers: Vec<(String, String)>,
+}
+
+impl AddOptions {
+    /// Credentials from the options, if any were set.
+    #[must_use]
+    pub fn get_credentials(&self) -> Option<PodcastCredentials> {
+        let auth = if let Some(token) = &self.bearer_token {
+            Some(PodcastAuth::Bearer {
+                token: token.clone(),
+            })
+        } else {
+            self.username.as_ref().map(|username| PodcastAuth::Basic {
+                username: username.clone(),
+                password: self.password.clone(),
+            })
+        };
+        let credentials = PodcastCredentials {
+            auth,
+            headers: self.headers.iter().cloned().collect::<BTreeMap<_, _>>(),
+        };
+        if credentials.is_empty() {
+            None
+        } else {
+            Some(credentials)
+        }
+    }
 }
 
 impl From<AddOptions> for AddRequest {
     fn from(options: AddOptions) -> Self {
+        let credentials = options.get_credentials();
         Self {
             slug: options.slug,
             feed_url: options.feed_url,
+            credentials,
         }
     }
 }
+
+/// Parse a `Name: value` header.
+fn parse_header(value: &str) -> Result<(String, String), String> {
+    let Some((name, value)) = value.split_once(':') else {
+        return Err("expected `Name: value`".to_owned());
+    };
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("header name is empty".to_owned());
+    }
+    Ok((name.to_owned(), value.trim().to_owned()))
+}
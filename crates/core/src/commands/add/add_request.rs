@@ -1,17 +1,29 @@
 use crate::prelude::*;
 
 /// A request to execute an [`AddHandler`].
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct AddRequest {
     /// User-defined identifier for the podcast.
     pub slug: Slug,
     /// URL of the RSS feed.
+    ///
+    /// A username and password in the URL are moved to `credentials`.
     pub feed_url: UrlWrapper,
+    /// Credentials for a private feed.
+    ///
+    /// Serialized so the web add form can send them to the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<PodcastCredentials>,
 }
 
 impl Display for AddRequest {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "Slug: {} URL: {}", self.slug, self.feed_url)
+        write!(
+            f,
+            "Slug: {} URL: {}",
+            self.slug,
+            self.feed_url.to_redacted_string()
+        )
     }
 }
 
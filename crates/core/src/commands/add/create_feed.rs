@@ -6,6 +6,7 @@ impl MetadataRepository {
     /// Create a new podcast feed.
     ///
     /// - Fails if a podcast with the same slug already exists
+    /// - Gives items that reuse a GUID a unique `source_id`, see [`dedupe_episodes`]
     pub async fn create_feed(&self, feed: PodcastFeed) -> Result<PodcastFeed, Report<CreateError>> {
         trace!(podcast = %feed.podcast.slug, "Inserting new podcast and episodes");
         let tx = self.db.begin().await.change_context(CreateError::Begin)?;
@@ -21,7 +22,11 @@ impl MetadataRepository {
                 Report::new(e).change_context(e2)
             })?;
 
-        let episodes = insert_episodes(feed.episodes, podcast.primary_key)
+        let (episodes, collisions) = dedupe_episodes(feed.episodes);
+        for collision in &collisions {
+            warn!(podcast = %podcast.slug, "{collision}");
+        }
+        let episodes = insert_episodes(episodes, podcast.primary_key)
             .exec_with_returning(&tx)
             .await
             .change_context(CreateError::Episodes)?;
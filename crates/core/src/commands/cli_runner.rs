@@ -41,6 +41,18 @@ impl CliRunner {
         self.runner.start(CONCURRENCY).await;
         self.runner.drain().await;
         self.progress.finish().await;
+        self.take().await
+    }
+
+    /// Take the completed results of a request type.
+    ///
+    /// Includes requests queued by handlers during an earlier [`CliRunner::run`].
+    pub async fn take<R>(&self) -> RunStatus<R>
+    where
+        R: Executable + TryFrom<CommandRequest>,
+        R::Response: TryFrom<CommandSuccess>,
+        R::ExecutionError: TryFrom<CommandFailure>,
+    {
         let succeeded = self.runner.take_succeeded::<R>().await;
         let failed = self.runner.take_failed::<R>().await;
         RunStatus { succeeded, failed }
@@ -0,0 +1,76 @@
+use crate::prelude::*;
+
+/// Queue requests on the shared [`CommandRunner`] from within a handler.
+///
+/// The runner resolves every handler when it is created so a handler can't
+/// depend on it directly. Instead the runner is resolved on first use, by which
+/// time the handlers have been created.
+pub struct CommandQueue {
+    services: ServiceProvider,
+}
+
+impl CommandQueue {
+    /// Queue a request to be executed by the runner's workers.
+    pub async fn queue<R>(&self, request: R) -> Result<(), Report<CommandQueueError>>
+    where
+        R: Executable + Into<CommandRequest> + Send + Sync + 'static,
+    {
+        self.get_runner()
+            .await?
+            .queue_request(request)
+            .await
+            .change_context(CommandQueueError::Queue)
+    }
+
+    /// Take the error of a failed request from the runner.
+    ///
+    /// - Returns `Ok(None)` if the request has not failed
+    /// - Other completed requests are left in the runner
+    pub async fn take_failure<R>(
+        &self,
+        request: &R,
+    ) -> Result<Option<R::ExecutionError>, Report<CommandQueueError>>
+    where
+        R: Executable + Into<CommandRequest>,
+        R::ExecutionError: TryFrom<CommandFailure>,
+    {
+        let runner = self.get_runner().await?;
+        let mut commands = runner.get_commands().await;
+        let key: CommandRequest = request.clone().into();
+        if !matches!(commands.get(&key), Some(CommandStatus::Failed(_))) {
+            return Ok(None);
+        }
+        let Some(CommandStatus::Failed(failure)) = commands.remove(&key) else {
+            return Ok(None);
+        };
+        Ok(R::ExecutionError::try_from(failure).ok())
+    }
+
+    async fn get_runner(
+        &self,
+    ) -> Result<Arc<CommandRunner<CommandInfo>>, Report<CommandQueueError>> {
+        self.services
+            .get_async::<CommandRunner<CommandInfo>>()
+            .await
+            .change_context(CommandQueueError::Resolve)
+    }
+}
+
+impl FromServices for CommandQueue {
+    type Error = Infallible;
+
+    fn from_services(services: &ServiceProvider) -> Result<Self, Report<Infallible>> {
+        Ok(Self {
+            services: services.clone(),
+        })
+    }
+}
+
+/// Errors from [`CommandQueue`].
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum CommandQueueError {
+    #[error("Unable to resolve command runner")]
+    Resolve,
+    #[error("Unable to queue request")]
+    Queue,
+}
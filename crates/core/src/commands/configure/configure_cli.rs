@@ -0,0 +1,73 @@
+use crate::prelude::*;
+use sea_orm::DbErr;
+
+/// CLI command for changing the settings of a podcast.
+#[derive(FromServicesAsync)]
+pub struct ConfigureCliCommand {
+    metadata: Arc<MetadataRepository>,
+}
+
+impl ConfigureCliCommand {
+    /// Update the settings specified by the options.
+    pub async fn execute(&self, options: ConfigureOptions) -> Result<(), Report<ConfigureError>> {
+        let slug = &options.podcast;
+        let mut changed = false;
+        if options.refresh_interval.is_some() || options.default_refresh_interval {
+            let result = self
+                .metadata
+                .set_refresh_interval(slug, options.refresh_interval)
+                .await;
+            check_exists(slug, result)?;
+            changed = true;
+        }
+        if let Some(rules) = options.get_auto_download() {
+            if let Some(rules) = &rules {
+                info!(podcast = %slug, "Auto-download {rules}");
+            } else {
+                info!(podcast = %slug, "Auto-download disabled");
+            }
+            let result = self.metadata.set_auto_download(slug, rules).await;
+            check_exists(slug, result)?;
+            changed = true;
+        }
+        if let Some(rules) = options.get_retention() {
+            if let Some(rules) = &rules {
+                info!(podcast = %slug, "Retention: {rules}");
+            } else {
+                info!(podcast = %slug, "Retention: server default");
+            }
+            let result = self.metadata.set_retention(slug, rules).await;
+            check_exists(slug, result)?;
+            changed = true;
+        }
+        if options.path_template.is_some() || options.default_path_template {
+            if let Some(template) = &options.path_template {
+                info!(podcast = %slug, "Path template: {template}");
+            } else {
+                info!(podcast = %slug, "Path template: server default");
+            }
+            let result = self
+                .metadata
+                .set_path_template(slug, options.path_template.clone())
+                .await;
+            check_exists(slug, result)?;
+            changed = true;
+        }
+        if !changed {
+            return Err(Report::new(ConfigureError::NoSettings));
+        }
+        info!(podcast = %slug, "Updated podcast settings");
+        Ok(())
+    }
+}
+
+/// Fail if the query errored or the podcast does not exist.
+fn check_exists(slug: &Slug, result: Result<bool, DbErr>) -> Result<(), Report<ConfigureError>> {
+    let exists = result
+        .change_context(ConfigureError::Repository)
+        .attach("Podcast", slug.clone())?;
+    if !exists {
+        return Err(Report::new(ConfigureError::NoPodcast).attach("Podcast", slug.clone()));
+    }
+    Ok(())
+}
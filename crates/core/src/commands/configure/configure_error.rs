@@ -0,0 +1,12 @@
+use crate::prelude::*;
+
+/// Errors from [`ConfigureCliCommand`].
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum ConfigureError {
+    #[error("Unable to query database")]
+    Repository,
+    #[error("Podcast does not exist")]
+    NoPodcast,
+    #[error("No settings were specified")]
+    NoSettings,
+}
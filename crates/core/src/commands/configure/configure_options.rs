@@ -0,0 +1,132 @@
+use crate::prelude::*;
+
+/// CLI options for [`ConfigureCliCommand`].
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "each flag is a separate CLI switch"
+)]
+#[derive(Clone, Debug, Args)]
+pub struct ConfigureOptions {
+    /// Slug of the podcast.
+    #[arg(long)]
+    pub podcast: Slug,
+    /// Minutes between scheduled refreshes by the web server.
+    ///
+    /// `0` disables scheduled refresh of the podcast.
+    #[arg(long, conflicts_with = "default_refresh_interval")]
+    pub refresh_interval: Option<u32>,
+    /// Use the default refresh interval of the web server.
+    #[arg(long)]
+    pub default_refresh_interval: bool,
+    /// Download new episodes after each fetch.
+    ///
+    /// Replaces any existing rules with the `--auto-download-*` options.
+    #[arg(long, conflicts_with = "no_auto_download")]
+    pub auto_download: bool,
+    /// Stop downloading new episodes after each fetch.
+    #[arg(long)]
+    pub no_auto_download: bool,
+    /// Only auto-download episodes of this type: full, trailer or bonus.
+    ///
+    /// Can be specified multiple times.
+    #[arg(
+        long = "auto-download-kind",
+        value_name = "KIND",
+        conflicts_with = "no_auto_download"
+    )]
+    pub auto_download_kinds: Vec<EpisodeKind>,
+    /// Only auto-download the most recently published of the new episodes.
+    #[arg(long, value_name = "COUNT", conflicts_with = "no_auto_download")]
+    pub auto_download_latest: Option<u32>,
+    /// Only auto-download episodes in this season or later.
+    #[arg(long, value_name = "SEASON", conflicts_with = "no_auto_download")]
+    pub auto_download_from_season: Option<u32>,
+    /// Keep only this many of the most recently published downloads.
+    ///
+    /// Replaces any existing rules with the `--retention-*` options.
+    #[arg(
+        long,
+        value_name = "COUNT",
+        conflicts_with_all = ["keep_all_downloads", "default_retention"]
+    )]
+    pub retention_keep_latest: Option<u32>,
+    /// Keep only downloads of episodes published within this many days.
+    #[arg(
+        long,
+        value_name = "DAYS",
+        conflicts_with_all = ["keep_all_downloads", "default_retention"]
+    )]
+    pub retention_keep_days: Option<u32>,
+    /// Keep only downloads of episodes that have not been played.
+    #[arg(
+        long,
+        conflicts_with_all = ["keep_all_downloads", "default_retention"]
+    )]
+    pub retention_unplayed_only: bool,
+    /// Maximum total size of the downloads in megabytes.
+    #[arg(
+        long,
+        value_name = "MB",
+        conflicts_with_all = ["keep_all_downloads", "default_retention"]
+    )]
+    pub retention_max_size_mb: Option<u64>,
+    /// Keep every download regardless of the server retention rules.
+    #[arg(long, conflicts_with = "default_retention")]
+    pub keep_all_downloads: bool,
+    /// Use the retention rules of the web server.
+    #[arg(long)]
+    pub default_retention: bool,
+    /// Template for the paths of new downloads.
+    ///
+    /// Placeholders: `{slug}`, `{podcast}`, `{title}`, `{season}`, `{episode}`,
+    /// `{kind}`, `{year}`, `{month}`, `{day}`, `{date}`, `{guid}` and `{stem}`.
+    /// Numbers can be zero padded with a width: `{episode:3}`.
+    #[arg(
+        long,
+        value_name = "TEMPLATE",
+        conflicts_with = "default_path_template"
+    )]
+    pub path_template: Option<PathTemplate>,
+    /// Use the path template of the web server.
+    #[arg(long)]
+    pub default_path_template: bool,
+}
+
+impl ConfigureOptions {
+    /// Get the auto-download rules to store.
+    ///
+    /// - Returns `None` if no auto-download options were specified
+    /// - Returns `Some(None)` to disable auto-download
+    /// - Any `--auto-download-*` option enables auto-download
+    #[must_use]
+    pub fn get_auto_download(&self) -> Option<Option<AutoDownloadRules>> {
+        if self.no_auto_download {
+            return Some(None);
+        }
+        let rules = AutoDownloadRules {
+            kinds: self.auto_download_kinds.clone(),
+            latest: self.auto_download_latest,
+            from_season: self.auto_download_from_season,
+        };
+        (self.auto_download || rules != AutoDownloadRules::default()).then_some(Some(rules))
+    }
+
+    /// Get the retention rules to store.
+    ///
+    /// - Returns `None` if no retention options were specified
+    /// - Returns `Some(None)` to use the server rules
+    /// - Returns empty rules to keep every download
+    #[must_use]
+    pub fn get_retention(&self) -> Option<Option<RetentionRules>> {
+        if self.default_retention {
+            return Some(None);
+        }
+        let rules = RetentionRules {
+            keep_latest: self.retention_keep_latest,
+            keep_days: self.retention_keep_days,
+            unplayed_only: self.retention_unplayed_only,
+            max_size_mb: self.retention_max_size_mb,
+        };
+        (self.keep_all_downloads || !rules.is_empty()).then_some(Some(rules))
+    }
+}
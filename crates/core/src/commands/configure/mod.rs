@@ -0,0 +1,17 @@
+#[cfg(feature = "server")]
+mod configure_cli;
+mod configure_error;
+mod configure_options;
+#[cfg(feature = "server")]
+mod set_auto_download;
+#[cfg(feature = "server")]
+mod set_path_template;
+#[cfg(feature = "server")]
+mod set_refresh_interval;
+#[cfg(feature = "server")]
+mod set_retention;
+
+#[cfg(feature = "server")]
+pub use configure_cli::*;
+pub use configure_error::*;
+pub use configure_options::*;
@@ -0,0 +1,78 @@
+use crate::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+
+impl MetadataRepository {
+    /// Set the auto-download rules of a podcast.
+    ///
+    /// - `None` disables auto-download
+    /// - Returns `Ok(false)` if the podcast does not exist
+    pub(crate) async fn set_auto_download(
+        &self,
+        slug: &Slug,
+        rules: Option<AutoDownloadRules>,
+    ) -> Result<bool, DbErr> {
+        let result = set_auto_download_query(slug, rules).exec(&self.db).await?;
+        Ok(result.rows_affected > 0)
+    }
+}
+
+fn set_auto_download_query(
+    slug: &Slug,
+    rules: Option<AutoDownloadRules>,
+) -> UpdateMany<podcast::Entity> {
+    podcast::Entity::update_many()
+        .col_expr(podcast::Column::AutoDownload, Expr::value(rules))
+        .filter(podcast::Column::Slug.eq(slug.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn _set_auto_download_query() {
+        // Arrange
+        let slug = MockFeeds::podcast_slug();
+        let rules = AutoDownloadRules {
+            kinds: vec![EpisodeKind::Full],
+            latest: Some(1),
+            from_season: None,
+        };
+
+        // Act
+        let statement = set_auto_download_query(&slug, Some(rules)).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[tokio::test]
+    pub async fn set_auto_download() {
+        // Arrange
+        let metadata = MockServices::default()
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let rules = AutoDownloadRules {
+            latest: Some(2),
+            ..AutoDownloadRules::default()
+        };
+
+        // Act
+        let result = metadata
+            .set_auto_download(&MockFeeds::podcast_slug(), Some(rules.clone()))
+            .await;
+
+        // Assert
+        assert!(result.assert_ok_debug());
+        let stored = metadata
+            .get_auto_download_rules(MockFeeds::PODCAST_KEY)
+            .await
+            .assert_ok_debug();
+        assert_eq!(stored, Some(rules));
+    }
+}
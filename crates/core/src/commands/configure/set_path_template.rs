@@ -0,0 +1,76 @@
+use crate::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+
+impl MetadataRepository {
+    /// Set the template for the paths of downloaded episodes of a podcast.
+    ///
+    /// - `None` uses the server template
+    /// - Returns `Ok(false)` if the podcast does not exist
+    pub(crate) async fn set_path_template(
+        &self,
+        slug: &Slug,
+        template: Option<PathTemplate>,
+    ) -> Result<bool, DbErr> {
+        let result = set_path_template_query(slug, template)
+            .exec(&self.db)
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+}
+
+fn set_path_template_query(
+    slug: &Slug,
+    template: Option<PathTemplate>,
+) -> UpdateMany<podcast::Entity> {
+    podcast::Entity::update_many()
+        .col_expr(podcast::Column::PathTemplate, Expr::value(template))
+        .filter(podcast::Column::Slug.eq(slug.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    fn template() -> PathTemplate {
+        PathTemplate::from_str("{podcast}/{year}/{date} - {title}")
+            .expect("should be valid template")
+    }
+
+    #[test]
+    fn _set_path_template_query() {
+        // Arrange
+        let slug = MockFeeds::podcast_slug();
+
+        // Act
+        let statement = set_path_template_query(&slug, Some(template())).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[tokio::test]
+    pub async fn set_path_template() {
+        // Arrange
+        let metadata = MockServices::default()
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let slug = MockFeeds::podcast_slug();
+
+        // Act
+        let result = metadata.set_path_template(&slug, Some(template())).await;
+
+        // Assert
+        assert!(result.assert_ok_debug());
+        let podcast = podcast::Entity::find_by_id(MockFeeds::PODCAST_KEY)
+            .one(&metadata.db)
+            .await
+            .assert_ok_debug()
+            .expect("podcast should exist");
+        assert_eq!(podcast.path_template, Some(template()));
+    }
+}
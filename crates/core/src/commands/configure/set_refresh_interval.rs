@@ -0,0 +1,86 @@
+use crate::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+
+impl MetadataRepository {
+    /// Set the scheduled refresh interval of a podcast.
+    ///
+    /// - `None` uses the default interval
+    /// - Returns `Ok(false)` if the podcast does not exist
+    pub(crate) async fn set_refresh_interval(
+        &self,
+        slug: &Slug,
+        interval: Option<u32>,
+    ) -> Result<bool, DbErr> {
+        let result = set_refresh_interval_query(slug, interval)
+            .exec(&self.db)
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+}
+
+fn set_refresh_interval_query(slug: &Slug, interval: Option<u32>) -> UpdateMany<podcast::Entity> {
+    podcast::Entity::update_many()
+        .col_expr(podcast::Column::RefreshInterval, Expr::value(interval))
+        .filter(podcast::Column::Slug.eq(slug.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn _set_refresh_interval_query() {
+        // Arrange
+        let slug = MockFeeds::podcast_slug();
+
+        // Act
+        let statement = set_refresh_interval_query(&slug, Some(60)).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[tokio::test]
+    pub async fn set_refresh_interval() {
+        // Arrange
+        let metadata = MockServices::default()
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let slug = MockFeeds::podcast_slug();
+
+        // Act
+        let result = metadata.set_refresh_interval(&slug, Some(60)).await;
+
+        // Assert
+        assert!(result.assert_ok_debug());
+        let refreshes = metadata.get_podcast_refreshes().await.assert_ok_debug();
+        let refresh = refreshes
+            .iter()
+            .find(|refresh| refresh.slug == slug)
+            .expect("should have podcast");
+        assert_eq!(refresh.refresh_interval, Some(60));
+    }
+
+    #[tokio::test]
+    pub async fn set_refresh_interval__not_found() {
+        // Arrange
+        let metadata = MockServices::default()
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let slug = Slug::from_str("non-existent").expect("should be valid slug");
+
+        // Act
+        let result = metadata.set_refresh_interval(&slug, None).await;
+
+        // Assert
+        assert!(!result.assert_ok_debug());
+    }
+}
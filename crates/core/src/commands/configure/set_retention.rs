@@ -0,0 +1,71 @@
+use crate::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+
+impl MetadataRepository {
+    /// Set the retention rules of a podcast.
+    ///
+    /// - `None` uses the server rules
+    /// - Returns `Ok(false)` if the podcast does not exist
+    pub(crate) async fn set_retention(
+        &self,
+        slug: &Slug,
+        rules: Option<RetentionRules>,
+    ) -> Result<bool, DbErr> {
+        let result = set_retention_query(slug, rules).exec(&self.db).await?;
+        Ok(result.rows_affected > 0)
+    }
+}
+
+fn set_retention_query(slug: &Slug, rules: Option<RetentionRules>) -> UpdateMany<podcast::Entity> {
+    podcast::Entity::update_many()
+        .col_expr(podcast::Column::Retention, Expr::value(rules))
+        .filter(podcast::Column::Slug.eq(slug.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn _set_retention_query() {
+        // Arrange
+        let slug = MockFeeds::podcast_slug();
+        let rules = RetentionRules {
+            keep_latest: Some(5),
+            unplayed_only: true,
+            ..RetentionRules::default()
+        };
+
+        // Act
+        let statement = set_retention_query(&slug, Some(rules)).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[tokio::test]
+    pub async fn set_retention() {
+        // Arrange
+        let metadata = MockServices::default()
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let slug = MockFeeds::podcast_slug();
+        let rules = RetentionRules {
+            keep_days: Some(30),
+            ..RetentionRules::default()
+        };
+
+        // Act
+        let result = metadata.set_retention(&slug, Some(rules.clone())).await;
+
+        // Assert
+        assert!(result.assert_ok_debug());
+        let stored = metadata.get_retention(&slug).await.assert_ok_debug();
+        assert_eq!(stored, Some((MockFeeds::PODCAST_KEY, Some(rules))));
+    }
+}
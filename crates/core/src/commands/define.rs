@@ -1,15 +1,21 @@
 use crate::prelude::*;
 
 define_commands_web!(
+    Add(AddRequest),
     Cover(CoverRequest),
     Download(DownloadRequest),
     Emulate(EmulateRequest),
     Fetch(FetchRequest),
+    Prune(PruneRequest),
+    Relayout(RelayoutRequest),
 );
 #[cfg(feature = "server")]
 define_commands_server!(
+    Add(AddRequest, AddHandler),
     Cover(CoverRequest, CoverHandler),
     Download(DownloadRequest, DownloadHandler),
     Emulate(EmulateRequest, EmulateHandler),
     Fetch(FetchRequest, FetchHandler),
+    Prune(PruneRequest, PruneHandler),
+    Relayout(RelayoutRequest, RelayoutHandler),
 );
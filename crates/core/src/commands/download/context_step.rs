@@ -2,6 +2,37 @@ use crate::prelude::*;
 use sea_orm::Selector;
 use sea_orm::*;
 
+/// Columns of [`DownloadPodcastPartial`].
+pub(crate) const DOWNLOAD_PODCAST_COLUMNS: [podcast::Column; 8] = [
+    podcast::Column::PrimaryKey,
+    podcast::Column::Slug,
+    podcast::Column::Title,
+    podcast::Column::FeedUrl,
+    podcast::Column::Link,
+    podcast::Column::Categories,
+    podcast::Column::Credentials,
+    podcast::Column::PathTemplate,
+];
+
+/// Columns of [`DownloadEpisodePartial`].
+pub(crate) const DOWNLOAD_EPISODE_COLUMNS: [episode::Column; 15] = [
+    episode::Column::PrimaryKey,
+    episode::Column::Title,
+    episode::Column::SourceId,
+    episode::Column::FileSubPath,
+    episode::Column::ImageSubPath,
+    episode::Column::EnclosureChangedAt,
+    episode::Column::SourceUrl,
+    episode::Column::SourceFileSize,
+    episode::Column::SourceContentType,
+    episode::Column::PublishedAt,
+    episode::Column::Description,
+    episode::Column::Image,
+    episode::Column::Episode,
+    episode::Column::Season,
+    episode::Column::Kind,
+];
+
 impl DownloadHandler {
     /// Load podcast and episode metadata from the database.
     pub(super) async fn context_step(
@@ -13,7 +44,8 @@ impl DownloadHandler {
             .get_download_podcast(request.podcast, request.episode)
             .await?;
         let podcasts_dir = self.paths.get_podcasts_dir();
-        Ok(DownloadContext::new(podcast, episodes, podcasts_dir))
+        let template = podcast.get_path_template(&self.options);
+        DownloadContext::new(podcast, episodes, podcasts_dir, &template)
     }
 }
 
@@ -53,11 +85,7 @@ fn get_download_podcast_query(
 ) -> Selector<SelectModel<DownloadPodcastPartial>> {
     podcast::Entity::find_by_id(podcast_key)
         .select_only()
-        .columns([
-            podcast::Column::PrimaryKey,
-            podcast::Column::Slug,
-            podcast::Column::Title,
-        ])
+        .columns(DOWNLOAD_PODCAST_COLUMNS)
         .into_model()
 }
 
@@ -68,18 +96,7 @@ fn get_download_episode_query(
     episode::Entity::find_by_id(episode_key)
         .has_related(podcast::Entity, podcast::Column::PrimaryKey.eq(podcast_key))
         .select_only()
-        .columns([
-            episode::Column::PrimaryKey,
-            episode::Column::Title,
-            episode::Column::FileSubPath,
-            episode::Column::ImageSubPath,
-            episode::Column::SourceUrl,
-            episode::Column::SourceContentType,
-            episode::Column::PublishedAt,
-            episode::Column::Image,
-            episode::Column::Episode,
-            episode::Column::Season,
-        ])
+        .columns(DOWNLOAD_EPISODE_COLUMNS)
         .into_model()
 }
 
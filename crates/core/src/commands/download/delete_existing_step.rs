@@ -3,7 +3,7 @@ use tokio::fs::remove_file;
 use crate::prelude::*;
 
 impl DownloadHandler {
-    /// Delete existing audio and image files before re-downloading.
+    /// Delete existing audio and image files once the replacement is verified.
     ///
     /// Resolves old paths from `episode.file_sub_path` and `episode.image_sub_path`
     /// relative to the podcasts directory. Warns on any failure and continues,
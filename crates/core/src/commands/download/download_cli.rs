@@ -29,11 +29,18 @@ impl DownloadCliCommand {
                 .ok_or(DownloadCliError::NoPodcast)?;
             let podcast = feed.podcast.primary_key;
             for episode in feed.episodes.iter() {
-                requests.push(DownloadRequest::new(
-                    podcast,
-                    episode.primary_key,
-                    options.replace,
-                ));
+                if options.replaced {
+                    if episode.enclosure_changed_at.is_none() {
+                        continue;
+                    }
+                    requests.push(DownloadRequest::new(podcast, episode.primary_key, true));
+                } else {
+                    requests.push(DownloadRequest::new(
+                        podcast,
+                        episode.primary_key,
+                        options.replace,
+                    ));
+                }
             }
         }
         let status = self.cli_runner.run(requests).await;
@@ -67,7 +74,7 @@ pub enum DownloadCliError {
 
 #[cfg(test)]
 mod tests {
-    #![allow(clippy::as_conversions, clippy::cast_possible_wrap)]
+    #![allow(non_snake_case, clippy::as_conversions, clippy::cast_possible_wrap)]
     use super::*;
 
     #[tokio::test]
@@ -90,6 +97,7 @@ mod tests {
                 ..FilterOptions::default()
             },
             replace: false,
+            replaced: false,
         };
 
         // Act
@@ -98,4 +106,57 @@ mod tests {
         // Assert
         result.assert_ok_debug();
     }
+
+    #[tokio::test]
+    #[serial]
+    pub async fn download_command__replaced() {
+        // Arrange
+        let services = MockServices::new()
+            .with_metadata_factory(MockFeedsFactory {
+                edit_episode: Some(|episode| {
+                    episode.enclosure_changed_at = Some(DateTime::default());
+                }),
+                ..MockFeedsFactory::default()
+            })
+            .create()
+            .await;
+        let command = services
+            .get_async::<DownloadCliCommand>()
+            .await
+            .expect("should be able to get command");
+        let metadata = services
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let filter = FilterOptions {
+            year: Some(MockFeeds::START_YEAR as i32),
+            season: Some(1),
+            ..FilterOptions::default()
+        };
+        let options = DownloadOptions {
+            selection: PodcastOptions {
+                podcast: Some(MockFeeds::podcast_slug()),
+                all_podcasts: false,
+            },
+            filter: filter.clone(),
+            replace: false,
+            replaced: true,
+        };
+
+        // Act
+        let result = command.execute(options).await;
+
+        // Assert
+        result.assert_ok_debug();
+        let feed = metadata
+            .get_feed_by_slug(MockFeeds::podcast_slug(), Some(filter))
+            .await
+            .assert_ok_debug()
+            .expect("podcast should exist");
+        assert!(!feed.episodes.is_empty());
+        for episode in &feed.episodes {
+            assert!(episode.file_sub_path.is_some(), "downloaded");
+            assert!(episode.enclosure_changed_at.is_none(), "flag cleared");
+        }
+    }
 }
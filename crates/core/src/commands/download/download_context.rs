@@ -9,51 +9,76 @@ pub struct DownloadContext {
     pub(super) episode: DownloadEpisodePartial,
     /// Resolved path for the audio file.
     pub(super) file_path: PathBuf,
+    /// Path the audio file is downloaded to before it is verified and moved to `file_path`.
+    pub(super) download_path: PathBuf,
     /// Resolved path for artwork. `None` if episode has no image URL.
     pub(super) image_path: Option<PathBuf>,
 }
 
 impl DownloadContext {
     /// Create a new [`DownloadContext`] from metadata partials and base directory.
-    #[must_use]
+    ///
+    /// The file path is rendered from the template and must be within the base directory.
     pub fn new(
         podcast: DownloadPodcastPartial,
         episode: DownloadEpisodePartial,
         podcasts_dir: PathBuf,
-    ) -> Self {
-        let sub_path = get_sub_path(&podcast, &episode);
+        template: &PathTemplate,
+    ) -> Result<Self, Report<DownloadError>> {
+        let sub_path = get_sub_path(template, &podcast, &episode)
+            .change_context(DownloadError::PathTemplate)
+            .attach("Template", template.to_string())?;
         let file_path = podcasts_dir.join(&sub_path);
+        if !is_path_within(&file_path, &podcasts_dir) {
+            return Err(Report::new(DownloadError::PathTemplate)
+                .attach("Template", template.to_string())
+                .attach("Path", file_path.display().to_string()));
+        }
+        let mut download_name = file_path.file_name().unwrap_or_default().to_os_string();
+        download_name.push(".download");
+        let download_path = file_path.with_file_name(download_name);
         let image_path = episode.image.clone().map(|image| {
             let extension = image.get_extension().unwrap_or_else(|| "jpg".to_owned());
             file_path.with_extension(extension)
         });
-        Self {
+        Ok(Self {
             podcast,
             episode,
             file_path,
+            download_path,
             image_path,
-        }
+        })
+    }
+
+    /// Resolved path for the audio file.
+    #[must_use]
+    pub fn get_file_path(&self) -> &Path {
+        &self.file_path
     }
 }
 
 /// Sub path for an episodes's audio file.
 ///
-/// Example: `irl/1970/1970-01-01 001 Hello World.mp3`
-fn get_sub_path(podcast: &DownloadPodcastPartial, episode: &DownloadEpisodePartial) -> PathBuf {
-    let year = episode.published_at.year().to_string();
-    let file_stem = get_episode_file_stem(
-        episode.title.clone(),
-        episode.published_at,
-        episode.season,
-        episode.episode,
-        None,
-    );
+/// Example: `irl/S00/1970/1970-01-01 001 Hello World.mp3`
+pub(crate) fn get_sub_path(
+    template: &PathTemplate,
+    podcast: &DownloadPodcastPartial,
+    episode: &DownloadEpisodePartial,
+) -> Result<PathBuf, PathTemplateError> {
+    let values = PathTemplateValues {
+        slug: podcast.slug.clone(),
+        podcast_title: podcast.title.clone(),
+        title: episode.title.clone(),
+        season: episode.season,
+        episode: episode.episode,
+        kind: episode.kind,
+        published_at: episode.published_at,
+        guid: episode.source_id.clone(),
+    };
+    let path = template.render(&values)?;
     let extension = get_episode_file_extenson(&episode.source_content_type).unwrap_or_default();
-    let season = format!("S{:02}", episode.season.unwrap_or(0));
-    // TODO: Remove season from sub path
-    PathBuf::new()
-        .join(podcast.slug.to_string())
-        .join(season)
-        .join(year)
-        .join(format!("{file_stem}.{extension}"))
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".");
+    file_name.push(extension);
+    Ok(path.with_file_name(file_name))
 }
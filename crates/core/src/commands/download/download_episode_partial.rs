@@ -8,6 +8,8 @@ pub struct DownloadEpisodePartial {
     pub primary_key: EpisodeKey,
     /// Title
     pub title: String,
+    /// GUID from the feed, or a fallback if the GUID is not unique
+    pub source_id: String,
     /// Relative file path to the downloaded audio file.
     ///
     /// Value will be `None` until the file is downloaded with [`DownloadContext`].
@@ -16,13 +18,19 @@ pub struct DownloadEpisodePartial {
     ///
     /// Value will be `None` until the file is downloaded with [`DownloadContext`].
     pub image_sub_path: Option<PathWrapper>,
+    /// Date and time the enclosure changed after the episode was downloaded.
+    pub enclosure_changed_at: Option<DateTime<FixedOffset>>,
     /// URL of source media file including a file extension
     /// - Supported file formats include M4A, MP3, MOV, MP4, M4V, and PDF
     pub source_url: UrlWrapper,
+    /// Size of source media file in bytes
+    pub source_file_size: FileSize,
     /// Mime type of source media file
     pub source_content_type: String,
     /// Date and time episode was released
     pub published_at: DateTime<FixedOffset>,
+    /// HTML formatted description
+    pub description: Option<String>,
     /// URL of JPEG or PNG artwork
     /// - Min: 1400 x 1400 px
     /// - Max: 3000 x 3000 px
@@ -31,6 +39,8 @@ pub struct DownloadEpisodePartial {
     pub episode: Option<EpisodeNumber>,
     /// Season number
     pub season: Option<SeasonNumber>,
+    /// Episode type
+    pub kind: Option<EpisodeKind>,
 }
 
 impl Display for DownloadEpisodePartial {
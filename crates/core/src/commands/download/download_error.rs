@@ -11,8 +11,20 @@ pub enum DownloadError {
     NoPodcast,
     #[error("Episode does not exist")]
     NoEpisode,
+    #[error("Unable to render the episode path template")]
+    PathTemplate,
     #[error("Unable to download the episode file")]
     DownloadEpisode,
+    #[error("Unable to read the downloaded episode file")]
+    ReadEpisode,
+    #[error("Downloaded episode file is smaller than the enclosure length")]
+    SizeMismatch,
+    #[error("Downloaded episode file does not match the enclosure content type")]
+    ContentMismatch,
+    #[error("Unable to move the downloaded episode file into place")]
+    MoveEpisode,
+    #[error("Unable to remove the partial episode file")]
+    RemovePartial,
     #[error("Unable to download the episode image")]
     DownloadImage,
     #[error("Unable to read the episode image")]
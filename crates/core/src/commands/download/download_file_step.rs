@@ -2,12 +2,23 @@ use crate::prelude::*;
 
 impl DownloadHandler {
     /// Download the episode audio file to the local filesystem.
+    ///
+    /// The file is written to the download path so an existing download is
+    /// not replaced until the new file is verified.
+    ///
+    /// Applies the podcast credentials if the enclosure has the same origin
+    /// as the feed.
     pub(super) async fn download_file_step(
         &self,
         context: &DownloadContext,
     ) -> Result<(), Report<DownloadError>> {
-        self.http
-            .download(&context.episode.source_url, context.file_path.clone())
+        let http = match &context.podcast.feed_url {
+            Some(feed_url) => self
+                .http
+                .with_credentials(context.podcast.credentials.clone(), feed_url),
+            None => Arc::clone(&self.http),
+        };
+        http.download(&context.episode.source_url, context.download_path.clone())
             .await
             .change_context(DownloadError::DownloadEpisode)
     }
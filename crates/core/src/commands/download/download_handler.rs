@@ -3,14 +3,17 @@ use crate::prelude::*;
 /// Downloads a single episode through a multi-step pipeline.
 ///
 /// - Fetch audio file
+/// - Verify audio file size and format
+/// - Replace any existing download with the verified file
 /// - Fetch and resize artwork
-/// - Add ID3 tags
+/// - Add ID3 or MP4 tags
 /// - Save file paths to database
 #[derive(FromServicesAsync)]
 pub struct DownloadHandler {
     pub(super) paths: Arc<PathProvider>,
     pub(super) http: Arc<dyn HttpFetch>,
     pub(super) metadata: Arc<MetadataRepository>,
+    pub(super) options: Arc<AppOptions>,
 }
 
 #[async_trait]
@@ -27,8 +30,10 @@ impl Execute<DownloadRequest, DownloadResponse, Report<DownloadError>> for Downl
         if let Some(path) = &context.episode.file_sub_path {
             if request.replace {
                 debug!(podcast, episode, %path, "Replacing existing download");
-                self.delete_existing_step(&context).await;
             } else {
+                if context.episode.enclosure_changed_at.is_some() {
+                    warn!(podcast, episode, %path, "Existing download has been replaced in the feed");
+                }
                 debug!(podcast, episode, %path, "Skipping already downloaded");
                 return Ok(DownloadResponse {
                     file_path: path.as_ref().clone(),
@@ -38,6 +43,14 @@ impl Execute<DownloadRequest, DownloadResponse, Report<DownloadError>> for Downl
         }
         trace!(podcast, episode, "Downloading episode file");
         self.download_file_step(&context).await?;
+        trace!(podcast, episode, "Verifying episode file");
+        self.verify_step(&context).await?;
+        if request.replace {
+            trace!(podcast, episode, "Deleting existing download");
+            self.delete_existing_step(&context).await;
+        }
+        trace!(podcast, episode, "Moving episode file into place");
+        self.move_file_step(&context).await?;
         trace!(podcast, episode, "Downloading episode image");
         self.download_image_step(&context).await?;
         trace!(podcast, episode, "Resizing episode image");
@@ -49,6 +62,23 @@ impl Execute<DownloadRequest, DownloadResponse, Report<DownloadError>> for Downl
     }
 }
 
+impl DownloadHandler {
+    /// Remove the partial file left by an interrupted download of an episode.
+    ///
+    /// Use when the download is cancelled so the partial file isn't left in the library.
+    pub async fn remove_partial(
+        &self,
+        request: &DownloadRequest,
+    ) -> Result<(), Report<DownloadError>> {
+        let context = self.context_step(request).await?;
+        self.http
+            .remove_partial(&context.download_path)
+            .await
+            .change_context(DownloadError::RemovePartial)
+            .attach_path(&context.download_path)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use tokio::fs::{create_dir_all, read, remove_file, write};
@@ -76,6 +106,42 @@ mod tests {
         result.assert_ok_debug();
     }
 
+    #[tokio::test]
+    #[serial]
+    pub async fn download_handler_path_template() {
+        // Arrange
+        let services = MockServices::new()
+            .with_metadata_factory(MockFeedsFactory {
+                edit_podcast: Some(|podcast| {
+                    podcast.path_template = Some(
+                        PathTemplate::from_str("{podcast}/{year}/{date} - {title}")
+                            .expect("should be a valid template"),
+                    );
+                }),
+                ..MockFeedsFactory::default()
+            })
+            .create()
+            .await;
+        let download = services
+            .get_async::<DownloadHandler>()
+            .await
+            .expect("should be able to get command");
+        let request = DownloadRequest::new(MockFeeds::PODCAST_KEY, MockFeeds::EPISODE_KEY, false);
+
+        // Act
+        let result = download.execute(&request).await;
+
+        // Assert
+        let response = result.assert_ok_debug();
+        let file_name = response
+            .file_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .expect("should have a file name");
+        assert!(file_name.contains(" - "), "{file_name}");
+        assert_eq!(response.file_path.components().count(), 3);
+    }
+
     #[tokio::test]
     #[serial]
     pub async fn download_handler_replace() {
@@ -178,4 +244,75 @@ mod tests {
         let snapshot = TagSnapshot::from_path(&full_path);
         assert_yaml_snapshot!(snapshot);
     }
+
+    #[tokio::test]
+    #[serial]
+    pub async fn download_handler_tags_m4a() {
+        // Arrange
+        // Act
+        let snapshot = download_mp4(|episode| {
+            episode.source_content_type = "audio/x-m4a".to_owned();
+        })
+        .await;
+
+        // Assert
+        assert_yaml_snapshot!(snapshot);
+    }
+
+    #[tokio::test]
+    #[serial]
+    pub async fn download_handler_tags_audio_mp4() {
+        // Arrange
+        // Act
+        let snapshot = download_mp4(|episode| {
+            episode.source_content_type = "audio/mp4".to_owned();
+        })
+        .await;
+
+        // Assert
+        assert_yaml_snapshot!(snapshot);
+    }
+
+    #[tokio::test]
+    #[serial]
+    pub async fn download_handler_tags_video_mp4() {
+        // Arrange
+        // Act
+        let snapshot = download_mp4(|episode| {
+            episode.source_content_type = "video/mp4".to_owned();
+        })
+        .await;
+
+        // Assert
+        assert_yaml_snapshot!(snapshot);
+    }
+
+    /// Download the sample M4A and read its tags.
+    async fn download_mp4(edit_episode: fn(&mut EpisodeInfo)) -> TagSnapshot {
+        let services = MockServices::new()
+            .with_metadata_factory(MockFeedsFactory {
+                edit_episode: Some(edit_episode),
+                ..MockFeedsFactory::default()
+            })
+            .with_http(|http| {
+                http.with_file(
+                    MockFeeds::episode_file_url().as_str(),
+                    SampleFixtures::m4a(),
+                )
+            })
+            .create()
+            .await;
+        let download = services
+            .get_async::<DownloadHandler>()
+            .await
+            .expect("should be able to get command");
+        let paths = services
+            .get_async::<PathProvider>()
+            .await
+            .expect("should be able to get path provider");
+        let request = DownloadRequest::new(MockFeeds::PODCAST_KEY, MockFeeds::EPISODE_KEY, false);
+        let response = download.execute(&request).await.assert_ok_debug();
+        let full_path = paths.get_podcasts_dir().join(&response.file_path);
+        TagSnapshot::from_path(&full_path)
+    }
 }
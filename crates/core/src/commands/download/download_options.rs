@@ -11,4 +11,7 @@ pub struct DownloadOptions {
     /// Replace existing downloads by re-downloading and re-processing.
     #[arg(long)]
     pub replace: bool,
+    /// Only re-download episodes whose enclosure URL or size changed after they were downloaded.
+    #[arg(long)]
+    pub replaced: bool,
 }
@@ -12,6 +12,31 @@ pub struct DownloadPodcastPartial {
     pub slug: Slug,
     /// Title
     pub title: String,
+    /// URL of the RSS feed
+    pub feed_url: Option<UrlWrapper>,
+    /// Website associated with the podcast
+    pub link: Option<UrlWrapper>,
+    /// Apple Podcasts categories
+    pub categories: PodcastCategories,
+    /// Credentials applied to enclosure requests
+    #[serde(skip)]
+    pub credentials: Option<PodcastCredentials>,
+    /// Template for the paths of downloaded episodes
+    pub path_template: Option<PathTemplate>,
+}
+
+#[cfg(feature = "server")]
+impl DownloadPodcastPartial {
+    /// Get the template for the paths of downloaded episodes.
+    ///
+    /// Falls back to [`AppOptions::path_template`] then the default template.
+    #[must_use]
+    pub fn get_path_template(&self, options: &AppOptions) -> PathTemplate {
+        self.path_template
+            .clone()
+            .or_else(|| options.path_template.clone())
+            .unwrap_or_default()
+    }
 }
 
 impl Display for DownloadPodcastPartial {
@@ -19,12 +19,20 @@ mod download_podcast_partial;
 mod download_request;
 mod download_response;
 #[cfg(feature = "server")]
+mod move_file_step;
+#[cfg(feature = "server")]
 mod resize_step;
 #[cfg(feature = "server")]
 mod save_step;
 #[cfg(feature = "server")]
+mod tag_field;
+#[cfg(feature = "server")]
 mod tag_step;
+#[cfg(feature = "server")]
+mod verify_step;
 
+#[cfg(feature = "server")]
+pub(crate) use context_step::*;
 #[cfg(feature = "server")]
 pub use download_cli::*;
 #[cfg(feature = "server")]
@@ -37,3 +45,5 @@ pub use download_options::*;
 pub use download_podcast_partial::*;
 pub use download_request::*;
 pub use download_response::*;
+#[cfg(feature = "server")]
+pub use tag_field::*;
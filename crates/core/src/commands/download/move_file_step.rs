@@ -0,0 +1,15 @@
+use crate::prelude::*;
+use tokio::fs::rename;
+
+impl DownloadHandler {
+    /// Move the verified audio file from the download path to its final path.
+    pub(super) async fn move_file_step(
+        &self,
+        context: &DownloadContext,
+    ) -> Result<(), Report<DownloadError>> {
+        rename(&context.download_path, &context.file_path)
+            .await
+            .change_context(DownloadError::MoveEpisode)
+            .attach_path(&context.file_path)
+    }
+}
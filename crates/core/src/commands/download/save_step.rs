@@ -57,6 +57,7 @@ impl MetadataRepository {
             primary_key: Set(episode_key),
             file_sub_path: Set(Some(PathWrapper::from(file_path))),
             image_sub_path: Set(image_path.map(PathWrapper::from)),
+            enclosure_changed_at: Set(None),
             ..Default::default()
         };
         episode::Entity::update(model)
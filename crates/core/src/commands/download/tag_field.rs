@@ -0,0 +1,56 @@
+use crate::prelude::*;
+
+/// Optional values added to the tags of downloaded episodes.
+///
+/// Title, artist, album, disc, track, date and cover are always written.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TagField {
+    /// Episode description as plain text.
+    ///
+    /// ID3: `COMM`, MP4: `desc`
+    Description,
+    /// First category of the podcast.
+    ///
+    /// ID3: `TCON`, MP4: `©gen`
+    Genre,
+    /// Website of the podcast.
+    ///
+    /// ID3: `WOAS`, MP4: not written as iTunes has no atom for it
+    PodcastUrl,
+    /// URL of the podcast feed.
+    ///
+    /// Not written if the podcast has credentials or the URL has a username,
+    /// password or query as these may contain secrets.
+    ///
+    /// ID3: `WFED`, MP4: `purl`
+    FeedUrl,
+    /// GUID of the episode.
+    ///
+    /// ID3: `TXXX` with the description [`GUID_DESCRIPTION`], MP4: `egid`
+    Guid,
+    /// Flag marking the file as a podcast episode.
+    ///
+    /// ID3: `PCST`, MP4: `pcst`
+    PodcastFlag,
+    /// Date and time the episode was released.
+    ///
+    /// ID3: `TDRL`, MP4: `©day` including the time
+    ReleaseTime,
+}
+
+/// Description of the `TXXX` frame containing the episode GUID.
+pub const GUID_DESCRIPTION: &str = "GUID";
+
+impl TagField {
+    /// Every optional tag field.
+    pub const ALL: [TagField; 7] = [
+        TagField::Description,
+        TagField::Genre,
+        TagField::PodcastUrl,
+        TagField::FeedUrl,
+        TagField::Guid,
+        TagField::PodcastFlag,
+        TagField::ReleaseTime,
+    ];
+}
@@ -0,0 +1,349 @@
+use crate::prelude::*;
+use tokio::io::AsyncReadExt;
+
+/// Declared sizes below this are treated as placeholders and not checked.
+const MIN_DECLARED_SIZE: u64 = 10_000;
+
+/// Percentage a download may be smaller than its declared size.
+///
+/// Feeds often declare an approximate length, and dynamically inserted ads
+/// change the size of every download.
+const SIZE_TOLERANCE_PERCENT: u64 = 10;
+
+/// Number of bytes read to identify the file format.
+const SNIFF_LENGTH: u64 = 16;
+
+/// File format identified by its magic bytes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum MediaFormat {
+    /// MP3 or ADTS AAC
+    Mpeg,
+    /// MP4, M4A, M4V or MOV
+    IsoMedia,
+    Ogg,
+    Wave,
+    Flac,
+    Pdf,
+    /// HTML or XML, typically an error page
+    Markup,
+}
+
+impl DownloadHandler {
+    /// Check the downloaded audio file is complete and the expected format.
+    ///
+    /// - Fails if the file is smaller than the enclosure length allows
+    /// - Fails if the magic bytes identify a different format than the
+    ///   enclosure content type, or an HTML page
+    /// - Checks the file at the download path so an existing download is kept
+    ///   if the new file fails verification
+    /// - Deletes the file if it fails verification
+    pub(super) async fn verify_step(
+        &self,
+        context: &DownloadContext,
+    ) -> Result<(), Report<DownloadError>> {
+        let path = &context.download_path;
+        let result = verify_file(
+            path,
+            context.episode.source_file_size,
+            &context.episode.source_content_type,
+        )
+        .await;
+        if result.is_err()
+            && let Err(error) = remove_file(path).await
+        {
+            warn!(podcast = %context.podcast, episode = %context.episode, path = %path.display(), %error, "Failed to delete unverified file");
+        }
+        result.attach_url(&context.episode.source_url)
+    }
+}
+
+async fn verify_file(
+    path: &Path,
+    declared_size: FileSize,
+    content_type: &str,
+) -> Result<(), Report<DownloadError>> {
+    let mut file = AsyncFile::open(path)
+        .await
+        .change_context(DownloadError::ReadEpisode)
+        .attach_path(path)?;
+    let size = file
+        .metadata()
+        .await
+        .change_context(DownloadError::ReadEpisode)
+        .attach_path(path)?
+        .len();
+    check_size(size, declared_size)?;
+    let mut header = Vec::new();
+    (&mut file)
+        .take(SNIFF_LENGTH)
+        .read_to_end(&mut header)
+        .await
+        .change_context(DownloadError::ReadEpisode)
+        .attach_path(path)?;
+    check_content(&header, content_type)
+}
+
+/// Fail if the file is smaller than the declared size less the tolerance.
+///
+/// Larger files are accepted since only truncation is an error.
+fn check_size(size: u64, declared_size: FileSize) -> Result<(), Report<DownloadError>> {
+    let Ok(declared_size) = u64::try_from(declared_size) else {
+        return Ok(());
+    };
+    if declared_size < MIN_DECLARED_SIZE {
+        return Ok(());
+    }
+    let minimum = declared_size.saturating_mul(100 - SIZE_TOLERANCE_PERCENT);
+    if size.saturating_mul(100) < minimum {
+        return Err(Report::new(DownloadError::SizeMismatch)
+            .attach("Size", size)
+            .attach("Enclosure length", declared_size));
+    }
+    Ok(())
+}
+
+/// Fail if the magic bytes identify a different format than the content type.
+///
+/// Unrecognized bytes and content types are accepted.
+fn check_content(header: &[u8], content_type: &str) -> Result<(), Report<DownloadError>> {
+    let actual = sniff_format(header);
+    let expected = get_expected_format(content_type);
+    let is_match = match (expected, actual) {
+        (_, Some(MediaFormat::Markup)) => false,
+        (Some(expected), Some(actual)) => expected == actual,
+        _ => true,
+    };
+    if !is_match {
+        return Err(Report::new(DownloadError::ContentMismatch)
+            .attach("Content type", content_type.to_owned())
+            .attach("Detected", format!("{actual:?}")));
+    }
+    Ok(())
+}
+
+fn get_expected_format(content_type: &str) -> Option<MediaFormat> {
+    let format = match content_type {
+        "audio/mpeg" | "audio/mp3" | "audio/aac" | "audio/aacp" => MediaFormat::Mpeg,
+        "audio/x-m4a" | "audio/m4a" | "audio/mp4" | "video/mp4" | "video/x-m4v"
+        | "video/quicktime" => MediaFormat::IsoMedia,
+        "audio/ogg" | "audio/opus" | "audio/vorbis" => MediaFormat::Ogg,
+        "audio/wav" | "audio/x-wav" | "audio/wave" => MediaFormat::Wave,
+        "audio/flac" | "audio/x-flac" => MediaFormat::Flac,
+        "application/pdf" => MediaFormat::Pdf,
+        _ => return None,
+    };
+    Some(format)
+}
+
+fn sniff_format(header: &[u8]) -> Option<MediaFormat> {
+    let box_type = header.get(4..8).unwrap_or_default();
+    let format = if header.starts_with(b"ID3") || is_frame_sync(header) {
+        MediaFormat::Mpeg
+    } else if matches!(box_type, b"ftyp" | b"moov" | b"mdat" | b"wide" | b"free") {
+        MediaFormat::IsoMedia
+    } else if header.starts_with(b"OggS") {
+        MediaFormat::Ogg
+    } else if header.starts_with(b"RIFF") && header.get(8..12) == Some(b"WAVE") {
+        MediaFormat::Wave
+    } else if header.starts_with(b"fLaC") {
+        MediaFormat::Flac
+    } else if header.starts_with(b"%PDF") {
+        MediaFormat::Pdf
+    } else if is_markup(header) {
+        MediaFormat::Markup
+    } else {
+        return None;
+    };
+    Some(format)
+}
+
+/// Whether the header starts with an MPEG audio frame sync.
+fn is_frame_sync(header: &[u8]) -> bool {
+    matches!(header, [0xFF, second, ..] if second & 0xE0 == 0xE0)
+}
+
+/// Whether the header starts with `<` after any byte order mark and whitespace.
+fn is_markup(header: &[u8]) -> bool {
+    let header = header.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(header);
+    header
+        .iter()
+        .find(|byte| !byte.is_ascii_whitespace())
+        .is_some_and(|&byte| byte == b'<')
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+    use tokio::fs::write;
+
+    #[test]
+    fn check_size__within_tolerance() {
+        // Arrange
+        // Act
+        let smaller = check_size(950_000, 1_000_000);
+        let larger = check_size(2_000_000, 1_000_000);
+        let placeholder = check_size(10, 1);
+
+        // Assert
+        smaller.assert_ok_debug();
+        larger.assert_ok_debug();
+        placeholder.assert_ok_debug();
+    }
+
+    #[test]
+    fn check_size__truncated() {
+        // Arrange
+        // Act
+        let result = check_size(500_000, 1_000_000);
+
+        // Assert
+        let report = result.expect_err("should be truncated");
+        assert_eq!(report.current_context(), &DownloadError::SizeMismatch);
+    }
+
+    #[test]
+    fn sniff_format__formats() {
+        // Arrange
+        let cases: [(&[u8], Option<MediaFormat>); 9] = [
+            (b"ID3\x04\x00", Some(MediaFormat::Mpeg)),
+            (b"\xFF\xFB\x90\x64", Some(MediaFormat::Mpeg)),
+            (b"\x00\x00\x00\x20ftypM4A ", Some(MediaFormat::IsoMedia)),
+            (b"OggS\x00\x02", Some(MediaFormat::Ogg)),
+            (b"RIFF\x24\x08\x00\x00WAVEfmt ", Some(MediaFormat::Wave)),
+            (b"fLaC\x00\x00", Some(MediaFormat::Flac)),
+            (
+                b"\xEF\xBB\xBF\n  <!DOCTYPE html>",
+                Some(MediaFormat::Markup),
+            ),
+            (b"%PDF-1.7", Some(MediaFormat::Pdf)),
+            (b"\x00\x01\x02\x03", None),
+        ];
+
+        for (header, expected) in cases {
+            // Act
+            let format = sniff_format(header);
+
+            // Assert
+            assert_eq!(format, expected, "header: {header:?}");
+        }
+    }
+
+    #[test]
+    fn check_content__mismatch() {
+        // Arrange
+        // Act
+        let html = check_content(b"<html><body>Not Found", "audio/mpeg");
+        let mp4 = check_content(b"\x00\x00\x00\x20ftypisom", "audio/mpeg");
+        let unknown = check_content(b"\x00\x01\x02\x03", "audio/mpeg");
+        let unknown_type = check_content(b"ID3\x04\x00", "audio/x-unknown");
+
+        // Assert
+        let report = html.expect_err("should not accept HTML");
+        assert_eq!(report.current_context(), &DownloadError::ContentMismatch);
+        let report = mp4.expect_err("should not accept MP4");
+        assert_eq!(report.current_context(), &DownloadError::ContentMismatch);
+        unknown.assert_ok_debug();
+        unknown_type.assert_ok_debug();
+    }
+
+    #[tokio::test]
+    #[serial]
+    pub async fn download_handler__content_mismatch() {
+        // Arrange
+        let services = MockServices::new()
+            .with_metadata_factory(MockFeedsFactory {
+                edit_episode: Some(|episode| {
+                    episode.source_content_type = "audio/x-m4a".to_owned();
+                }),
+                ..MockFeedsFactory::default()
+            })
+            .create()
+            .await;
+        let download = services
+            .get_async::<DownloadHandler>()
+            .await
+            .expect("should be able to get command");
+        let request = DownloadRequest::new(MockFeeds::PODCAST_KEY, MockFeeds::EPISODE_KEY, false);
+
+        // Act
+        let result = download.execute(&request).await;
+
+        // Assert
+        let report = result.expect_err("should fail verification");
+        assert_eq!(report.current_context(), &DownloadError::ContentMismatch);
+        let paths = services
+            .get_async::<PathProvider>()
+            .await
+            .expect("should be able to get paths");
+        let podcast_dir = paths
+            .get_podcasts_dir()
+            .join(MockFeeds::podcast_slug().to_string());
+        let mut files = Vec::new();
+        let mut entries = vec![podcast_dir];
+        while let Some(dir) = entries.pop() {
+            let Ok(mut reader) = read_dir(&dir).await else {
+                continue;
+            };
+            while let Some(entry) = reader.next_entry().await.expect("should read entry") {
+                let path = entry.path();
+                if path.is_dir() {
+                    entries.push(path);
+                } else {
+                    files.push(path);
+                }
+            }
+        }
+        assert_eq!(
+            files,
+            Vec::<PathBuf>::new(),
+            "unverified file should be deleted"
+        );
+    }
+
+    #[tokio::test]
+    #[serial]
+    pub async fn download_handler__content_mismatch_keeps_existing() {
+        // Arrange
+        const EXISTING_SUB_PATH: &str = "existing/episode.mp3";
+        let services = MockServices::new()
+            .with_metadata_factory(MockFeedsFactory {
+                edit_episode: Some(|episode| {
+                    episode.source_content_type = "audio/x-m4a".to_owned();
+                    episode.file_sub_path = Some(
+                        PathWrapper::from_str(EXISTING_SUB_PATH).expect("should be a valid path"),
+                    );
+                }),
+                ..MockFeedsFactory::default()
+            })
+            .create()
+            .await;
+        let download = services
+            .get_async::<DownloadHandler>()
+            .await
+            .expect("should be able to get command");
+        let paths = services
+            .get_async::<PathProvider>()
+            .await
+            .expect("should be able to get paths");
+        let existing = paths.get_podcasts_dir().join(EXISTING_SUB_PATH);
+        create_dir_all(existing.parent().expect("should have parent"))
+            .await
+            .expect("should create dir");
+        write(&existing, "existing")
+            .await
+            .expect("should write existing file");
+        let request = DownloadRequest::new(MockFeeds::PODCAST_KEY, MockFeeds::EPISODE_KEY, true);
+
+        // Act
+        let result = download.execute(&request).await;
+
+        // Assert
+        let report = result.expect_err("should fail verification");
+        assert_eq!(report.current_context(), &DownloadError::ContentMismatch);
+        let contents = read_to_string(&existing)
+            .await
+            .expect("existing file should be kept");
+        assert_eq!(contents, "existing");
+    }
+}
@@ -1,7 +1,8 @@
 use crate::prelude::*;
-use rss::extension::ExtensionMap;
 use rss::extension::itunes::{ITunesCategory, ITunesChannelExtension, ITunesItemExtension};
+use rss::extension::{Extension, ExtensionMap};
 use rss::{Channel as RssChannel, Enclosure as RssEnclosure, Guid as RssGuid, Item as RssItem};
+use std::collections::BTreeMap;
 
 pub struct PodcastToRss;
 
@@ -9,11 +10,38 @@ impl PodcastToRss {
     pub fn execute(feed: PodcastFeed) -> RssChannel {
         let mut rss = podcast_to_rss(feed.podcast.clone());
         rss.items = feed.episodes.into_iter().map(episode_to_rss).collect();
+        let uses_namespace = rss.extensions.contains_key(PODCAST_NAMESPACE_PREFIX)
+            || rss
+                .items
+                .iter()
+                .any(|item| item.extensions.contains_key(PODCAST_NAMESPACE_PREFIX));
+        if uses_namespace {
+            rss.namespaces.insert(
+                PODCAST_NAMESPACE_PREFIX.to_owned(),
+                PODCAST_NAMESPACE.to_owned(),
+            );
+        }
         rss
     }
 }
 
 fn podcast_to_rss(podcast: PodcastInfo) -> RssChannel {
+    let mut extensions = Vec::new();
+    if let Some(guid) = podcast.podcast_guid {
+        extensions.push(podcast_extension("guid", Some(guid), []));
+    }
+    if let Some(locked) = podcast.locked {
+        let value = if locked { "yes" } else { "no" };
+        extensions.push(podcast_extension("locked", Some(value.to_owned()), []));
+    }
+    for funding in podcast.funding.0 {
+        extensions.push(podcast_extension(
+            "funding",
+            funding.message,
+            [("url", Some(funding.url.to_string()))],
+        ));
+    }
+    extensions.extend(podcast.persons.0.into_iter().map(person_to_extension));
     RssChannel {
         title: podcast.title,
         link: podcast.link.map(|url| url.to_string()).unwrap_or_default(),
@@ -42,11 +70,47 @@ fn podcast_to_rss(podcast: PodcastInfo) -> RssChannel {
             r#type: podcast.kind.map(|kind| kind.to_string()),
             ..ITunesChannelExtension::default()
         }),
+        extensions: to_extension_map(extensions),
         ..RssChannel::default()
     }
 }
 
 fn episode_to_rss(episode: EpisodeInfo) -> RssItem {
+    let mut extensions: Vec<Extension> = episode
+        .transcripts
+        .0
+        .into_iter()
+        .map(|transcript| {
+            podcast_extension(
+                "transcript",
+                None,
+                [
+                    ("url", Some(transcript.url.to_string())),
+                    ("type", Some(transcript.content_type)),
+                    ("language", transcript.language),
+                    ("rel", transcript.rel),
+                ],
+            )
+        })
+        .collect();
+    if let Some(url) = episode.chapters_url {
+        extensions.push(podcast_extension(
+            "chapters",
+            None,
+            [
+                ("url", Some(url.to_string())),
+                ("type", episode.chapters_content_type),
+            ],
+        ));
+    }
+    extensions.extend(episode.persons.0.into_iter().map(person_to_extension));
+    if let Some(name) = episode.season_name {
+        extensions.push(podcast_extension(
+            "season",
+            episode.season.map(|season| season.to_string()),
+            [("name", Some(name))],
+        ));
+    }
     RssItem {
         title: Some(episode.title),
         link: None,
@@ -66,7 +130,7 @@ fn episode_to_rss(episode: EpisodeInfo) -> RssItem {
         pub_date: Some(episode.published_at.to_rfc2822()),
         source: None,
         content: None,
-        extensions: ExtensionMap::default(),
+        extensions: to_extension_map(extensions),
         itunes_ext: Some(ITunesItemExtension {
             duration: episode.source_duration.map(|d| d.to_string()),
             explicit: episode.explicit.map(|explicit| explicit.to_string()),
@@ -79,3 +143,50 @@ fn episode_to_rss(episode: EpisodeInfo) -> RssItem {
         dublin_core_ext: None,
     }
 }
+
+fn person_to_extension(person: PodcastPerson) -> Extension {
+    podcast_extension(
+        "person",
+        Some(person.name),
+        [
+            ("role", person.role),
+            ("group", person.group),
+            ("img", person.image.map(|url| url.to_string())),
+            ("href", person.link.map(|url| url.to_string())),
+        ],
+    )
+}
+
+fn podcast_extension<const N: usize>(
+    name: &str,
+    value: Option<String>,
+    attrs: [(&str, Option<String>); N],
+) -> Extension {
+    Extension {
+        name: format!("{PODCAST_NAMESPACE_PREFIX}:{name}"),
+        value,
+        attrs: attrs
+            .into_iter()
+            .filter_map(|(key, value)| Some((key.to_owned(), value?)))
+            .collect(),
+        children: BTreeMap::new(),
+    }
+}
+
+fn to_extension_map(extensions: Vec<Extension>) -> ExtensionMap {
+    let mut map = ExtensionMap::new();
+    if extensions.is_empty() {
+        return map;
+    }
+    let mut by_name: BTreeMap<String, Vec<Extension>> = BTreeMap::new();
+    for extension in extensions {
+        let name = extension
+            .name
+            .trim_start_matches(PODCAST_NAMESPACE_PREFIX)
+            .trim_start_matches(':')
+            .to_owned();
+        by_name.entry(name).or_default().push(extension);
+    }
+    map.insert(PODCAST_NAMESPACE_PREFIX.to_owned(), by_name);
+    map
+}
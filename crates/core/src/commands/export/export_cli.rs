@@ -0,0 +1,41 @@
+use crate::prelude::*;
+
+/// CLI command for exporting podcast subscriptions.
+#[derive(FromServicesAsync)]
+pub struct ExportCliCommand {
+    opml: Arc<OpmlExporter>,
+}
+
+impl ExportCliCommand {
+    /// Export the podcast subscriptions in the requested format.
+    pub async fn execute(&self, options: ExportOptions) -> Result<(), Report<ExportError>> {
+        match options.format {
+            ExportFormat::Opml(options) => {
+                let xml = self.opml.export(options.emulated).await?;
+                write_output(options.output.as_deref(), &xml).await
+            }
+        }
+    }
+}
+
+/// Write to a file if a path is set, otherwise to stdout.
+async fn write_output(path: Option<&Path>, contents: &str) -> Result<(), Report<ExportError>> {
+    let Some(path) = path else {
+        println!("{contents}");
+        return Ok(());
+    };
+    let mut file = AsyncFile::create(path)
+        .await
+        .change_context(ExportError::Create)
+        .attach_path(path)?;
+    file.write_all(contents.as_bytes())
+        .await
+        .change_context(ExportError::Write)
+        .attach_path(path)?;
+    file.flush()
+        .await
+        .change_context(ExportError::Flush)
+        .attach_path(path)?;
+    info!("Exported to {}", path.display());
+    Ok(())
+}
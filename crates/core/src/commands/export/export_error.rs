@@ -0,0 +1,20 @@
+use crate::prelude::*;
+
+/// Errors from exporting podcast subscriptions.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum ExportError {
+    #[error("Unable to query database")]
+    Repository,
+    #[error("Server base is required for emulated feed URLs")]
+    NoServerBase,
+    #[error("Unable to create emulated feed URL")]
+    ParseUrl,
+    #[error("Unable to write OPML")]
+    Opml,
+    #[error("Unable to create file")]
+    Create,
+    #[error("Unable to write file")]
+    Write,
+    #[error("Unable to flush file")]
+    Flush,
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+
+/// CLI options for [`ExportCliCommand`].
+#[derive(Clone, Debug, Args)]
+pub struct ExportOptions {
+    /// Format to export.
+    #[command(subcommand)]
+    pub format: ExportFormat,
+}
+
+/// Formats supported by [`ExportCliCommand`].
+#[derive(Clone, Debug, Subcommand)]
+pub enum ExportFormat {
+    /// Export every podcast as an OPML 2.0 subscription list.
+    Opml(OpmlExportOptions),
+}
+
+/// CLI options for exporting OPML.
+#[derive(Clone, Debug, Args)]
+pub struct OpmlExportOptions {
+    /// Use the emulated feed URLs under `server_base` instead of the upstream feed URLs.
+    #[arg(long)]
+    pub emulated: bool,
+    /// Path to write the OPML file.
+    ///
+    /// If not set the OPML is written to stdout.
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+}
@@ -0,0 +1,13 @@
+#[cfg(feature = "server")]
+mod export_cli;
+mod export_error;
+mod export_options;
+#[cfg(feature = "server")]
+mod opml_exporter;
+
+#[cfg(feature = "server")]
+pub use export_cli::*;
+pub use export_error::*;
+pub use export_options::*;
+#[cfg(feature = "server")]
+pub use opml_exporter::*;
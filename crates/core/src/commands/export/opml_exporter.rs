@@ -0,0 +1,111 @@
+use crate::prelude::*;
+
+/// Title of the exported OPML document.
+const OPML_TITLE: &str = "Alnwick subscriptions";
+
+/// Exports the podcast subscriptions as OPML.
+///
+/// - Writes the title, feed URL and website of every podcast
+/// - Skips podcasts without a feed URL unless emulated feed URLs are requested
+#[derive(FromServicesAsync)]
+pub struct OpmlExporter {
+    options: Arc<AppOptions>,
+    paths: Arc<PathProvider>,
+    metadata: Arc<MetadataRepository>,
+}
+
+impl OpmlExporter {
+    /// Export every podcast as an OPML 2.0 document.
+    ///
+    /// If `emulated` is set the outlines point at the emulated feeds under `server_base`.
+    pub async fn export(&self, emulated: bool) -> Result<String, Report<ExportError>> {
+        let subscriptions = self
+            .metadata
+            .get_all_subscriptions()
+            .await
+            .change_context(ExportError::Repository)?;
+        let mut outlines = Vec::new();
+        for (slug, title, feed_url, link) in subscriptions {
+            let xml_url = if emulated {
+                self.get_emulated_url(&slug)?
+            } else if let Some(feed_url) = feed_url {
+                feed_url.to_string()
+            } else {
+                trace!(%slug, "Skipping podcast without a feed URL");
+                continue;
+            };
+            let html_url = link.map(|link| link.to_string());
+            outlines.push(OpmlOutline::rss(title, xml_url, html_url));
+        }
+        Opml::new(Some(OPML_TITLE.to_owned()), outlines)
+            .write()
+            .change_context(ExportError::Opml)
+    }
+
+    /// URL of the emulated feed of a podcast.
+    ///
+    /// Example: `https://example.com/irl/feed.rss`
+    fn get_emulated_url(&self, slug: &Slug) -> Result<String, Report<ExportError>> {
+        let Some(base) = &self.options.server_base else {
+            return Err(Report::new(ExportError::NoServerBase));
+        };
+        let path = self.paths.get_rss_path(slug, None, None);
+        let sub_path = path
+            .strip_prefix(self.paths.get_podcasts_dir())
+            .change_context(ExportError::ParseUrl)
+            .attach_path(&path)?;
+        let url = base
+            .join(sub_path.to_string_lossy().as_ref())
+            .change_context(ExportError::ParseUrl)?;
+        Ok(url.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[tokio::test]
+    async fn export() {
+        // Arrange
+        let services = MockServices::new()
+            .with_metadata_factory(MockFeedsFactory {
+                podcast_count: 2,
+                edit_podcast: Some(|podcast| {
+                    podcast.feed_url = Some(MockServices::rss_url());
+                }),
+                ..MockFeedsFactory::default()
+            })
+            .create()
+            .await;
+        let exporter = services
+            .get_async::<OpmlExporter>()
+            .await
+            .expect("should be able to get exporter");
+
+        // Act
+        let result = exporter.export(false).await;
+
+        // Assert
+        let xml = result.assert_ok_debug();
+        assert_snapshot!(xml);
+    }
+
+    #[tokio::test]
+    async fn export__emulated_without_server_base() {
+        // Arrange
+        let services = MockServices::default().create().await;
+        let exporter = services
+            .get_async::<OpmlExporter>()
+            .await
+            .expect("should be able to get exporter");
+
+        // Act
+        let result = exporter.export(true).await;
+
+        // Assert
+        let report = result.assert_err_debug();
+        assert_eq!(report.current_context(), &ExportError::NoServerBase);
+    }
+}
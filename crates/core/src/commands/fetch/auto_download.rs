@@ -0,0 +1,192 @@
+use crate::prelude::*;
+use sea_orm::*;
+
+impl FetchHandler {
+    /// Queue downloads of the inserted episodes that match the auto-download rules.
+    ///
+    /// Requests are added to the [`DownloadQueue`] if it has been restored by the
+    /// web server so they survive a restart, otherwise they are sent straight to
+    /// the [`CommandRunner`].
+    pub(super) async fn queue_auto_downloads(
+        &self,
+        podcast_key: PodcastKey,
+        inserted_keys: &[EpisodeKey],
+    ) -> Result<Vec<DownloadRequest>, Report<FetchError>> {
+        if inserted_keys.is_empty() {
+            return Ok(Vec::new());
+        }
+        let Some(rules) = self
+            .metadata
+            .get_auto_download_rules(podcast_key)
+            .await
+            .change_context(FetchError::Repository)?
+        else {
+            return Ok(Vec::new());
+        };
+        let episode_keys = self
+            .metadata
+            .get_auto_download_episodes(podcast_key, inserted_keys, &rules)
+            .await
+            .change_context(FetchError::Repository)?;
+        trace!(podcast_key, %rules, episodes = episode_keys.len(), "Queueing auto-downloads");
+        let mut requests = Vec::new();
+        for episode_key in episode_keys {
+            let request = DownloadRequest::new(podcast_key, episode_key, false);
+            if self.download_queue.is_restored() {
+                self.download_queue
+                    .add(request)
+                    .await
+                    .change_context(FetchError::Queue)
+                    .attach("Request", request.to_string())?;
+            } else {
+                self.queue
+                    .queue(request)
+                    .await
+                    .change_context(FetchError::Queue)
+                    .attach("Request", request.to_string())?;
+            }
+            requests.push(request);
+        }
+        Ok(requests)
+    }
+}
+
+impl MetadataRepository {
+    /// Get the auto-download rules of a podcast.
+    ///
+    /// Returns `Ok(None)` if the podcast does not exist or auto-download is disabled.
+    pub(crate) async fn get_auto_download_rules(
+        &self,
+        podcast_key: PodcastKey,
+    ) -> Result<Option<AutoDownloadRules>, DbErr> {
+        let option = get_auto_download_rules_query(podcast_key)
+            .into_tuple::<Option<AutoDownloadRules>>()
+            .one(&self.db)
+            .await?;
+        Ok(option.flatten())
+    }
+
+    /// Get the episodes that match the auto-download rules, newest first.
+    async fn get_auto_download_episodes(
+        &self,
+        podcast_key: PodcastKey,
+        episode_keys: &[EpisodeKey],
+        rules: &AutoDownloadRules,
+    ) -> Result<Vec<EpisodeKey>, DbErr> {
+        get_auto_download_episodes_query(podcast_key, episode_keys, rules)
+            .into_tuple::<EpisodeKey>()
+            .all(&self.db)
+            .await
+    }
+}
+
+fn get_auto_download_rules_query(podcast_key: PodcastKey) -> Select<podcast::Entity> {
+    podcast::Entity::find()
+        .select_only()
+        .column(podcast::Column::AutoDownload)
+        .filter(podcast::Column::PrimaryKey.eq(podcast_key))
+}
+
+fn get_auto_download_episodes_query(
+    podcast_key: PodcastKey,
+    episode_keys: &[EpisodeKey],
+    rules: &AutoDownloadRules,
+) -> Select<episode::Entity> {
+    let mut query = episode::Entity::find()
+        .select_only()
+        .column(episode::Column::PrimaryKey)
+        .filter(episode::Column::PodcastKey.eq(podcast_key))
+        .filter(episode::Column::PrimaryKey.is_in(episode_keys.iter().copied()));
+    if !rules.kinds.is_empty() {
+        query = query.filter(episode::Column::Kind.is_in(rules.kinds.iter().copied()));
+    }
+    if let Some(season) = rules.from_season {
+        query = query.filter(episode::Column::Season.gte(season));
+    }
+    query = query.order_by_desc(episode::Column::PublishedAt);
+    if let Some(latest) = rules.latest {
+        query = query.limit(u64::from(latest));
+    }
+    query
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+    use std::cmp::Reverse;
+
+    #[test]
+    fn _get_auto_download_rules_query() {
+        // Arrange
+        // Act
+        let statement = get_auto_download_rules_query(MockFeeds::PODCAST_KEY).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[test]
+    fn _get_auto_download_episodes_query() {
+        // Arrange
+        let rules = AutoDownloadRules {
+            kinds: vec![EpisodeKind::Full, EpisodeKind::Bonus],
+            latest: Some(2),
+            from_season: Some(3),
+        };
+
+        // Act
+        let statement =
+            get_auto_download_episodes_query(MockFeeds::PODCAST_KEY, &[1, 2, 3], &rules)
+                .build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[tokio::test]
+    pub async fn get_auto_download_episodes__rules() {
+        // Arrange
+        let metadata = MockServices::default()
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let podcast = metadata
+            .get_feed_by_slug(MockFeeds::podcast_slug(), None)
+            .await
+            .assert_ok_debug()
+            .expect("podcast should exist");
+        let episode_keys: Vec<EpisodeKey> = podcast
+            .episodes
+            .iter()
+            .map(|episode| episode.primary_key)
+            .collect();
+        let mut expected: Vec<&EpisodeInfo> = podcast
+            .episodes
+            .iter()
+            .filter(|episode| episode.season.is_some_and(|season| season >= 2))
+            .collect();
+        expected.sort_by_key(|episode| Reverse(episode.published_at));
+        let expected: Vec<EpisodeKey> = expected
+            .into_iter()
+            .take(3)
+            .map(|episode| episode.primary_key)
+            .collect();
+        let rules = AutoDownloadRules {
+            latest: Some(3),
+            from_season: Some(2),
+            ..AutoDownloadRules::default()
+        };
+
+        // Act
+        let result = metadata
+            .get_auto_download_episodes(MockFeeds::PODCAST_KEY, &episode_keys, &rules)
+            .await;
+
+        // Assert
+        let keys = result.assert_ok_debug();
+        assert_eq!(keys, expected);
+    }
+}
@@ -0,0 +1,147 @@
+use crate::prelude::*;
+
+/// Give every episode of a feed a unique `source_id`.
+///
+/// Items are considered in feed order so the result is deterministic.
+/// The first item with a GUID keeps it. A later item with the same GUID is:
+/// - Dropped if it has the same enclosure URL, or the same title and date,
+///   as an earlier item with that GUID
+/// - Otherwise keyed by its enclosure URL
+/// - Otherwise keyed by its title and published date
+/// - Otherwise dropped
+///
+/// An item without a GUID is keyed the same way, by its enclosure URL then its
+/// title and published date, and is dropped if both are already used.
+pub(crate) fn dedupe_episodes(
+    episodes: Vec<EpisodeInfo>,
+) -> (Vec<EpisodeInfo>, Vec<GuidCollision>) {
+    let mut source_ids: HashSet<String> = HashSet::new();
+    let mut kept: Vec<(String, EpisodeInfo)> = Vec::new();
+    let mut collisions = Vec::new();
+    for mut episode in episodes {
+        let guid = episode.source_id.clone();
+        if !guid.is_empty() && source_ids.insert(guid.clone()) {
+            kept.push((guid, episode));
+            continue;
+        }
+        let is_duplicate = !guid.is_empty()
+            && kept
+                .iter()
+                .filter(|(other_guid, _)| *other_guid == guid)
+                .any(|(_, other)| is_same_episode(other, &episode));
+        let url_id = episode.source_url.to_string();
+        let title_id = get_title_and_date_id(&episode);
+        let resolution = if is_duplicate {
+            GuidResolution::Dropped
+        } else if source_ids.insert(url_id.clone()) {
+            episode.source_id = url_id;
+            GuidResolution::EnclosureUrl
+        } else if source_ids.insert(title_id.clone()) {
+            episode.source_id = title_id;
+            GuidResolution::TitleAndDate
+        } else {
+            GuidResolution::Dropped
+        };
+        trace!(%guid, episode = %episode, ?resolution, "Episode has no GUID or reuses a GUID");
+        collisions.push(GuidCollision {
+            guid: guid.clone(),
+            title: episode.title.clone(),
+            resolution,
+        });
+        if resolution != GuidResolution::Dropped {
+            kept.push((guid, episode));
+        }
+    }
+    let episodes = kept.into_iter().map(|(_, episode)| episode).collect();
+    (episodes, collisions)
+}
+
+/// Whether two items with the same GUID are the same episode.
+fn is_same_episode(a: &EpisodeInfo, b: &EpisodeInfo) -> bool {
+    a.source_url == b.source_url || (a.title == b.title && a.published_at == b.published_at)
+}
+
+fn get_title_and_date_id(episode: &EpisodeInfo) -> String {
+    format!("{} {}", episode.title, episode.published_at.to_rfc3339())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    fn episode(title: &str, url: &str) -> EpisodeInfo {
+        EpisodeInfo {
+            title: title.to_owned(),
+            source_url: UrlWrapper::from_str(url).expect("should be valid URL"),
+            ..EpisodeInfo::example()
+        }
+    }
+
+    #[test]
+    fn dedupe_episodes__unique() {
+        // Arrange
+        let episodes = vec![
+            EpisodeInfo {
+                source_id: "a".to_owned(),
+                ..EpisodeInfo::example()
+            },
+            EpisodeInfo {
+                source_id: "b".to_owned(),
+                ..EpisodeInfo::example()
+            },
+        ];
+
+        // Act
+        let (output, collisions) = dedupe_episodes(episodes.clone());
+
+        // Assert
+        assert_eq!(output, episodes);
+        assert!(collisions.is_empty());
+    }
+
+    #[test]
+    fn dedupe_episodes__collisions() {
+        // Arrange
+        let episodes = vec![
+            episode("First", "https://example.com/1.mp3"),
+            episode("First again", "https://example.com/1.mp3"),
+            episode("Second", "https://example.com/2.mp3"),
+            EpisodeInfo {
+                source_id: "https://example.com/3.mp3".to_owned(),
+                ..episode("Other", "https://example.com/3.mp3")
+            },
+            episode("Third", "https://example.com/3.mp3"),
+            episode("Third", "https://example.com/4.mp3"),
+        ];
+
+        // Act
+        let (output, collisions) = dedupe_episodes(episodes);
+
+        // Assert
+        let source_ids: Vec<_> = output.iter().map(|episode| &episode.source_id).collect();
+        assert_yaml_snapshot!((source_ids, collisions));
+    }
+
+    #[test]
+    fn dedupe_episodes__no_guid() {
+        // Arrange
+        let no_guid = |title: &str, url: &str| EpisodeInfo {
+            source_id: String::new(),
+            ..episode(title, url)
+        };
+        let episodes = vec![
+            no_guid("First", "https://example.com/1.mp3"),
+            no_guid("Second", "https://example.com/2.mp3"),
+            no_guid("Third", "https://example.com/2.mp3"),
+            no_guid("Third", "https://example.com/2.mp3"),
+        ];
+
+        // Act
+        let (output, collisions) = dedupe_episodes(episodes);
+
+        // Assert
+        let source_ids: Vec<_> = output.iter().map(|episode| &episode.source_id).collect();
+        assert_yaml_snapshot!((source_ids, collisions));
+    }
+}
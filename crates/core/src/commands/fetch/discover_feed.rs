@@ -0,0 +1,366 @@
+use crate::prelude::*;
+
+/// Paths commonly used for podcast feeds, relative to the site root.
+const COMMON_FEED_PATHS: [&str; 7] = [
+    "/feed",
+    "/rss",
+    "/feed.xml",
+    "/rss.xml",
+    "/podcast.xml",
+    "/feed/podcast",
+    "/atom.xml",
+];
+
+impl FetchHandler {
+    /// Discover the feed of an HTML page.
+    ///
+    /// - Prefers `<link rel="alternate">` elements with an RSS or Atom type
+    /// - Then tries common feed paths on the same site
+    /// - Then asks each [`FeedSource`] in order
+    /// - A source that errors is logged and skipped
+    /// - Returns `NotFound` with the errors of every source if none has a feed
+    pub(super) async fn discover_feed(
+        &self,
+        http: &dyn HttpFetch,
+        slug: &Slug,
+        url: &UrlWrapper,
+    ) -> Result<FeedSourceResult, Report<DiscoverFeedError>> {
+        let body = http
+            .get_string(url)
+            .await
+            .change_context(DiscoverFeedError::GetPage)
+            .attach_url(url)?;
+        let alternates = get_alternate_feed_urls(&Html::parse_document(&body), url);
+        if let Some(feed_url) = alternates.into_iter().next() {
+            trace!(%slug, url = %url.to_redacted_string(), feed_url = %feed_url.to_redacted_string(), "Discovered feed from alternate link");
+            return Ok(FeedSourceResult::Url(feed_url));
+        }
+        if let Some(feed_url) = get_common_feed_url(http, url).await {
+            trace!(%slug, url = %url.to_redacted_string(), feed_url = %feed_url.to_redacted_string(), "Discovered feed at common path");
+            return Ok(FeedSourceResult::Url(feed_url));
+        }
+        let mut errors = Vec::new();
+        for source in self.sources.iter() {
+            let source_name = source.get_name();
+            match source.get_feed(slug, url, &body).await {
+                Ok(Some(FeedSourceResult::Url(feed_url))) => {
+                    trace!(%slug, url = %url.to_redacted_string(), feed_url = %feed_url.to_redacted_string(), source_name, "Discovered feed from source");
+                    return Ok(FeedSourceResult::Url(feed_url));
+                }
+                Ok(Some(FeedSourceResult::Feed(feed))) => {
+                    trace!(%slug, url = %url.to_redacted_string(), source_name, episodes = feed.episodes.len(), "Built feed from source");
+                    return Ok(FeedSourceResult::Feed(feed));
+                }
+                Ok(None) => {}
+                Err(report) => {
+                    warn!(%slug, url = %url.to_redacted_string(), source_name, "Skipping feed source\n{}", report.render());
+                    errors.push((source_name, report.to_string()));
+                }
+            }
+        }
+        let mut report = Report::new(DiscoverFeedError::NotFound).attach_url(url);
+        for (source_name, error) in errors {
+            report = report.attach(source_name, error);
+        }
+        Err(report)
+    }
+}
+
+/// Get the first common feed path that responds with a feed content type.
+async fn get_common_feed_url(http: &dyn HttpFetch, url: &UrlWrapper) -> Option<UrlWrapper> {
+    for path in COMMON_FEED_PATHS {
+        let Ok(candidate) = url.join(path) else {
+            continue;
+        };
+        let Ok(candidate) = UrlWrapper::from_str(candidate.as_str()) else {
+            continue;
+        };
+        match http.head(&candidate).await {
+            Ok(content_type) if FeedFormat::is_feed_content_type(&content_type) => {
+                return Some(candidate);
+            }
+            Ok(content_type) => {
+                trace!(url = %candidate.to_redacted_string(), content_type, "Common feed path is not a feed");
+            }
+            Err(_) => {
+                trace!(url = %candidate.to_redacted_string(), "Common feed path is unavailable");
+            }
+        }
+    }
+    None
+}
+
+/// Get the RSS and Atom feeds advertised by `<link rel="alternate">` elements.
+///
+/// - Relative URLs are resolved against the page URL
+/// - Comment feeds are skipped
+/// - RSS feeds are ordered before Atom feeds
+fn get_alternate_feed_urls(html: &Html, base: &Url) -> Vec<UrlWrapper> {
+    let selector =
+        Selector::parse(r#"link[rel~="alternate"][href]"#).expect("Selector should be valid");
+    let mut feeds: Vec<(FeedFormat, UrlWrapper)> = html
+        .select(&selector)
+        .filter_map(|element| {
+            let content_type = element.attr("type")?.trim().to_ascii_lowercase();
+            let format = FeedFormat::from_content_type(&content_type)?;
+            let href = element.attr("href")?.trim();
+            let url = base.join(href).ok()?;
+            if url.path().contains("/comments/") {
+                return None;
+            }
+            let url = UrlWrapper::from_str(url.as_str()).ok()?;
+            Some((format, url))
+        })
+        .collect();
+    feeds.sort_by_key(|(format, _)| *format == FeedFormat::Atom);
+    feeds.into_iter().map(|(_, url)| url).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    /// [`FeedSource`] that returns a fixed result.
+    struct StubFeedSource {
+        name: &'static str,
+        result: Option<FeedSourceResult>,
+        error: bool,
+    }
+
+    #[async_trait]
+    impl FeedSource for StubFeedSource {
+        fn get_name(&self) -> &'static str {
+            self.name
+        }
+
+        async fn get_feed(
+            &self,
+            _slug: &Slug,
+            _url: &UrlWrapper,
+            _html: &str,
+        ) -> Result<Option<FeedSourceResult>, Report<FeedSourceError>> {
+            if self.error {
+                return Err(Report::new(FeedSourceError::Request));
+            }
+            Ok(self.result.clone())
+        }
+    }
+
+    fn get_url(result: FeedSourceResult) -> UrlWrapper {
+        let FeedSourceResult::Url(url) = result else {
+            unreachable!("should discover a feed URL");
+        };
+        url
+    }
+
+    async fn create_handler(page: &str, sources: Vec<Arc<dyn FeedSource>>) -> FetchHandler {
+        let handler = MockServices::new()
+            .with_http(|http| http.with_string(page, "<html></html>".to_owned()))
+            .create()
+            .await
+            .get_async::<FetchHandler>()
+            .await
+            .expect("should be able to get handler");
+        FetchHandler {
+            sources: Arc::new(FeedSources::new(sources)),
+            ..(*handler).clone()
+        }
+    }
+
+    #[test]
+    fn get_alternate_feed_urls__relative_and_ordered() {
+        // Arrange
+        let html = Html::parse_document(
+            r#"<html><head>
+<link rel="alternate" type="application/atom+xml" href="/atom.xml">
+<link rel="alternate" type="application/rss+xml" title="Comments" href="/comments/feed/">
+<link rel="stylesheet" href="/style.css">
+<link rel="alternate" type="application/rss+xml" href="episodes/feed.rss">
+</head></html>"#,
+        );
+        let base = Url::parse("https://example.com/show/").expect("URL should parse");
+
+        // Act
+        let urls = get_alternate_feed_urls(&html, &base);
+
+        // Assert
+        let urls: Vec<String> = urls.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://example.com/show/episodes/feed.rss",
+                "https://example.com/atom.xml",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn discover_feed__alternate_link() {
+        // Arrange
+        let page = "https://example.com/";
+        let html = r#"<html><head><link rel="alternate" type="application/rss+xml" href="/podcast.rss"></head></html>"#;
+        let handler = MockServices::new()
+            .with_http(|http| http.with_string(page, html.to_owned()))
+            .create()
+            .await
+            .get_async::<FetchHandler>()
+            .await
+            .expect("should be able to get handler");
+        let url = UrlWrapper::from_str(page).expect("URL should parse");
+
+        // Act
+        let result = handler
+            .discover_feed(handler.http.as_ref(), &MockFeeds::podcast_slug(), &url)
+            .await;
+
+        // Assert
+        let feed_url = get_url(result.assert_ok_debug());
+        assert_eq!(feed_url.as_str(), "https://example.com/podcast.rss");
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn discover_feed__common_path() {
+        // Arrange
+        let page = "https://example.com/";
+        let feed = "https://example.com/feed.xml";
+        let handler = MockServices::new()
+            .with_http(|http| {
+                http.with_string(page, "<html></html>".to_owned())
+                    .with_string(feed, String::new())
+                    .with_content_type(feed, "application/rss+xml")
+            })
+            .create()
+            .await
+            .get_async::<FetchHandler>()
+            .await
+            .expect("should be able to get handler");
+        let url = UrlWrapper::from_str(page).expect("URL should parse");
+
+        // Act
+        let result = handler
+            .discover_feed(handler.http.as_ref(), &MockFeeds::podcast_slug(), &url)
+            .await;
+
+        // Assert
+        let feed_url = get_url(result.assert_ok_debug());
+        assert_eq!(feed_url.as_str(), feed);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn discover_feed__not_found() {
+        // Arrange
+        let page = "https://example.com/";
+        let handler = MockServices::new()
+            .with_http(|http| http.with_string(page, "<html></html>".to_owned()))
+            .create()
+            .await
+            .get_async::<FetchHandler>()
+            .await
+            .expect("should be able to get handler");
+        let url = UrlWrapper::from_str(page).expect("URL should parse");
+
+        // Act
+        let result = handler
+            .discover_feed(handler.http.as_ref(), &MockFeeds::podcast_slug(), &url)
+            .await;
+
+        // Assert
+        let report = result.assert_err_debug();
+        assert_eq!(report.current_context(), &DiscoverFeedError::NotFound);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn discover_feed__source_error_continues() {
+        // Arrange
+        let page = "https://example.com/";
+        let feed = "https://example.com/source.rss";
+        let handler = create_handler(
+            page,
+            vec![
+                Arc::new(StubFeedSource {
+                    name: "Failing",
+                    result: None,
+                    error: true,
+                }),
+                Arc::new(StubFeedSource {
+                    name: "Working",
+                    result: Some(FeedSourceResult::Url(
+                        UrlWrapper::from_str(feed).expect("URL should parse"),
+                    )),
+                    error: false,
+                }),
+            ],
+        )
+        .await;
+        let url = UrlWrapper::from_str(page).expect("URL should parse");
+
+        // Act
+        let result = handler
+            .discover_feed(handler.http.as_ref(), &MockFeeds::podcast_slug(), &url)
+            .await;
+
+        // Assert
+        let feed_url = get_url(result.assert_ok_debug());
+        assert_eq!(feed_url.as_str(), feed);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn discover_feed__source_errors_not_found() {
+        // Arrange
+        let page = "https://example.com/";
+        let handler = create_handler(
+            page,
+            vec![Arc::new(StubFeedSource {
+                name: "Failing",
+                result: None,
+                error: true,
+            })],
+        )
+        .await;
+        let url = UrlWrapper::from_str(page).expect("URL should parse");
+
+        // Act
+        let result = handler
+            .discover_feed(handler.http.as_ref(), &MockFeeds::podcast_slug(), &url)
+            .await;
+
+        // Assert
+        let report = result.assert_err_debug();
+        assert_eq!(report.current_context(), &DiscoverFeedError::NotFound);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn fetch_feed__source_feed() {
+        // Arrange
+        let page = "https://example.com/";
+        let feed = PodcastFeed::example();
+        let handler = create_handler(
+            page,
+            vec![Arc::new(StubFeedSource {
+                name: "Files",
+                result: Some(FeedSourceResult::Feed(Box::new(feed.clone()))),
+                error: false,
+            })],
+        )
+        .await;
+        let url = UrlWrapper::from_str(page).expect("URL should parse");
+
+        // Act
+        let result = handler
+            .fetch_feed(&MockFeeds::podcast_slug(), &url, None)
+            .await;
+
+        // Assert
+        let fetched = result.assert_ok_debug();
+        assert_eq!(fetched.podcast.title, feed.podcast.title);
+        assert_eq!(fetched.episodes.len(), feed.episodes.len());
+        assert_eq!(fetched.podcast.feed_url.as_ref(), Some(&url));
+    }
+}
@@ -0,0 +1,105 @@
+/// Syndication format of a podcast feed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeedFormat {
+    /// RSS 2.0
+    Rss,
+    /// Atom 1.0
+    ///
+    /// <https://www.rfc-editor.org/rfc/rfc4287>
+    Atom,
+}
+
+impl FeedFormat {
+    /// Check if a `Content-Type` could be an RSS or Atom feed.
+    #[must_use]
+    pub fn is_feed_content_type(content_type: &str) -> bool {
+        matches!(content_type, "application/xml" | "text/xml")
+            || Self::from_content_type(content_type).is_some()
+    }
+
+    /// Determine the format from a specific `Content-Type`.
+    ///
+    /// Returns `None` for generic XML content types.
+    #[must_use]
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        match content_type {
+            "application/rss+xml" => Some(Self::Rss),
+            "application/atom+xml" => Some(Self::Atom),
+            _ => None,
+        }
+    }
+
+    /// Determine the format from the root element of an XML document.
+    ///
+    /// Skips the XML declaration, processing instructions, comments and doctype.
+    #[must_use]
+    pub fn sniff(xml: &str) -> Option<Self> {
+        let mut remaining = xml;
+        loop {
+            let start = remaining.find('<')?;
+            remaining = remaining.get(start + 1..)?;
+            if remaining.starts_with('?') || remaining.starts_with('!') {
+                let end = if remaining.starts_with("!--") {
+                    remaining.find("-->")? + 2
+                } else {
+                    remaining.find('>')?
+                };
+                remaining = remaining.get(end + 1..)?;
+                continue;
+            }
+            let name: String = remaining
+                .chars()
+                .take_while(|c| !c.is_whitespace() && *c != '>' && *c != '/')
+                .collect();
+            let local_name = name.rsplit(':').next().unwrap_or_default();
+            return match local_name {
+                "rss" => Some(Self::Rss),
+                "feed" => Some(Self::Atom),
+                _ => None,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sniff_rss() {
+        // Arrange
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- generated -->
+<rss version="2.0"><channel></channel></rss>"#;
+
+        // Act
+        let format = FeedFormat::sniff(xml);
+
+        // Assert
+        assert_eq!(format, Some(FeedFormat::Rss));
+    }
+
+    #[test]
+    fn sniff_atom() {
+        // Arrange
+        let xml = r#"<?xml version="1.0"?><feed xmlns="http://www.w3.org/2005/Atom"></feed>"#;
+
+        // Act
+        let format = FeedFormat::sniff(xml);
+
+        // Assert
+        assert_eq!(format, Some(FeedFormat::Atom));
+    }
+
+    #[test]
+    fn sniff_html() {
+        // Arrange
+        let xml = "<!DOCTYPE html><html><head></head></html>";
+
+        // Act
+        let format = FeedFormat::sniff(xml);
+
+        // Assert
+        assert_eq!(format, None);
+    }
+}
@@ -0,0 +1,69 @@
+use super::simplecast::SimplecastFeedSource;
+use crate::prelude::*;
+
+/// Adapter that resolves the feed of a page hosted by a specific provider.
+///
+/// Sources are tried in order by [`FetchHandler`] when a URL is not a feed and
+/// the page does not advertise one.
+#[async_trait]
+pub trait FeedSource: Send + Sync {
+    /// Name of the source for logging.
+    fn get_name(&self) -> &'static str;
+
+    /// Resolve the feed of a page.
+    ///
+    /// - Returns the URL of an RSS or Atom feed if the host has one
+    /// - Otherwise returns a feed built from the host's API or files
+    /// - Returns `Ok(None)` if the page is not supported by this source
+    async fn get_feed(
+        &self,
+        slug: &Slug,
+        url: &UrlWrapper,
+        html: &str,
+    ) -> Result<Option<FeedSourceResult>, Report<FeedSourceError>>;
+}
+
+/// Feed resolved by a [`FeedSource`] or by discovery.
+#[derive(Clone, Debug)]
+pub enum FeedSourceResult {
+    /// URL of an RSS or Atom feed to fetch and parse
+    Url(UrlWrapper),
+    /// Feed built by a source for a host without an RSS or Atom feed
+    Feed(Box<PodcastFeed>),
+}
+
+/// Ordered collection of every registered [`FeedSource`].
+pub struct FeedSources {
+    sources: Vec<Arc<dyn FeedSource>>,
+}
+
+impl FeedSources {
+    /// Create a collection from specific sources.
+    #[must_use]
+    pub fn new(sources: Vec<Arc<dyn FeedSource>>) -> Self {
+        Self { sources }
+    }
+
+    /// Iterate the sources in the order they should be tried.
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<dyn FeedSource>> {
+        self.sources.iter()
+    }
+}
+
+impl FromServicesAsync for FeedSources {
+    type Error = ResolveError;
+
+    async fn from_services_async(services: &ServiceProvider) -> Result<Self, Report<ResolveError>> {
+        let simplecast: Arc<dyn FeedSource> = services.get_async::<SimplecastFeedSource>().await?;
+        Ok(Self::new(vec![simplecast]))
+    }
+}
+
+/// Errors from a [`FeedSource`].
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum FeedSourceError {
+    #[error("Unable to request source API")]
+    Request,
+    #[error("Source does not have a feed")]
+    NoFeed,
+}
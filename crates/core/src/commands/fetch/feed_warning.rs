@@ -0,0 +1,90 @@
+use crate::prelude::*;
+
+/// An item that was skipped or had a value coerced while parsing a feed.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct FeedWarning {
+    /// Title of the item, or its GUID if it has no title
+    pub item: String,
+    /// What happened to the item
+    pub kind: FeedWarningKind,
+    /// Value from the feed that was coerced or ignored, or the tag that was ignored
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// Reason the item or tag was skipped
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Kinds of [`FeedWarning`].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedWarningKind {
+    /// Item could not be converted to an episode so it was skipped
+    Skipped,
+    /// Item has no enclosure so it was skipped
+    NoEnclosure,
+    /// Published date was not RFC 2822 but could be parsed leniently
+    PublishedAtCoerced,
+    /// Duration was not in a colon form but could be parsed leniently
+    DurationCoerced,
+    /// Duration could not be parsed so it was ignored
+    DurationIgnored,
+    /// Optional tag had a missing or invalid attribute so it was ignored
+    TagIgnored,
+}
+
+impl FeedWarning {
+    /// Create a warning for an item that was skipped.
+    #[must_use]
+    pub fn skipped(item: &str, kind: FeedWarningKind, reason: String) -> Self {
+        Self {
+            item: item.to_owned(),
+            kind,
+            value: None,
+            reason: Some(reason),
+        }
+    }
+
+    /// Create a warning for a value that was coerced or ignored.
+    #[must_use]
+    pub fn value(item: &str, kind: FeedWarningKind, value: &str) -> Self {
+        Self {
+            item: item.to_owned(),
+            kind,
+            value: Some(value.to_owned()),
+            reason: None,
+        }
+    }
+
+    /// Create a warning for an optional tag that was ignored.
+    #[must_use]
+    pub fn tag(item: &str, tag: &str, reason: String) -> Self {
+        Self {
+            item: item.to_owned(),
+            kind: FeedWarningKind::TagIgnored,
+            value: Some(tag.to_owned()),
+            reason: Some(reason),
+        }
+    }
+}
+
+impl Display for FeedWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let message = match self.kind {
+            FeedWarningKind::Skipped => "Skipped unparseable episode",
+            FeedWarningKind::NoEnclosure => "Skipped episode without an enclosure",
+            FeedWarningKind::PublishedAtCoerced => "Parsed non-standard published date",
+            FeedWarningKind::DurationCoerced => "Parsed non-standard duration",
+            FeedWarningKind::DurationIgnored => "Ignored unparseable duration",
+            FeedWarningKind::TagIgnored => "Ignored invalid tag",
+        };
+        write!(f, "{message}: {}", self.item)?;
+        if let Some(value) = &self.value {
+            write!(f, " ({value})")?;
+        }
+        if let Some(reason) = &self.reason {
+            write!(f, ": {reason}")?;
+        }
+        Ok(())
+    }
+}
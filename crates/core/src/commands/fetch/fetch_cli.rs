@@ -20,9 +20,58 @@ impl FetchCliCommand {
             warn!("{}", error.render());
         }
         info!("Fetched {} podcasts", status.succeeded.len());
+        let unchanged = status
+            .succeeded
+            .iter()
+            .filter(|(_request, response)| !response.modified)
+            .count();
+        if unchanged > 0 {
+            info!("{unchanged} podcasts have not been modified");
+        }
+        let removed: usize = status
+            .succeeded
+            .iter()
+            .map(|(_request, response)| response.episodes_removed)
+            .sum();
+        if removed > 0 {
+            warn!("{removed} episodes have been removed from their feeds");
+        }
+        let replaced: usize = status
+            .succeeded
+            .iter()
+            .map(|(_request, response)| response.episodes_replaced)
+            .sum();
+        if replaced > 0 {
+            warn!(
+                "{replaced} downloaded episodes have a new enclosure. Use `download --replaced` to fetch them again"
+            );
+        }
+        for (request, response) in &status.succeeded {
+            for warning in &response.warnings {
+                warn!(podcast = %request.slug, "{warning}");
+            }
+            for collision in &response.collisions {
+                warn!(podcast = %request.slug, "{collision}");
+            }
+        }
         if !status.failed.is_empty() {
             warn!("Failed to fetch {} podcasts", status.failed.len());
         }
+        self.report_auto_downloads().await;
         Ok(())
     }
+
+    /// Log the results of downloads queued by auto-download rules.
+    async fn report_auto_downloads(&self) {
+        let status = self.cli_runner.take::<DownloadRequest>().await;
+        for (_request, error) in &status.failed {
+            warn!("{}", error.render());
+        }
+        if !status.succeeded.is_empty() {
+            info!("Auto-downloaded {} episodes", status.succeeded.len());
+        }
+        if !status.failed.is_empty() {
+            warn!("Failed to auto-download {} episodes", status.failed.len());
+        }
+    }
 }
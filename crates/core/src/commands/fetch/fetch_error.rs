@@ -9,36 +9,49 @@ pub enum FetchError {
     NoPodcast,
     #[error("Podcast does not have a stored feed URL")]
     NoFeedUrl,
-    #[error("Unable to fetch or parse RSS feed")]
+    #[error("Unable to fetch or parse feed")]
     Rss,
     #[error("Unable to save podcast")]
     Save,
+    #[error("Unable to record fetch history")]
+    History,
+    #[error("Unable to queue download")]
+    Queue,
     #[error("Feed redirect loop detected")]
     RedirectLoop,
     #[error("Too many feed redirects")]
     TooManyRedirects,
 }
 
-/// Errors from fetching an RSS feed.
+/// Errors from fetching an RSS or Atom feed.
 #[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
 pub enum FetchRssError {
     #[error("Unable to get feed")]
     Xml,
     #[error("An I/O error occurred")]
     Open,
-    #[error("Unable to parse RSS")]
+    #[error("Unable to parse feed")]
     Parse,
-    #[error("Unable to convert RSS")]
+    #[error("Unable to convert feed")]
     Convert,
+    #[error("Unable to discover feed")]
+    Discover,
 }
 
-/// Errors from fetching a Simplecast feed.
+/// Errors from discovering the feed of an HTML page.
 #[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
-pub enum FetchSimplecastError {
+pub enum DiscoverFeedError {
     #[error("Unable to get page")]
     GetPage,
-    #[error("Page does not contain a Simplecast Player")]
-    PlayerNotFound,
+    #[error("Page does not link to a feed")]
+    NotFound,
+    #[error("Unable to resolve feed from source")]
+    Source,
+}
+
+/// Errors from fetching a Simplecast feed.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum FetchSimplecastError {
     #[error("Unable to get episode")]
     GetEpisode,
     #[error("Unable to get podcast")]
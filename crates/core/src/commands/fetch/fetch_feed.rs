@@ -1,5 +1,7 @@
+use super::podcast_from_atom::PodcastFromAtom;
 use super::podcast_from_rss::PodcastFromRss;
 use crate::prelude::*;
+use atom_syndication::Feed as AtomFeed;
 use rss::Channel as RssChannel;
 
 /// Maximum number of feed redirects to follow.
@@ -9,38 +11,76 @@ impl FetchHandler {
     /// Fetch and parse a podcast feed without saving.
     ///
     /// - Follows `new_feed_url` redirects (up to 10)
-    /// - Sets `feed_url` to the canonical URL
-    /// - Resolves Simplecast URLs to RSS feeds
+    /// - Discovers the feed of HTML pages including Simplecast sites
+    /// - Uses the feed built by a [`FeedSource`] for hosts without an RSS or Atom feed
+    /// - Sets `feed_url` to the canonical URL, which is the discovered feed for HTML pages
+    /// - Parses RSS or Atom by content type or the document root
+    /// - Parses dates and durations leniently
+    /// - Applies `credentials` to requests with the same origin as `url`
     pub async fn fetch_feed(
         &self,
         slug: &Slug,
         url: &UrlWrapper,
+        credentials: Option<&PodcastCredentials>,
     ) -> Result<PodcastFeed, Report<FetchError>> {
-        let (mut feed, url) = self.fetch_feed_with_redirect(slug, url).await?;
-        feed.podcast.feed_url = Some(url);
-        Ok(feed)
+        self.fetch_feed_if_modified(slug, url, &CacheValidators::default(), credentials)
+            .await?
+            .map(|fetched| fetched.feed)
+            .ok_or_else(|| Report::new(FetchError::Rss).attach("Status", 304))
+    }
+
+    /// Fetch and parse a podcast feed without saving if it has changed.
+    ///
+    /// - Sends `validators` as a conditional request for the first URL
+    /// - Returns `Ok(None)` if the server responds `304 Not Modified`
+    /// - Sets `etag` and `last_modified` from the final response
+    /// - Includes the status and URL of the final response
+    pub async fn fetch_feed_if_modified(
+        &self,
+        slug: &Slug,
+        url: &UrlWrapper,
+        validators: &CacheValidators,
+        credentials: Option<&PodcastCredentials>,
+    ) -> Result<Option<FetchedFeed>, Report<FetchError>> {
+        let http = self.http.with_credentials(credentials.cloned(), url);
+        let Some((mut fetched, url)) = self
+            .fetch_feed_with_redirect(http.as_ref(), slug, url, validators)
+            .await?
+        else {
+            return Ok(None);
+        };
+        fetched.feed.podcast.feed_url = Some(url);
+        Ok(Some(fetched))
     }
 
     async fn fetch_feed_with_redirect(
         &self,
+        http: &dyn HttpFetch,
         slug: &Slug,
         url: &UrlWrapper,
-    ) -> Result<(PodcastFeed, UrlWrapper), Report<FetchError>> {
+        validators: &CacheValidators,
+    ) -> Result<Option<(FetchedFeed, UrlWrapper)>, Report<FetchError>> {
         let mut visited = HashSet::new();
         let mut current = url.clone();
+        let mut validators = validators.clone();
         let mut i = 0;
         loop {
-            trace!(%slug, url = %current, "Fetching feed");
-            let feed = self
-                .fetch_feed_without_redirect(slug, &current)
+            trace!(%slug, url = %current.to_redacted_string(), "Fetching feed");
+            let Some((fetched, resolved)) = self
+                .fetch_feed_without_redirect(http, slug, &current, &validators)
                 .await
-                .change_context(FetchError::Rss)?;
+                .change_context(FetchError::Rss)?
+            else {
+                return Ok(None);
+            };
+            visited.insert(current.clone());
+            current = resolved;
             visited.insert(current.clone());
-            let Some(next) = &feed.podcast.new_feed_url else {
-                return Ok((feed, current));
+            let Some(next) = &fetched.feed.podcast.new_feed_url else {
+                return Ok(Some((fetched, current)));
             };
             if next == &current {
-                return Ok((feed, current));
+                return Ok(Some((fetched, current)));
             }
             if visited.contains(next) {
                 return Err(Report::new(FetchError::RedirectLoop).attach_url(next));
@@ -50,45 +90,93 @@ impl FetchHandler {
                     Report::new(FetchError::TooManyRedirects).attach("Limit", MAX_FEED_REDIRECTS)
                 );
             }
-            trace!(%slug, %current, new_feed_url = %next, redirects = i, "Feed includes a `new_feed_url`");
+            trace!(%slug, current = %current.to_redacted_string(), new_feed_url = %next.to_redacted_string(), redirects = i, "Feed includes a `new_feed_url`");
             current = next.clone();
+            validators = CacheValidators::default();
             i += 1;
         }
     }
 
     async fn fetch_feed_without_redirect(
         &self,
+        http: &dyn HttpFetch,
         slug: &Slug,
         url: &UrlWrapper,
-    ) -> Result<PodcastFeed, Report<FetchRssError>> {
-        let content_type = self
-            .http
-            .head(url)
+        validators: &CacheValidators,
+    ) -> Result<Option<(FetchedFeed, UrlWrapper)>, Report<FetchRssError>> {
+        let content_type = http.head(url).await.change_context(FetchRssError::Xml)?;
+        let resolved_url = if FeedFormat::is_feed_content_type(&content_type) {
+            url.clone()
+        } else {
+            match self
+                .discover_feed(http, slug, url)
+                .await
+                .change_context(FetchRssError::Discover)?
+            {
+                FeedSourceResult::Url(feed_url) => feed_url,
+                FeedSourceResult::Feed(feed) => {
+                    let fetched = FetchedFeed {
+                        feed: *feed,
+                        status: 200,
+                        url: url.clone(),
+                        warnings: Vec::new(),
+                    };
+                    return Ok(Some((fetched, url.clone())));
+                }
+            }
+        };
+        let format = FeedFormat::from_content_type(&content_type);
+        let response = http
+            .get_conditional(&resolved_url, validators)
             .await
             .change_context(FetchRssError::Xml)?;
-        let resolved_url = match content_type.as_str() {
-            "application/xml" | "text/xml" => url.clone(),
-            _ => self
-                .get_simplecast_rss(slug, url)
-                .await
-                .change_context(FetchRssError::Convert)?,
+        let ConditionalResponse::Modified {
+            body,
+            validators,
+            status,
+            url,
+        } = response
+        else {
+            trace!(%slug, url = %resolved_url.to_redacted_string(), "Feed has not been modified");
+            return Ok(None);
+        };
+        let mut warnings = Vec::new();
+        let mut feed = parse_feed(&resolved_url, slug, &body, format, &mut warnings)?;
+        feed.podcast.etag = validators.etag;
+        feed.podcast.last_modified = validators.last_modified;
+        let fetched = FetchedFeed {
+            feed,
+            status,
+            url,
+            warnings,
         };
-        self.parse_rss(&resolved_url, slug).await
+        Ok(Some((fetched, resolved_url)))
     }
+}
 
-    async fn parse_rss(
-        &self,
-        url: &UrlWrapper,
-        slug: &Slug,
-    ) -> Result<PodcastFeed, Report<FetchRssError>> {
-        let xml = self
-            .http
-            .get_string(url)
-            .await
-            .change_context(FetchRssError::Xml)?;
-        let reader = BufReader::new(xml.as_bytes());
-        let channel = RssChannel::read_from(reader).change_context(FetchRssError::Parse)?;
-        PodcastFromRss::execute(channel, slug.clone()).change_context(FetchRssError::Convert)
+fn parse_feed(
+    url: &UrlWrapper,
+    slug: &Slug,
+    xml: &str,
+    format: Option<FeedFormat>,
+    warnings: &mut Vec<FeedWarning>,
+) -> Result<PodcastFeed, Report<FetchRssError>> {
+    let format = format
+        .or_else(|| FeedFormat::sniff(xml))
+        .unwrap_or(FeedFormat::Rss);
+    trace!(%slug, url = %url.to_redacted_string(), ?format, "Parsing feed");
+    let reader = BufReader::new(xml.as_bytes());
+    match format {
+        FeedFormat::Rss => {
+            let channel = RssChannel::read_from(reader).change_context(FetchRssError::Parse)?;
+            PodcastFromRss::execute(channel, slug.clone(), warnings)
+                .change_context(FetchRssError::Convert)
+        }
+        FeedFormat::Atom => {
+            let feed = AtomFeed::read_from(reader).change_context(FetchRssError::Parse)?;
+            PodcastFromAtom::execute(feed, slug.clone(), warnings)
+                .change_context(FetchRssError::Convert)
+        }
     }
 }
 
@@ -111,7 +199,9 @@ mod tests {
         let url = UrlWrapper::from_str("https://irlpodcast.org").expect("URL should parse");
 
         // Act
-        let result = handler.fetch_feed(&MockFeeds::podcast_slug(), &url).await;
+        let result = handler
+            .fetch_feed(&MockFeeds::podcast_slug(), &url, None)
+            .await;
 
         // Assert
         let podcast = result.assert_ok_debug();
@@ -132,11 +222,52 @@ mod tests {
 
         // Act
         let result = handler
-            .fetch_feed(&MockFeeds::podcast_slug(), &MockServices::rss_url())
+            .fetch_feed(&MockFeeds::podcast_slug(), &MockServices::rss_url(), None)
             .await;
 
         // Assert
         let podcast = result.assert_ok_debug();
         assert_yaml_snapshot!(podcast);
     }
+
+    #[tokio::test]
+    #[serial]
+    pub async fn fetch_feed_atom() {
+        // Arrange
+        let url = "https://example.com/feed.atom";
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Atom Podcast</title>
+  <id>urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6</id>
+  <updated>2026-01-12T10:00:00Z</updated>
+  <link href="https://example.com/"/>
+  <entry>
+    <title>First episode</title>
+    <id>urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a</id>
+    <updated>2026-01-05T10:00:00Z</updated>
+    <link rel="enclosure" type="audio/mpeg" length="1024" href="https://example.com/episode-1.mp3"/>
+  </entry>
+</feed>"#;
+        let handler = MockServices::new()
+            .with_http(|http| {
+                http.with_string(url, xml.to_owned())
+                    .with_content_type(url, "application/xml")
+            })
+            .create()
+            .await
+            .get_async::<FetchHandler>()
+            .await
+            .expect("should be able to get handler");
+        let url = UrlWrapper::from_str(url).expect("URL should parse");
+
+        // Act
+        let result = handler
+            .fetch_feed(&MockFeeds::podcast_slug(), &url, None)
+            .await;
+
+        // Assert
+        let feed = result.assert_ok_debug();
+        assert_eq!(feed.podcast.title, "Atom Podcast");
+        assert_eq!(feed.episodes.len(), 1);
+    }
 }
@@ -3,12 +3,18 @@ use crate::prelude::*;
 /// Fetches an existing podcast using its stored feed URL.
 ///
 /// - Reads the feed URL from the database
-/// - Fetches and parses the RSS feed
+/// - Fetches and parses the RSS feed unless it has not been modified
 /// - Saves the updated podcast and episodes to the database
+/// - Records the fetch in the podcast's fetch history
+/// - Queues downloads of new episodes that match the podcast's auto-download rules
+///   on the persistent [`DownloadQueue`] once the web server has restored it
 #[derive(Clone, FromServicesAsync)]
 pub struct FetchHandler {
     pub(super) http: Arc<dyn HttpFetch>,
     pub(super) metadata: Arc<MetadataRepository>,
+    pub(super) sources: Arc<FeedSources>,
+    pub(super) queue: Arc<CommandQueue>,
+    pub(super) download_queue: Arc<DownloadQueue>,
 }
 
 #[async_trait]
@@ -17,28 +23,136 @@ impl Execute<FetchRequest, FetchResponse, Report<FetchError>> for FetchHandler {
     async fn execute(&self, request: &FetchRequest) -> Result<FetchResponse, Report<FetchError>> {
         trace!(slug = %request.slug, "Getting feed URL");
         let stored_url = self.get_feed_url(&request.slug).await?;
+        let (podcast_key, validators) = self
+            .metadata
+            .get_cache_validators(&request.slug)
+            .await
+            .change_context(FetchError::Repository)?
+            .ok_or(FetchError::NoPodcast)?;
+        let credentials = self
+            .metadata
+            .get_credentials(&request.slug)
+            .await
+            .change_context(FetchError::Repository)?;
         trace!(slug = %request.slug, "Fetching feed");
-        let feed = self.fetch_feed(&request.slug, &stored_url).await?;
-        trace!(slug = %request.slug, episodes = feed.episodes.len(), "Fetched feed");
-        let response = self
+        let Some(fetched) = self
+            .fetch_feed_if_modified(
+                &request.slug,
+                &stored_url,
+                &validators,
+                credentials.as_ref(),
+            )
+            .await?
+        else {
+            trace!(slug = %request.slug, "Podcast feed has not been modified");
+            let response = FetchResponse {
+                podcast_key,
+                podcast_slug: request.slug.clone(),
+                episodes_updated: 0,
+                episodes_inserted: 0,
+                episodes_removed: 0,
+                episodes_replaced: 0,
+                modified: false,
+                changes: EpisodeChanges::default(),
+                warnings: Vec::new(),
+                collisions: Vec::new(),
+                inserted_keys: Vec::new(),
+                auto_downloads: Vec::new(),
+            };
+            self.record_history(&response, 304, stored_url).await?;
+            return Ok(response);
+        };
+        let FetchedFeed {
+            feed,
+            status,
+            url,
+            warnings,
+        } = fetched;
+        trace!(slug = %request.slug, episodes = feed.episodes.len(), warnings = warnings.len(), "Fetched feed");
+        let mut response = self
             .metadata
             .update_feed(feed)
             .await
             .change_context(FetchError::Save)?;
+        response.warnings = warnings;
+        self.record_history(&response, status, url).await?;
+        response.auto_downloads = self
+            .queue_auto_downloads(response.podcast_key, &response.inserted_keys)
+            .await
+            .unwrap_or_else(|error| {
+                warn!(slug = %request.slug, error = %error.render(), "Unable to queue auto-downloads");
+                Vec::new()
+            });
         trace!(
             slug = %request.slug,
             podcast_key = response.podcast_key,
             episodes_updated = response.episodes_updated,
             episodes_inserted = response.episodes_inserted,
+            episodes_removed = response.episodes_removed,
+            episodes_replaced = response.episodes_replaced,
+            auto_downloads = response.auto_downloads.len(),
             "Fetched podcast"
         );
         Ok(response)
     }
 }
 
+impl FetchHandler {
+    async fn record_history(
+        &self,
+        response: &FetchResponse,
+        status: u16,
+        url: UrlWrapper,
+    ) -> Result<(), Report<FetchError>> {
+        let entry = FetchHistoryInfo {
+            primary_key: u32::default(),
+            podcast_key: response.podcast_key,
+            fetched_at: Utc::now().fixed_offset(),
+            status,
+            url: Some(url),
+            modified: response.modified,
+            episodes_inserted: to_count(response.episodes_inserted),
+            episodes_updated: to_count(response.episodes_updated),
+            episodes_removed: to_count(response.episodes_removed),
+            changes: response.changes.clone(),
+        };
+        self.metadata
+            .insert_fetch_history(entry)
+            .await
+            .change_context(FetchError::History)
+            .attach("Podcast", response.podcast_slug.clone())
+    }
+}
+
+fn to_count(value: usize) -> u32 {
+    u32::try_from(value).unwrap_or(u32::MAX)
+}
+
 #[cfg(test)]
 mod tests {
+    #![allow(non_snake_case)]
     use super::*;
+    use sea_orm::EntityTrait;
+
+    /// RSS feed with two items that have no GUID.
+    const NO_GUID_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0">
+  <channel>
+    <title>No GUID Podcast</title>
+    <link>https://example.com/</link>
+    <description>A podcast whose items have no GUID</description>
+    <item>
+      <title>First episode</title>
+      <pubDate>Mon, 05 Jan 2026 10:00:00 +0000</pubDate>
+      <enclosure url="https://example.com/episode-1.mp3" length="1024" type="audio/mpeg"/>
+    </item>
+    <item>
+      <title>Second episode</title>
+      <pubDate>Mon, 12 Jan 2026 10:00:00 +0000</pubDate>
+      <enclosure url="https://example.com/episode-2.mp3" length="2048" type="audio/mpeg"/>
+    </item>
+  </channel>
+</rss>"#;
 
     #[tokio::test]
     #[serial]
@@ -52,6 +166,7 @@ mod tests {
         let add_request = AddRequest {
             slug: MockFeeds::podcast_slug(),
             feed_url: MockServices::rss_url(),
+            credentials: None,
         };
         add_handler
             .execute(&add_request)
@@ -72,4 +187,293 @@ mod tests {
         let response = result.assert_ok_debug();
         assert!(response.episodes_updated > 0, "updated");
     }
+
+    #[tokio::test]
+    #[serial]
+    pub async fn fetch_handler__not_modified() {
+        // Arrange
+        let services = MockServices::new()
+            .with_rss_feed()
+            .with_http(|http| http.with_etag(MockServices::rss_url().as_str(), "\"abc\""))
+            .create()
+            .await;
+        let add_handler = services
+            .get_async::<AddHandler>()
+            .await
+            .expect("should be able to get add handler");
+        let add_request = AddRequest {
+            slug: MockFeeds::podcast_slug(),
+            feed_url: MockServices::rss_url(),
+            credentials: None,
+        };
+        add_handler
+            .execute(&add_request)
+            .await
+            .expect("should be able to add podcast");
+        let handler = services
+            .get_async::<FetchHandler>()
+            .await
+            .expect("should be able to get fetch handler");
+        let request = FetchRequest {
+            slug: MockFeeds::podcast_slug(),
+        };
+
+        // Act
+        let result = handler.execute(&request).await;
+
+        // Assert
+        let response = result.assert_ok_debug();
+        assert!(!response.modified);
+        assert_eq!(response.episodes_updated, 0);
+        assert_eq!(response.episodes_inserted, 0);
+        let metadata = services
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let history = metadata
+            .get_fetch_history(MockFeeds::podcast_slug(), 10)
+            .await
+            .assert_ok_debug();
+        let statuses: Vec<u16> = history.iter().map(|entry| entry.status).collect();
+        assert_eq!(statuses, vec![304]);
+    }
+
+    #[tokio::test]
+    #[serial]
+    pub async fn fetch_handler__credentials() {
+        // Arrange
+        let credentials = PodcastCredentials {
+            auth: Some(PodcastAuth::Basic {
+                username: "user".to_owned(),
+                password: Some("password".to_owned()),
+            }),
+            ..PodcastCredentials::default()
+        };
+        let required = credentials.clone();
+        let services = MockServices::new()
+            .with_rss_feed()
+            .with_http(|http| {
+                http.with_required_credentials(MockServices::rss_url().as_str(), required)
+            })
+            .create()
+            .await;
+        let add_handler = services
+            .get_async::<AddHandler>()
+            .await
+            .expect("should be able to get add handler");
+        let add_request = AddRequest {
+            slug: MockFeeds::podcast_slug(),
+            feed_url: MockServices::rss_url(),
+            credentials: Some(credentials),
+        };
+        add_handler
+            .execute(&add_request)
+            .await
+            .expect("should be able to add podcast");
+        let handler = services
+            .get_async::<FetchHandler>()
+            .await
+            .expect("should be able to get fetch handler");
+        let request = FetchRequest {
+            slug: MockFeeds::podcast_slug(),
+        };
+
+        // Act
+        let result = handler.execute(&request).await;
+
+        // Assert
+        let response = result.assert_ok_debug();
+        assert!(response.modified);
+    }
+
+    #[tokio::test]
+    #[serial]
+    pub async fn fetch_handler__auto_download() {
+        // Arrange
+        let services = MockServices::new().with_rss_feed().create().await;
+        let add_handler = services
+            .get_async::<AddHandler>()
+            .await
+            .expect("should be able to get add handler");
+        let add_request = AddRequest {
+            slug: MockFeeds::podcast_slug(),
+            feed_url: MockServices::rss_url(),
+            credentials: None,
+        };
+        add_handler
+            .execute(&add_request)
+            .await
+            .expect("should be able to add podcast");
+        let metadata = services
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let rules = AutoDownloadRules {
+            latest: Some(1),
+            ..AutoDownloadRules::default()
+        };
+        metadata
+            .set_auto_download(&MockFeeds::podcast_slug(), Some(rules))
+            .await
+            .assert_ok_debug();
+        episode::Entity::delete_many()
+            .exec(&metadata.db)
+            .await
+            .assert_ok_debug();
+        let handler = services
+            .get_async::<FetchHandler>()
+            .await
+            .expect("should be able to get fetch handler");
+        let request = FetchRequest {
+            slug: MockFeeds::podcast_slug(),
+        };
+
+        // Act
+        let result = handler.execute(&request).await;
+
+        // Assert
+        let response = result.assert_ok_debug();
+        assert!(response.episodes_inserted > 1);
+        assert_eq!(response.inserted_keys.len(), response.episodes_inserted);
+        assert_eq!(response.auto_downloads.len(), 1);
+        let runner = services
+            .get_async::<CommandRunner<CommandInfo>>()
+            .await
+            .expect("should be able to get runner");
+        let commands = runner.get_commands().await;
+        for download in &response.auto_downloads {
+            assert!(commands.contains_key(&CommandRequest::from(*download)));
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    pub async fn fetch_handler__auto_download_restored_queue() {
+        // Arrange
+        let services = MockServices::new().with_rss_feed().create().await;
+        let add_handler = services
+            .get_async::<AddHandler>()
+            .await
+            .expect("should be able to get add handler");
+        let add_request = AddRequest {
+            slug: MockFeeds::podcast_slug(),
+            feed_url: MockServices::rss_url(),
+            credentials: None,
+        };
+        add_handler
+            .execute(&add_request)
+            .await
+            .expect("should be able to add podcast");
+        let metadata = services
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let rules = AutoDownloadRules {
+            latest: Some(1),
+            ..AutoDownloadRules::default()
+        };
+        metadata
+            .set_auto_download(&MockFeeds::podcast_slug(), Some(rules))
+            .await
+            .assert_ok_debug();
+        episode::Entity::delete_many()
+            .exec(&metadata.db)
+            .await
+            .assert_ok_debug();
+        let download_queue = services
+            .get_async::<DownloadQueue>()
+            .await
+            .expect("should be able to get download queue");
+        download_queue.restore().await.assert_ok_debug();
+        let handler = services
+            .get_async::<FetchHandler>()
+            .await
+            .expect("should be able to get fetch handler");
+        let request = FetchRequest {
+            slug: MockFeeds::podcast_slug(),
+        };
+
+        // Act
+        let result = handler.execute(&request).await;
+
+        // Assert
+        let response = result.assert_ok_debug();
+        assert!(response.episodes_inserted > 1);
+        assert_eq!(response.inserted_keys.len(), response.episodes_inserted);
+        assert_eq!(response.auto_downloads.len(), 1);
+        let jobs = download_queue.get_jobs().await.assert_ok_debug();
+        let episodes: Vec<_> = jobs.iter().map(|job| job.episode_key).collect();
+        let expected: Vec<_> = response
+            .auto_downloads
+            .iter()
+            .map(|download| download.episode)
+            .collect();
+        assert_eq!(episodes, expected);
+    }
+
+    #[tokio::test]
+    #[serial]
+    pub async fn fetch_handler__no_guid() {
+        // Arrange
+        let url = "https://example.com/no-guid.xml";
+        let services = MockServices::new()
+            .with_http(|http| {
+                http.with_string(url, NO_GUID_XML.to_owned())
+                    .with_content_type(url, "application/rss+xml")
+            })
+            .create()
+            .await;
+        let add_handler = services
+            .get_async::<AddHandler>()
+            .await
+            .expect("should be able to get add handler");
+        let add_request = AddRequest {
+            slug: MockFeeds::podcast_slug(),
+            feed_url: UrlWrapper::from_str(url).expect("URL should parse"),
+            credentials: None,
+        };
+        add_handler
+            .execute(&add_request)
+            .await
+            .expect("should be able to add podcast");
+        let handler = services
+            .get_async::<FetchHandler>()
+            .await
+            .expect("should be able to get fetch handler");
+        let request = FetchRequest {
+            slug: MockFeeds::podcast_slug(),
+        };
+
+        // Act
+        let result = handler.execute(&request).await;
+
+        // Assert
+        let response = result.assert_ok_debug();
+        assert_eq!(response.episodes_inserted, 0);
+        assert_eq!(response.episodes_updated, 2);
+        assert_eq!(response.episodes_removed, 0);
+        assert_eq!(response.collisions.len(), 2);
+        let metadata = services
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let feed = metadata
+            .get_feed_by_slug(MockFeeds::podcast_slug(), None)
+            .await
+            .assert_ok_debug()
+            .expect("podcast should exist");
+        let mut source_ids: Vec<_> = feed
+            .episodes
+            .iter()
+            .map(|episode| episode.source_id.clone())
+            .collect();
+        source_ids.sort();
+        assert_eq!(
+            source_ids,
+            vec![
+                "https://example.com/episode-1.mp3",
+                "https://example.com/episode-2.mp3",
+            ]
+        );
+    }
 }
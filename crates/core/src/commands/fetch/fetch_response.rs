@@ -11,4 +11,22 @@ pub struct FetchResponse {
     pub episodes_updated: usize,
     /// Number of episodes inserted.
     pub episodes_inserted: usize,
+    /// Number of episodes newly missing from the feed.
+    pub episodes_removed: usize,
+    /// Number of downloaded episodes whose enclosure URL or size changed.
+    pub episodes_replaced: usize,
+    /// Whether the feed changed since the last fetch.
+    ///
+    /// `false` if the server responded `304 Not Modified`.
+    pub modified: bool,
+    /// Changes to the title, enclosure URL or description of existing episodes.
+    pub changes: EpisodeChanges,
+    /// Items that were skipped or had values coerced while parsing the feed.
+    pub warnings: Vec<FeedWarning>,
+    /// Items that reused the GUID of an earlier item in the feed.
+    pub collisions: Vec<GuidCollision>,
+    /// Database keys of the inserted episodes.
+    pub inserted_keys: Vec<EpisodeKey>,
+    /// Downloads queued by the auto-download rules of the podcast.
+    pub auto_downloads: Vec<DownloadRequest>,
 }
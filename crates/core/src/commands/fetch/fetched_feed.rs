@@ -0,0 +1,14 @@
+use crate::prelude::*;
+
+/// A parsed feed with details of the HTTP response it was read from.
+#[derive(Clone, Debug)]
+pub struct FetchedFeed {
+    /// Parsed podcast and episodes
+    pub feed: PodcastFeed,
+    /// HTTP status code of the feed response, or `200` for a feed built by a [`FeedSource`]
+    pub status: u16,
+    /// URL of the feed response after HTTP and `new_feed_url` redirects
+    pub url: UrlWrapper,
+    /// Items that were skipped or had values coerced while parsing
+    pub warnings: Vec<FeedWarning>,
+}
@@ -0,0 +1,77 @@
+use crate::prelude::*;
+use sea_orm::*;
+
+impl MetadataRepository {
+    /// Get the primary key and cache validators of a podcast by its slug.
+    ///
+    /// - Returns `Ok(None)` if the podcast does not exist
+    pub async fn get_cache_validators(
+        &self,
+        slug: &Slug,
+    ) -> Result<Option<(PodcastKey, CacheValidators)>, DbErr> {
+        let option = get_cache_validators_query(slug)
+            .into_tuple::<(PodcastKey, Option<String>, Option<String>)>()
+            .one(&self.db)
+            .await?
+            .map(|(key, etag, last_modified)| {
+                let validators = CacheValidators {
+                    etag,
+                    last_modified,
+                };
+                (key, validators)
+            });
+        Ok(option)
+    }
+}
+
+fn get_cache_validators_query(slug: &Slug) -> Select<podcast::Entity> {
+    podcast::Entity::find()
+        .select_only()
+        .columns([
+            podcast::Column::PrimaryKey,
+            podcast::Column::Etag,
+            podcast::Column::LastModified,
+        ])
+        .filter(podcast::Column::Slug.eq(slug.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    pub fn _get_cache_validators_query() {
+        // Arrange
+        let slug = MockFeeds::podcast_slug();
+
+        // Act
+        let statement = get_cache_validators_query(&slug).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[tokio::test]
+    pub async fn get_cache_validators() {
+        // Arrange
+        let metadata = MockServices::default()
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+
+        // Act
+        let result = metadata
+            .get_cache_validators(&MockFeeds::podcast_slug())
+            .await;
+
+        // Assert
+        let option = result.assert_ok_debug();
+        assert_eq!(
+            option,
+            Some((MockFeeds::PODCAST_KEY, CacheValidators::default()))
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use crate::prelude::*;
+use sea_orm::*;
+
+impl MetadataRepository {
+    /// Get the credentials of a podcast by its slug.
+    ///
+    /// - Returns `Ok(None)` if the podcast does not exist or has no credentials
+    pub async fn get_credentials(&self, slug: &Slug) -> Result<Option<PodcastCredentials>, DbErr> {
+        let option = get_credentials_query(slug)
+            .into_tuple::<Option<PodcastCredentials>>()
+            .one(&self.db)
+            .await?;
+        Ok(option.flatten())
+    }
+}
+
+fn get_credentials_query(slug: &Slug) -> Select<podcast::Entity> {
+    podcast::Entity::find()
+        .select_only()
+        .column(podcast::Column::Credentials)
+        .filter(podcast::Column::Slug.eq(slug.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    pub fn _get_credentials_query() {
+        // Arrange
+        let slug = MockFeeds::podcast_slug();
+
+        // Act
+        let statement = get_credentials_query(&slug).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[tokio::test]
+    pub async fn get_credentials() {
+        // Arrange
+        let credentials = PodcastCredentials {
+            auth: Some(PodcastAuth::Bearer {
+                token: "token".to_owned(),
+            }),
+            ..PodcastCredentials::default()
+        };
+        let metadata = MockServices::new()
+            .with_metadata_factory(MockFeedsFactory {
+                edit_podcast: Some(|podcast| {
+                    podcast.credentials = Some(PodcastCredentials {
+                        auth: Some(PodcastAuth::Bearer {
+                            token: "token".to_owned(),
+                        }),
+                        ..PodcastCredentials::default()
+                    });
+                }),
+                ..MockFeedsFactory::default()
+            })
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+
+        // Act
+        let result = metadata.get_credentials(&MockFeeds::podcast_slug()).await;
+
+        // Assert
+        let option = result.assert_ok_debug();
+        assert_eq!(option, Some(credentials));
+    }
+}
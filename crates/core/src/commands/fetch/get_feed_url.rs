@@ -24,7 +24,10 @@ impl MetadataRepository {
     ///
     /// - Returns `Ok(None)` if the podcast does not exist
     /// - Returns `Ok(Some(None))` if the podcast exists but has no feed URL
-    async fn get_feed_url(&self, slug: &Slug) -> Result<Option<Option<UrlWrapper>>, DbErr> {
+    pub(crate) async fn get_feed_url(
+        &self,
+        slug: &Slug,
+    ) -> Result<Option<Option<UrlWrapper>>, DbErr> {
         get_feed_url_query(slug)
             .into_tuple::<Option<UrlWrapper>>()
             .one(&self.db)
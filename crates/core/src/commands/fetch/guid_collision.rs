@@ -0,0 +1,43 @@
+use crate::prelude::*;
+
+/// An item without a GUID or that reused the GUID of an earlier item in the same feed.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct GuidCollision {
+    /// GUID shared with the earlier item, or empty if the item has no GUID
+    pub guid: String,
+    /// Title of the later item
+    pub title: String,
+    /// How the collision was resolved
+    pub resolution: GuidResolution,
+}
+
+/// How a [`GuidCollision`] was resolved.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuidResolution {
+    /// Item is a duplicate of an earlier item so it was dropped
+    Dropped,
+    /// Item was stored with its enclosure URL as the `source_id`
+    EnclosureUrl,
+    /// Item was stored with its title and published date as the `source_id`
+    TitleAndDate,
+}
+
+impl Display for GuidCollision {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let resolution = match self.resolution {
+            GuidResolution::Dropped => "dropped as a duplicate",
+            GuidResolution::EnclosureUrl => "stored by enclosure URL",
+            GuidResolution::TitleAndDate => "stored by title and date",
+        };
+        if self.guid.is_empty() {
+            write!(f, "Episode has no GUID: {} ({resolution})", self.title)
+        } else {
+            write!(
+                f,
+                "Episode reuses GUID {}: {} ({resolution})",
+                self.guid, self.title
+            )
+        }
+    }
+}
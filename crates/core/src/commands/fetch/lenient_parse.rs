@@ -0,0 +1,406 @@
+//! Tolerant parsing of feed dates and durations.
+//!
+//! Each parser tries the strict standard form first and then falls back to
+//! forms seen in real feeds so the caller can report values that were coerced.
+
+use crate::prelude::*;
+use chrono::{NaiveDate, TimeZone};
+
+/// Result of a lenient parse.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum Parsed<T> {
+    /// Value was in the standard form
+    Strict(T),
+    /// Value was only parseable by a fallback
+    Lenient(T),
+}
+
+/// Formats with a time zone tried after RFC 2822 and RFC 3339.
+const ZONED_FORMATS: [&str; 6] = [
+    "%Y-%m-%dT%H:%M:%S%.f%z",
+    "%Y-%m-%dT%H:%M%z",
+    "%Y-%m-%d %H:%M:%S%.f%z",
+    "%Y-%m-%d %H:%M:%S %z",
+    "%d %b %Y %H:%M:%S %z",
+    "%d %b %Y %H:%M %z",
+];
+
+/// Formats without a time zone which are assumed to be UTC.
+const NAIVE_FORMATS: [&str; 6] = [
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%d %H:%M",
+    "%d %b %Y %H:%M:%S",
+    "%d %b %Y %H:%M",
+];
+
+/// Date only formats which are assumed to be midnight UTC.
+const DATE_FORMATS: [&str; 2] = ["%Y-%m-%d", "%d %b %Y"];
+
+/// Month names in English, French, German, Spanish, Italian, Portuguese and Dutch.
+const MONTHS: [(&str, &[&str]); 12] = [
+    (
+        "Jan",
+        &[
+            "january", "janvier", "janv", "januar", "jän", "enero", "ene", "gennaio", "gen",
+            "janeiro", "januari",
+        ],
+    ),
+    (
+        "Feb",
+        &[
+            "february",
+            "février",
+            "fevrier",
+            "févr",
+            "fevr",
+            "februar",
+            "febrero",
+            "febbraio",
+            "fevereiro",
+            "fev",
+            "februari",
+        ],
+    ),
+    (
+        "Mar",
+        &[
+            "march", "mars", "märz", "maerz", "mär", "marzo", "março", "marco", "maart", "mrt",
+        ],
+    ),
+    ("Apr", &["april", "avril", "avr", "abril", "abr", "aprile"]),
+    ("May", &["mai", "mayo", "maggio", "mag", "maio", "mei"]),
+    (
+        "Jun",
+        &["june", "juin", "juni", "junio", "giugno", "giu", "junho"],
+    ),
+    (
+        "Jul",
+        &[
+            "july", "juillet", "juil", "juli", "julio", "luglio", "lug", "julho",
+        ],
+    ),
+    (
+        "Aug",
+        &["august", "août", "aout", "agosto", "ago", "augustus"],
+    ),
+    (
+        "Sep",
+        &[
+            "september",
+            "sept",
+            "septembre",
+            "septiembre",
+            "settembre",
+            "set",
+            "setembro",
+        ],
+    ),
+    (
+        "Oct",
+        &[
+            "october", "octobre", "oktober", "okt", "octubre", "ottobre", "ott", "outubro", "out",
+        ],
+    ),
+    ("Nov", &["november", "novembre", "noviembre", "novembro"]),
+    (
+        "Dec",
+        &[
+            "december",
+            "décembre",
+            "decembre",
+            "déc",
+            "dezember",
+            "dez",
+            "diciembre",
+            "dic",
+            "dicembre",
+            "dezembro",
+        ],
+    ),
+];
+
+/// Time zone abbreviations not accepted by the RFC 2822 parser.
+const ZONES: [(&str, &str); 8] = [
+    ("UTC", "+0000"),
+    ("Z", "+0000"),
+    ("BST", "+0100"),
+    ("CET", "+0100"),
+    ("CEST", "+0200"),
+    ("MEZ", "+0100"),
+    ("AEST", "+1000"),
+    ("AEDT", "+1100"),
+];
+
+/// Parse the published date of an item.
+///
+/// - RFC 2822 is strict
+/// - RFC 3339 and other ISO 8601 forms are lenient
+/// - RFC 2822 with a missing or wrong weekday, a non-English month name,
+///   a missing time or a named time zone is lenient
+/// - Dates without a time zone are assumed to be UTC
+pub(super) fn parse_published_at(value: &str) -> Option<Parsed<DateTime<FixedOffset>>> {
+    let value = value.trim();
+    if let Ok(date) = DateTime::parse_from_rfc2822(value) {
+        return Some(Parsed::Strict(date));
+    }
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Some(Parsed::Lenient(date));
+    }
+    let normalized = normalize_date(value);
+    let candidates = [value, normalized.as_str()];
+    for candidate in candidates {
+        if let Some(date) = parse_date_formats(candidate) {
+            return Some(Parsed::Lenient(date));
+        }
+    }
+    DateTime::parse_from_rfc2822(&normalized)
+        .ok()
+        .map(Parsed::Lenient)
+}
+
+fn parse_date_formats(value: &str) -> Option<DateTime<FixedOffset>> {
+    if let Some(date) = ZONED_FORMATS
+        .iter()
+        .find_map(|format| DateTime::parse_from_str(value, format).ok())
+    {
+        return Some(date);
+    }
+    if let Some(date) = NAIVE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+    {
+        return Some(Utc.from_utc_datetime(&date).fixed_offset());
+    }
+    DATE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date| Utc.from_utc_datetime(&date).fixed_offset())
+}
+
+/// Remove the weekday and replace month names and time zone abbreviations.
+fn normalize_date(value: &str) -> String {
+    let tokens: Vec<String> = value
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .map(|token| token.trim_end_matches('.'))
+        .enumerate()
+        .filter(|(index, token)| *index != 0 || !is_weekday(token))
+        .map(|(_, token)| normalize_token(token))
+        .collect();
+    tokens.join(" ")
+}
+
+fn normalize_token(token: &str) -> String {
+    if let Some(month) = get_month(token) {
+        return month.to_owned();
+    }
+    if let Some((_, offset)) = ZONES
+        .iter()
+        .find(|(zone, _)| zone.eq_ignore_ascii_case(token))
+    {
+        return (*offset).to_owned();
+    }
+    token.to_owned()
+}
+
+/// Get the English abbreviation of a month name in any supported language.
+fn get_month(token: &str) -> Option<&'static str> {
+    let lower = token.to_lowercase();
+    MONTHS
+        .iter()
+        .find(|(month, names)| {
+            month.eq_ignore_ascii_case(&lower) || names.contains(&lower.as_str())
+        })
+        .map(|(month, _)| *month)
+}
+
+fn is_weekday(token: &str) -> bool {
+    !token.is_empty() && token.chars().all(char::is_alphabetic) && get_month(token).is_none()
+}
+
+/// Parse the `itunes:duration` of an item in seconds.
+///
+/// - Seconds, `MM:SS` and `HH:MM:SS` are strict
+/// - Fractional seconds are lenient and truncated
+/// - Unit forms such as `1h 2m`, `62 min` or `1 hour, 5 minutes` are lenient
+/// - ISO 8601 durations such as `PT1H2M3S` are lenient
+pub(super) fn parse_duration(value: &str) -> Option<Parsed<Duration>> {
+    let value = value.trim();
+    if let Some(duration) = parse_colon_duration(value, false) {
+        return Some(Parsed::Strict(duration));
+    }
+    parse_colon_duration(value, true)
+        .or_else(|| parse_iso_duration(value))
+        .or_else(|| parse_unit_duration(value))
+        .map(Parsed::Lenient)
+}
+
+fn parse_colon_duration(value: &str, allow_fraction: bool) -> Option<Duration> {
+    let parts: Vec<&str> = value.split(':').collect();
+    if parts.len() > 3 || parts.iter().any(|part| part.is_empty()) {
+        return None;
+    }
+    let (last, rest) = parts.split_last()?;
+    let seconds = if allow_fraction {
+        parse_fraction(last)?
+    } else {
+        last.parse::<Duration>().ok()?
+    };
+    rest.iter().rev().zip([60, 60 * 60]).try_fold(
+        seconds,
+        |total, (part, multiplier): (&&str, Duration)| {
+            let value = part.parse::<Duration>().ok()?;
+            total.checked_add(value.checked_mul(multiplier)?)
+        },
+    )
+}
+
+#[allow(
+    clippy::as_conversions,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn parse_fraction(value: &str) -> Option<Duration> {
+    let float = value.parse::<f64>().ok()?;
+    if !float.is_finite() || float < 0.0 || float > f64::from(Duration::MAX) {
+        return None;
+    }
+    Some(float as Duration)
+}
+
+fn parse_iso_duration(value: &str) -> Option<Duration> {
+    let upper = value.to_uppercase();
+    let rest = upper.strip_prefix("PT")?;
+    parse_units(&rest.to_lowercase())
+}
+
+fn parse_unit_duration(value: &str) -> Option<Duration> {
+    let cleaned: String = value
+        .to_lowercase()
+        .replace(',', " ")
+        .split_whitespace()
+        .filter(|token| *token != "and")
+        .collect();
+    parse_units(&cleaned)
+}
+
+/// Parse a sequence of whole numbers each followed by an hour, minute or second unit.
+fn parse_units(value: &str) -> Option<Duration> {
+    if value.is_empty() {
+        return None;
+    }
+    let mut total: Duration = 0;
+    let mut rest = value;
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (number, tail) = rest.split_at(digits);
+        let letters = tail
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(letters);
+        let number = number.parse::<Duration>().ok()?;
+        let multiplier = get_unit_multiplier(unit)?;
+        total = total.checked_add(number.checked_mul(multiplier)?)?;
+        rest = tail;
+    }
+    Some(total)
+}
+
+fn get_unit_multiplier(unit: &str) -> Option<Duration> {
+    match unit {
+        "h" | "hr" | "hrs" | "hour" | "hours" => Some(60 * 60),
+        "m" | "min" | "mins" | "minute" | "minutes" => Some(60),
+        "s" | "sec" | "secs" | "second" | "seconds" => Some(1),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    fn date(value: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(value).expect("should be valid RFC 3339")
+    }
+
+    #[test]
+    fn parse_published_at__rfc2822() {
+        let output = parse_published_at("Mon, 05 Jan 2026 10:00:00 +0000");
+        assert_eq!(output, Some(Parsed::Strict(date("2026-01-05T10:00:00Z"))));
+    }
+
+    #[test]
+    fn parse_published_at__lenient() {
+        let cases = [
+            ("2026-01-05T10:00:00Z", "2026-01-05T10:00:00Z"),
+            ("2026-01-05T10:00:00+0100", "2026-01-05T10:00:00+01:00"),
+            ("2026-01-05 10:00:00", "2026-01-05T10:00:00Z"),
+            ("2026-01-05", "2026-01-05T00:00:00Z"),
+            ("05 Jan 2026 10:00:00 UTC", "2026-01-05T10:00:00Z"),
+            ("Tue, 05 Jan 2026 10:00:00 +0000", "2026-01-05T10:00:00Z"),
+            (
+                "Monday, 05 January 2026 10:00:00 GMT",
+                "2026-01-05T10:00:00Z",
+            ),
+            (
+                "lun., 05 janv. 2026 10:00:00 +0100",
+                "2026-01-05T10:00:00+01:00",
+            ),
+            ("Mo, 05 Mär 2026 10:00:00 CET", "2026-03-05T10:00:00+01:00"),
+            ("5 diciembre 2026 10:00 +0000", "2026-12-05T10:00:00Z"),
+            ("05 Jan 2026", "2026-01-05T00:00:00Z"),
+        ];
+        for (value, expected) in cases {
+            let output = parse_published_at(value);
+            assert_eq!(output, Some(Parsed::Lenient(date(expected))), "{value}");
+        }
+    }
+
+    #[test]
+    fn parse_published_at__invalid() {
+        assert_eq!(parse_published_at("yesterday"), None);
+        assert_eq!(parse_published_at(""), None);
+    }
+
+    #[test]
+    fn parse_duration__strict() {
+        assert_eq!(parse_duration("62"), Some(Parsed::Strict(62)));
+        assert_eq!(parse_duration("1:02"), Some(Parsed::Strict(62)));
+        assert_eq!(parse_duration("01:02:03"), Some(Parsed::Strict(3723)));
+    }
+
+    #[test]
+    fn parse_duration__lenient() {
+        let cases = [
+            ("4330.5", 4330),
+            ("01:02:03.500", 3723),
+            ("1h 2m", 3720),
+            ("1h2m3s", 3723),
+            ("62 min", 3720),
+            ("1 hour, 5 minutes and 3 seconds", 3903),
+            ("PT1H2M3S", 3723),
+            ("pt45m", 2700),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(
+                parse_duration(value),
+                Some(Parsed::Lenient(expected)),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_duration__invalid() {
+        assert_eq!(parse_duration("unknown"), None);
+        assert_eq!(parse_duration("1:2:3:4"), None);
+        assert_eq!(parse_duration("1x"), None);
+        assert_eq!(parse_duration(""), None);
+    }
+}
@@ -1,4 +1,15 @@
 #[cfg(feature = "server")]
+mod auto_download;
+#[cfg(feature = "server")]
+mod dedupe_episodes;
+#[cfg(feature = "server")]
+mod discover_feed;
+#[cfg(feature = "server")]
+mod feed_format;
+#[cfg(feature = "server")]
+mod feed_source;
+mod feed_warning;
+#[cfg(feature = "server")]
 mod fetch_cli;
 mod fetch_error;
 #[cfg(feature = "server")]
@@ -8,9 +19,18 @@ mod fetch_handler;
 mod fetch_request;
 mod fetch_response;
 #[cfg(feature = "server")]
-mod fetch_simplecast;
+mod fetched_feed;
+#[cfg(feature = "server")]
+mod get_cache_validators;
+#[cfg(feature = "server")]
+mod get_credentials;
 #[cfg(feature = "server")]
 mod get_feed_url;
+mod guid_collision;
+#[cfg(feature = "server")]
+mod lenient_parse;
+#[cfg(feature = "server")]
+mod podcast_from_atom;
 #[cfg(feature = "server")]
 mod podcast_from_rss;
 #[cfg(feature = "server")]
@@ -18,6 +38,13 @@ mod simplecast;
 #[cfg(feature = "server")]
 mod update_feed;
 
+#[cfg(feature = "server")]
+pub(crate) use dedupe_episodes::*;
+#[cfg(feature = "server")]
+pub use feed_format::*;
+#[cfg(feature = "server")]
+pub use feed_source::*;
+pub use feed_warning::*;
 #[cfg(feature = "server")]
 pub use fetch_cli::*;
 pub use fetch_error::*;
@@ -26,4 +53,9 @@ pub use fetch_handler::*;
 pub use fetch_request::*;
 pub use fetch_response::*;
 #[cfg(feature = "server")]
+pub use fetched_feed::*;
+pub use guid_collision::*;
+#[cfg(feature = "server")]
+pub use simplecast::SimplecastFeedSource;
+#[cfg(feature = "server")]
 pub use update_feed::*;
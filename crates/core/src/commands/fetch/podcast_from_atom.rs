@@ -0,0 +1,222 @@
+use super::podcast_from_rss::{try_parse, try_parse_url};
+use crate::prelude::*;
+use atom_syndication::{Entry as AtomEntry, Feed as AtomFeed, Link as AtomLink};
+
+pub struct PodcastFromAtom;
+
+impl PodcastFromAtom {
+    /// Convert an Atom feed to a [`PodcastFeed`].
+    ///
+    /// Entries that are skipped are added to `warnings`.
+    pub fn execute(
+        mut feed: AtomFeed,
+        slug: Slug,
+        warnings: &mut Vec<FeedWarning>,
+    ) -> Result<PodcastFeed, Report<PodcastFromAtomError>> {
+        let entries = take(&mut feed.entries);
+        let podcast = podcast_from_atom(feed, slug)?;
+        let mut episodes = Vec::new();
+        for entry in entries {
+            let name = if entry.title.value.is_empty() {
+                entry.id.clone()
+            } else {
+                entry.title.value.clone()
+            };
+            match episode_from_atom(entry) {
+                Ok(episode) => episodes.push(episode),
+                Err(error) => {
+                    warn!(slug = %podcast.slug, name, error = %error.render(), "Skipping unparseable episode");
+                    let context = error.current_context();
+                    let kind = if *context == EpisodeFromAtomError::NoEnclosure {
+                        FeedWarningKind::NoEnclosure
+                    } else {
+                        FeedWarningKind::Skipped
+                    };
+                    warnings.push(FeedWarning::skipped(&name, kind, context.to_string()));
+                }
+            }
+        }
+        let feed = PodcastFeed { podcast, episodes };
+        Ok(feed)
+    }
+}
+
+fn podcast_from_atom(
+    feed: AtomFeed,
+    slug: Slug,
+) -> Result<PodcastInfo, Report<PodcastFromAtomError>> {
+    let categories = feed
+        .categories
+        .into_iter()
+        .map(|category| PodcastCategory {
+            category: category.label.unwrap_or(category.term),
+            sub_category: None,
+        })
+        .collect();
+    let podcast = PodcastInfo {
+        primary_key: u32::default(),
+        slug,
+        feed_url: None,
+        etag: None,
+        last_modified: None,
+        credentials: None,
+        refresh_interval: None,
+        auto_download: None,
+        retention: None,
+        path_template: None,
+        title: feed.title.value,
+        description: feed.subtitle.map(|text| text.value).unwrap_or_default(),
+        image: if let Some(url) = feed.logo.or(feed.icon) {
+            Some(try_parse_url(url, PodcastFromAtomError::ParseImage)?)
+        } else {
+            None
+        },
+        language: feed.lang,
+        categories: PodcastCategories(categories),
+        explicit: false,
+        author: feed.authors.into_iter().next().map(|person| person.name),
+        link: if let Some(link) = find_link(&feed.links, "alternate") {
+            Some(try_parse_url(
+                link.href.clone(),
+                PodcastFromAtomError::ParseLink,
+            )?)
+        } else {
+            None
+        },
+        kind: None,
+        copyright: feed.rights.map(|text| text.value),
+        new_feed_url: None,
+        generator: feed.generator.map(|generator| generator.value),
+        podcast_guid: None,
+        locked: None,
+        funding: PodcastFundings::default(),
+        persons: PodcastPersons::default(),
+    };
+    Ok(podcast)
+}
+
+fn episode_from_atom(entry: AtomEntry) -> Result<EpisodeInfo, Report<EpisodeFromAtomError>> {
+    let enclosure =
+        find_link(&entry.links, "enclosure").ok_or(EpisodeFromAtomError::NoEnclosure)?;
+    if entry.id.is_empty() {
+        return Err(Report::new(EpisodeFromAtomError::NoId));
+    }
+    if entry.title.value.is_empty() {
+        return Err(Report::new(EpisodeFromAtomError::NoTitle));
+    }
+    let description = entry
+        .summary
+        .map(|text| text.value)
+        .or_else(|| entry.content.and_then(|content| content.value));
+    let episode = EpisodeInfo {
+        primary_key: u32::default(),
+        podcast_key: None,
+        file_sub_path: None,
+        image_sub_path: None,
+        removed_from_feed_at: None,
+        enclosure_changed_at: None,
+        played_at: None,
+        title: entry.title.value,
+        source_url: try_parse_url(enclosure.href.clone(), EpisodeFromAtomError::ParseUrl)?,
+        source_file_size: if let Some(length) = &enclosure.length {
+            try_parse(length, EpisodeFromAtomError::ParseFileSize)?
+        } else {
+            0
+        },
+        source_content_type: enclosure.mime_type.clone().unwrap_or_default(),
+        source_id: entry.id,
+        published_at: entry.published.unwrap_or(entry.updated),
+        description,
+        source_duration: None,
+        image: None,
+        explicit: None,
+        itunes_title: None,
+        episode: None,
+        season: None,
+        kind: None,
+        transcripts: EpisodeTranscripts::default(),
+        chapters_url: None,
+        chapters_content_type: None,
+        persons: PodcastPersons::default(),
+        season_name: None,
+    };
+    Ok(episode)
+}
+
+/// Find the first link with a relation.
+///
+/// Links without a `rel` attribute are treated as `alternate`.
+fn find_link<'a>(links: &'a [AtomLink], rel: &str) -> Option<&'a AtomLink> {
+    links.iter().find(|link| link.rel == rel)
+}
+
+/// Errors from parsing a podcast from Atom.
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum PodcastFromAtomError {
+    #[error("Unable to parse podcast image URL")]
+    ParseImage,
+    #[error("Unable to parse podcast link URL")]
+    ParseLink,
+}
+
+/// Errors from parsing an episode from Atom.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum EpisodeFromAtomError {
+    #[error("Entry has no ID")]
+    NoId,
+    #[error("Entry has no title")]
+    NoTitle,
+    #[error("Entry does not have an enclosure link")]
+    NoEnclosure,
+    #[error("Unable to parse enclosure URL")]
+    ParseUrl,
+    #[error("Unable to parse enclosure file size")]
+    ParseFileSize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn podcast_from_atom() {
+        // Arrange
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom" xml:lang="en">
+  <title>Atom Podcast</title>
+  <subtitle>A podcast published as Atom</subtitle>
+  <id>urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6</id>
+  <updated>2026-01-12T10:00:00Z</updated>
+  <link href="https://example.com/"/>
+  <link rel="self" href="https://example.com/feed.atom"/>
+  <logo>https://example.com/cover.png</logo>
+  <author><name>Jane Doe</name></author>
+  <category term="technology" label="Technology"/>
+  <entry>
+    <title>First episode</title>
+    <id>urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a</id>
+    <updated>2026-01-05T10:00:00Z</updated>
+    <published>2026-01-05T09:00:00Z</published>
+    <summary>The first episode</summary>
+    <link href="https://example.com/episode-1"/>
+    <link rel="enclosure" type="audio/mpeg" length="1024" href="https://example.com/episode-1.mp3"/>
+  </entry>
+  <entry>
+    <title>Entry without enclosure</title>
+    <id>urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6b</id>
+    <updated>2026-01-12T10:00:00Z</updated>
+  </entry>
+</feed>"#;
+        let feed = AtomFeed::from_str(xml).expect("should be able to read Atom");
+        let slug = Slug::from_str("atom").expect("should be valid slug");
+
+        // Act
+        let result = PodcastFromAtom::execute(feed, slug, &mut Vec::new());
+
+        // Assert
+        let feed = result.assert_ok();
+        assert_eq!(feed.episodes.len(), 1);
+        assert_yaml_snapshot!(feed);
+    }
+}
@@ -1,25 +1,45 @@
+use super::lenient_parse::{Parsed, parse_duration, parse_published_at};
 use crate::prelude::*;
-use rss::{Channel as RssChannel, Item as RssItem};
+use rss::extension::Extension;
+use rss::{Channel as RssChannel, Enclosure as RssEnclosure, Item as RssItem};
+use std::collections::BTreeMap;
+
+/// Extensions of a single namespace on a channel or item keyed by local name.
+type NamespaceExtensions = BTreeMap<String, Vec<Extension>>;
 
 pub struct PodcastFromRss;
 
 impl PodcastFromRss {
+    /// Convert an RSS channel to a [`PodcastFeed`].
+    ///
+    /// Items that are skipped and values that are coerced are added to `warnings`.
     pub fn execute(
         mut channel: RssChannel,
         slug: Slug,
+        warnings: &mut Vec<FeedWarning>,
     ) -> Result<PodcastFeed, Report<PodcastFromRssError>> {
         let items = take(&mut channel.items);
-        let podcast = podcast_from_rss(channel, slug)?;
+        let mut podcast = podcast_from_rss(channel, slug, warnings)?;
+        if podcast.author.is_none() {
+            podcast.author = items.iter().find_map(|item| item.author.clone());
+        }
         let mut episodes = Vec::new();
         for item in items {
             let name = item
                 .title
                 .clone()
                 .unwrap_or_else(|| item.guid.clone().unwrap_or_default().value);
-            match episode_from_rss(item) {
+            match episode_from_rss(item, &name, warnings) {
                 Ok(episode) => episodes.push(episode),
                 Err(error) => {
                     warn!(slug = %podcast.slug, name, error = %error.render(), "Skipping unparseable episode");
+                    let context = error.current_context();
+                    let kind = if *context == EpisodeFromRssError::NoEnclosure {
+                        FeedWarningKind::NoEnclosure
+                    } else {
+                        FeedWarningKind::Skipped
+                    };
+                    warnings.push(FeedWarning::skipped(&name, kind, context.to_string()));
                 }
             }
         }
@@ -29,26 +49,69 @@ impl PodcastFromRss {
 }
 
 fn podcast_from_rss(
-    channel: RssChannel,
+    mut channel: RssChannel,
     slug: Slug,
+    warnings: &mut Vec<FeedWarning>,
 ) -> Result<PodcastInfo, Report<PodcastFromRssError>> {
-    let itunes = channel.itunes_ext.ok_or(PodcastFromRssError::NoItunes)?;
-    let categories = itunes
-        .categories
-        .into_iter()
-        .map(|category| PodcastCategory {
-            category: category.text,
-            sub_category: category.subcategory.map(|sub_category| sub_category.text),
-        })
-        .collect();
+    let extensions = channel
+        .extensions
+        .remove(PODCAST_NAMESPACE_PREFIX)
+        .unwrap_or_default();
+    let media = channel
+        .extensions
+        .remove(MEDIA_NAMESPACE_PREFIX)
+        .unwrap_or_default();
+    let itunes = channel.itunes_ext.unwrap_or_default();
+    let categories = if itunes.categories.is_empty() {
+        let mut categories: Vec<PodcastCategory> = Vec::new();
+        for category in channel.categories {
+            if !categories.iter().any(|c| c.category == category.name) {
+                categories.push(PodcastCategory {
+                    category: category.name,
+                    sub_category: None,
+                });
+            }
+        }
+        categories
+    } else {
+        itunes
+            .categories
+            .into_iter()
+            .map(|category| PodcastCategory {
+                category: category.text,
+                sub_category: category.subcategory.map(|sub_category| sub_category.text),
+            })
+            .collect()
+    };
     let categories = PodcastCategories(categories);
+    let image = itunes
+        .image
+        .or_else(|| channel.image.map(|image| image.url))
+        .or_else(|| get_media_thumbnail(&media));
+    let author = itunes
+        .author
+        .or_else(|| {
+            channel
+                .dublin_core_ext
+                .and_then(|dublin_core| dublin_core.creators.into_iter().next())
+        })
+        .or(channel.managing_editor);
+    let funding = parse_fundings(&extensions, &channel.title, warnings);
+    let persons = parse_persons(&extensions, &channel.title, warnings);
     let podcast = PodcastInfo {
         primary_key: u32::default(),
         slug,
         feed_url: None,
+        etag: None,
+        last_modified: None,
+        credentials: None,
+        refresh_interval: None,
+        auto_download: None,
+        retention: None,
+        path_template: None,
         title: channel.title,
         description: channel.description,
-        image: if let Some(url) = itunes.image {
+        image: if let Some(url) = image {
             Some(try_parse_url(url, PodcastFromRssError::ParseImage)?)
         } else {
             None
@@ -56,7 +119,7 @@ fn podcast_from_rss(
         language: channel.language,
         categories,
         explicit: false,
-        author: itunes.author,
+        author,
         link: Some(try_parse_url(channel.link, PodcastFromRssError::ParseLink)?),
         kind: if let Some(kind) = &itunes.r#type {
             Some(PodcastKind::from_str(kind).change_context(PodcastFromRssError::ParseKind)?)
@@ -70,22 +133,58 @@ fn podcast_from_rss(
             None
         },
         generator: channel.generator,
+        podcast_guid: get_extension_value(&extensions, "guid"),
+        locked: get_extension_value(&extensions, "locked").and_then(|value| parse_locked(&value)),
+        funding,
+        persons,
     };
     Ok(podcast)
 }
 
-fn episode_from_rss(item: RssItem) -> Result<EpisodeInfo, Report<EpisodeFromRssError>> {
-    let itunes = item.itunes_ext.ok_or(EpisodeFromRssError::NoItunes)?;
-    let enclosure = item.enclosure.ok_or(EpisodeFromRssError::NoEnclosure)?;
-    let source_id = item.guid.ok_or(EpisodeFromRssError::NoGuid)?.value;
+fn episode_from_rss(
+    mut item: RssItem,
+    name: &str,
+    warnings: &mut Vec<FeedWarning>,
+) -> Result<EpisodeInfo, Report<EpisodeFromRssError>> {
+    let extensions = item
+        .extensions
+        .remove(PODCAST_NAMESPACE_PREFIX)
+        .unwrap_or_default();
+    let chapters = extensions
+        .get("chapters")
+        .and_then(|chapters| chapters.first())
+        .and_then(|chapters| {
+            let url = parse_url_attr(chapters, "url", true, name, warnings)?;
+            Some((url, get_attr(chapters, "type")))
+        });
+    let media = item
+        .extensions
+        .remove(MEDIA_NAMESPACE_PREFIX)
+        .unwrap_or_default();
+    let itunes = item.itunes_ext.unwrap_or_default();
+    let enclosure = item
+        .enclosure
+        .or_else(|| get_media_enclosure(&media))
+        .ok_or(EpisodeFromRssError::NoEnclosure)?;
+    let image = itunes
+        .image
+        .or_else(|| get_media_thumbnail(&media))
+        .or_else(|| get_media_image(&media));
+    // Items without a GUID are keyed by `dedupe_episodes`
+    let source_id = item.guid.map(|guid| guid.value).unwrap_or_default();
     let pub_date = &item.pub_date.ok_or(EpisodeFromRssError::NoPublishedAt)?;
-    let published_at = DateTime::parse_from_rfc2822(pub_date)
-        .change_context(EpisodeFromRssError::ParsePublishedAt)?;
+    let published_at = parse_pub_date(pub_date, name, warnings)?;
+    let source_duration = itunes
+        .duration
+        .and_then(|duration| parse_itunes_duration(&duration, name, warnings));
     let episode = EpisodeInfo {
         primary_key: u32::default(),
         podcast_key: None,
         file_sub_path: None,
         image_sub_path: None,
+        removed_from_feed_at: None,
+        enclosure_changed_at: None,
+        played_at: None,
         title: item.title.ok_or(EpisodeFromRssError::NoTitle)?,
         source_url: try_parse_url(enclosure.url, EpisodeFromRssError::ParseUrl)?,
         source_file_size: try_parse(&enclosure.length, EpisodeFromRssError::ParseFileSize)?,
@@ -93,12 +192,8 @@ fn episode_from_rss(item: RssItem) -> Result<EpisodeInfo, Report<EpisodeFromRssE
         source_id,
         published_at,
         description: item.description,
-        source_duration: if let Some(duration) = itunes.duration {
-            Some(try_parse_duration(&duration)?)
-        } else {
-            None
-        },
-        image: if let Some(url) = itunes.image {
+        source_duration,
+        image: if let Some(url) = image {
             Some(try_parse_url(url, EpisodeFromRssError::ParseImage)?)
         } else {
             None
@@ -126,27 +221,56 @@ fn episode_from_rss(item: RssItem) -> Result<EpisodeInfo, Report<EpisodeFromRssE
         } else {
             None
         },
+        transcripts: parse_transcripts(&extensions, name, warnings),
+        chapters_content_type: chapters
+            .as_ref()
+            .and_then(|(_, content_type)| content_type.clone()),
+        chapters_url: chapters.map(|(url, _)| url),
+        persons: parse_persons(&extensions, name, warnings),
+        season_name: extensions
+            .get("season")
+            .and_then(|season| season.first())
+            .and_then(|season| get_attr(season, "name")),
     };
     Ok(episode)
 }
 
-#[allow(clippy::indexing_slicing)]
-fn try_parse_duration(duration: &str) -> Result<Duration, Report<EpisodeFromRssError>> {
-    let parts: Vec<&str> = duration.split(':').collect();
-    match parts.len() {
-        1 => try_parse(parts[0], EpisodeFromRssError::ParseDuration),
-        2 => {
-            let minutes: Duration = try_parse(parts[0], EpisodeFromRssError::ParseDuration)?;
-            let seconds: Duration = try_parse(parts[1], EpisodeFromRssError::ParseDuration)?;
-            Ok(minutes * 60 + seconds)
+fn parse_pub_date(
+    pub_date: &str,
+    name: &str,
+    warnings: &mut Vec<FeedWarning>,
+) -> Result<DateTime<FixedOffset>, Report<EpisodeFromRssError>> {
+    match parse_published_at(pub_date) {
+        Some(Parsed::Strict(published_at)) => Ok(published_at),
+        Some(Parsed::Lenient(published_at)) => {
+            let kind = FeedWarningKind::PublishedAtCoerced;
+            warnings.push(FeedWarning::value(name, kind, pub_date));
+            Ok(published_at)
+        }
+        None => {
+            Err(Report::new(EpisodeFromRssError::ParsePublishedAt)
+                .attach("Value", pub_date.to_owned()))
+        }
+    }
+}
+
+fn parse_itunes_duration(
+    duration: &str,
+    name: &str,
+    warnings: &mut Vec<FeedWarning>,
+) -> Option<Duration> {
+    match parse_duration(duration) {
+        Some(Parsed::Strict(seconds)) => Some(seconds),
+        Some(Parsed::Lenient(seconds)) => {
+            let kind = FeedWarningKind::DurationCoerced;
+            warnings.push(FeedWarning::value(name, kind, duration));
+            Some(seconds)
         }
-        3 => {
-            let hours: Duration = try_parse(parts[0], EpisodeFromRssError::ParseDuration)?;
-            let minutes: Duration = try_parse(parts[1], EpisodeFromRssError::ParseDuration)?;
-            let seconds: Duration = try_parse(parts[2], EpisodeFromRssError::ParseDuration)?;
-            Ok(hours * 60 * 60 + minutes * 60 + seconds)
+        None => {
+            let kind = FeedWarningKind::DurationIgnored;
+            warnings.push(FeedWarning::value(name, kind, duration));
+            None
         }
-        count => Err(Report::new(EpisodeFromRssError::ParseDuration).attach("Parts", count)),
     }
 }
 
@@ -159,7 +283,186 @@ fn parse_explicit(option: Option<String>) -> Option<bool> {
     }
 }
 
-fn try_parse_url<E: Error + Send + Sync + 'static>(
+fn parse_locked(value: &str) -> Option<bool> {
+    match value.to_lowercase().as_str() {
+        "yes" | "true" => Some(true),
+        "no" | "false" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_fundings(
+    extensions: &NamespaceExtensions,
+    name: &str,
+    warnings: &mut Vec<FeedWarning>,
+) -> PodcastFundings {
+    let mut fundings = Vec::new();
+    for extension in extensions.get("funding").into_iter().flatten() {
+        let Some(url) = parse_url_attr(extension, "url", true, name, warnings) else {
+            continue;
+        };
+        fundings.push(PodcastFunding {
+            url,
+            message: get_value(extension),
+        });
+    }
+    PodcastFundings(fundings)
+}
+
+fn parse_persons(
+    extensions: &NamespaceExtensions,
+    name: &str,
+    warnings: &mut Vec<FeedWarning>,
+) -> PodcastPersons {
+    let mut persons = Vec::new();
+    for extension in extensions.get("person").into_iter().flatten() {
+        let Some(person) = get_value(extension) else {
+            continue;
+        };
+        let Ok(image) = try_parse_url_attr(extension, "img", name, warnings) else {
+            continue;
+        };
+        let Ok(link) = try_parse_url_attr(extension, "href", name, warnings) else {
+            continue;
+        };
+        persons.push(PodcastPerson {
+            name: person,
+            role: get_attr(extension, "role"),
+            group: get_attr(extension, "group"),
+            image,
+            link,
+        });
+    }
+    PodcastPersons(persons)
+}
+
+fn parse_transcripts(
+    extensions: &NamespaceExtensions,
+    name: &str,
+    warnings: &mut Vec<FeedWarning>,
+) -> EpisodeTranscripts {
+    let mut transcripts = Vec::new();
+    for extension in extensions.get("transcript").into_iter().flatten() {
+        let Some(url) = parse_url_attr(extension, "url", true, name, warnings) else {
+            continue;
+        };
+        let Some(content_type) = get_attr(extension, "type") else {
+            let reason = "Missing type attribute".to_owned();
+            warnings.push(FeedWarning::tag(name, extension.name(), reason));
+            continue;
+        };
+        transcripts.push(EpisodeTranscript {
+            url,
+            content_type,
+            language: get_attr(extension, "language"),
+            rel: get_attr(extension, "rel"),
+        });
+    }
+    EpisodeTranscripts(transcripts)
+}
+
+/// Parse a URL attribute of a `podcast:*` tag.
+///
+/// Returns `None` and adds to `warnings` if the attribute is invalid, or if it is
+/// `required` and missing, so the caller can ignore the tag.
+fn parse_url_attr(
+    extension: &Extension,
+    attr: &str,
+    required: bool,
+    name: &str,
+    warnings: &mut Vec<FeedWarning>,
+) -> Option<UrlWrapper> {
+    match try_parse_url_attr(extension, attr, name, warnings) {
+        Ok(Some(url)) => Some(url),
+        Ok(None) => {
+            if required {
+                let reason = format!("Missing {attr} attribute");
+                warnings.push(FeedWarning::tag(name, extension.name(), reason));
+            }
+            None
+        }
+        Err(()) => None,
+    }
+}
+
+/// Parse an optional URL attribute of a `podcast:*` tag.
+///
+/// Returns `Err` and adds to `warnings` if the attribute is present but invalid.
+fn try_parse_url_attr(
+    extension: &Extension,
+    attr: &str,
+    name: &str,
+    warnings: &mut Vec<FeedWarning>,
+) -> Result<Option<UrlWrapper>, ()> {
+    let Some(value) = get_attr(extension, attr) else {
+        return Ok(None);
+    };
+    if let Ok(url) = UrlWrapper::from_str(&value) {
+        Ok(Some(url))
+    } else {
+        let reason = format!("Invalid {attr} attribute: {value}");
+        warnings.push(FeedWarning::tag(name, extension.name(), reason));
+        Err(())
+    }
+}
+
+/// Get the `media:content` elements including those nested in a `media:group`.
+fn get_media_contents(media: &NamespaceExtensions) -> impl Iterator<Item = &Extension> {
+    let grouped = media
+        .get("group")
+        .into_iter()
+        .flatten()
+        .flat_map(|group| group.children().get("content").into_iter().flatten());
+    media.get("content").into_iter().flatten().chain(grouped)
+}
+
+/// Create an enclosure from the first audio or video `media:content` element.
+fn get_media_enclosure(media: &NamespaceExtensions) -> Option<RssEnclosure> {
+    let content = get_media_contents(media).find(|content| {
+        let medium = get_attr(content, "medium").unwrap_or_default();
+        let mime_type = get_attr(content, "type").unwrap_or_default();
+        medium == "audio"
+            || medium == "video"
+            || mime_type.starts_with("audio/")
+            || mime_type.starts_with("video/")
+    })?;
+    Some(RssEnclosure {
+        url: get_attr(content, "url")?,
+        length: get_attr(content, "fileSize").unwrap_or_else(|| "0".to_owned()),
+        mime_type: get_attr(content, "type").unwrap_or_default(),
+    })
+}
+
+fn get_media_image(media: &NamespaceExtensions) -> Option<String> {
+    let content = get_media_contents(media).find(|content| {
+        get_attr(content, "medium").as_deref() == Some("image")
+            || get_attr(content, "type").is_some_and(|mime_type| mime_type.starts_with("image/"))
+    })?;
+    get_attr(content, "url")
+}
+
+fn get_media_thumbnail(media: &NamespaceExtensions) -> Option<String> {
+    get_attr(media.get("thumbnail")?.first()?, "url")
+}
+
+fn get_extension_value(extensions: &NamespaceExtensions, name: &str) -> Option<String> {
+    get_value(extensions.get(name)?.first()?)
+}
+
+fn get_value(extension: &Extension) -> Option<String> {
+    let value = extension.value()?.trim();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_owned())
+    }
+}
+
+fn get_attr(extension: &Extension, name: &str) -> Option<String> {
+    extension.attrs().get(name).cloned()
+}
+
+pub(super) fn try_parse_url<E: Error + Send + Sync + 'static>(
     url: String,
     error: E,
 ) -> Result<UrlWrapper, Report<E>> {
@@ -186,7 +489,7 @@ fn try_parse_truncate<E: Error + Send + Sync + 'static>(
     }
 }
 
-fn try_parse<T: FromStr, E: Error + Send + Sync + 'static>(
+pub(super) fn try_parse<T: FromStr, E: Error + Send + Sync + 'static>(
     value: &str,
     error: E,
 ) -> Result<T, Report<E>>
@@ -200,10 +503,9 @@ where
 }
 
 /// Errors from parsing a podcast from RSS.
+#[allow(clippy::enum_variant_names)]
 #[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
 pub enum PodcastFromRssError {
-    #[error("Podcast RSS channel is missing the itunes extension")]
-    NoItunes,
     #[error("Unable to parse podcast image URL")]
     ParseImage,
     #[error("Unable to parse podcast link URL")]
@@ -217,10 +519,6 @@ pub enum PodcastFromRssError {
 /// Errors from parsing an episode from RSS.
 #[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
 pub enum EpisodeFromRssError {
-    #[error("RSS channel is missing the itunes extension")]
-    NoItunes,
-    #[error("Episode has no GUID")]
-    NoGuid,
     #[error("Episode has no title")]
     NoTitle,
     #[error("Episode does not have an enclosure")]
@@ -233,8 +531,6 @@ pub enum EpisodeFromRssError {
     NoPublishedAt,
     #[error("Unable to parse episode published at date")]
     ParsePublishedAt,
-    #[error("Unable to parse episode duration")]
-    ParseDuration,
     #[error("Unable to parse episode image")]
     ParseImage,
     #[error("Unable to parse episode number")]
@@ -247,6 +543,7 @@ pub enum EpisodeFromRssError {
 
 #[cfg(test)]
 mod tests {
+    #![allow(non_snake_case)]
     use super::*;
     use crate::prelude::PodcastToRss;
 
@@ -282,11 +579,212 @@ mod tests {
         assert_snapshot!(xml);
 
         // Act
-        let result = PodcastFromRss::execute(rss, slug);
+        let result = PodcastFromRss::execute(rss, slug, &mut Vec::new());
 
         // Assert
         let feed = result.assert_ok();
         assert_yaml_snapshot!(feed);
         assert_eq!(feed, source);
     }
+
+    #[test]
+    fn round_trip_conversion__podcast_namespace() {
+        // Arrange
+        let mut source = PodcastFeed::example();
+        let url = UrlWrapper::from_str("https://example.com/support").expect("should be valid URL");
+        source.podcast.podcast_guid = Some("917393e3-1b1e-5cef-ace4-edaa54e1f810".to_owned());
+        source.podcast.locked = Some(true);
+        source.podcast.funding = PodcastFundings(vec![PodcastFunding {
+            url: url.clone(),
+            message: Some("Support the show".to_owned()),
+        }]);
+        source.podcast.persons = PodcastPersons(vec![PodcastPerson {
+            name: "Jane Doe".to_owned(),
+            role: Some("host".to_owned()),
+            group: None,
+            image: None,
+            link: Some(url),
+        }]);
+        let episode = source.episodes.first_mut().expect("should have an episode");
+        episode.transcripts = EpisodeTranscripts(vec![EpisodeTranscript {
+            url: UrlWrapper::from_str("https://example.com/transcript.vtt")
+                .expect("should be valid URL"),
+            content_type: "text/vtt".to_owned(),
+            language: Some("en".to_owned()),
+            rel: Some("captions".to_owned()),
+        }]);
+        episode.chapters_url = Some(
+            UrlWrapper::from_str("https://example.com/chapters.json").expect("should be valid URL"),
+        );
+        episode.chapters_content_type = Some("application/json+chapters".to_owned());
+        episode.season_name = Some("Volume Two".to_owned());
+        let slug = Slug::from_str("test").expect("should be valid slug");
+
+        // Act
+        let rss = PodcastToRss::execute(source.clone());
+
+        // Assert
+        let xml = rss.to_string();
+        assert_snapshot!(xml);
+
+        // Act
+        let xml = RssChannel::read_from(xml.as_bytes()).expect("should be able to read RSS");
+        let result = PodcastFromRss::execute(xml, slug, &mut Vec::new());
+
+        // Assert
+        let feed = result.assert_ok();
+        assert_eq!(feed, source);
+    }
+
+    #[test]
+    fn podcast_from_rss__without_itunes() {
+        // Arrange
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:media="http://search.yahoo.com/mrss/">
+  <channel>
+    <title>Plain Podcast</title>
+    <link>https://example.com/</link>
+    <description>A podcast without the itunes extension</description>
+    <managingEditor>editor@example.com (Jane Doe)</managingEditor>
+    <category>Technology</category>
+    <category>Technology</category>
+    <category>News</category>
+    <image>
+      <url>https://example.com/cover.jpg</url>
+      <title>Plain Podcast</title>
+      <link>https://example.com/</link>
+    </image>
+    <item>
+      <title>Enclosure episode</title>
+      <guid>episode-1</guid>
+      <pubDate>Mon, 05 Jan 2026 10:00:00 +0000</pubDate>
+      <enclosure url="https://example.com/episode-1.mp3" length="1024" type="audio/mpeg"/>
+      <media:thumbnail url="https://example.com/episode-1.jpg"/>
+    </item>
+    <item>
+      <title>Media episode</title>
+      <guid>episode-2</guid>
+      <pubDate>Mon, 12 Jan 2026 10:00:00 +0000</pubDate>
+      <media:group>
+        <media:content url="https://example.com/episode-2.jpg" medium="image"/>
+        <media:content url="https://example.com/episode-2.m4a" type="audio/x-m4a" fileSize="2048"/>
+      </media:group>
+    </item>
+  </channel>
+</rss>"#;
+        let channel = RssChannel::read_from(xml.as_bytes()).expect("should be able to read RSS");
+        let slug = Slug::from_str("plain").expect("should be valid slug");
+
+        // Act
+        let result = PodcastFromRss::execute(channel, slug, &mut Vec::new());
+
+        // Assert
+        let feed = result.assert_ok();
+        assert_eq!(feed.episodes.len(), 2);
+        assert_yaml_snapshot!(feed);
+    }
+
+    #[test]
+    fn podcast_from_rss__lenient() {
+        // Arrange
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+  <channel>
+    <title>Lenient Podcast</title>
+    <link>https://example.com/</link>
+    <description>A podcast with non-standard values</description>
+    <item>
+      <title>ISO episode</title>
+      <guid>episode-1</guid>
+      <pubDate>2026-01-05T10:00:00Z</pubDate>
+      <itunes:duration>1h 2m</itunes:duration>
+      <enclosure url="https://example.com/episode-1.mp3" length="1024" type="audio/mpeg"/>
+    </item>
+    <item>
+      <title>French episode</title>
+      <guid>episode-2</guid>
+      <pubDate>12 janv. 2026 10:00:00 +0100</pubDate>
+      <itunes:duration>soon</itunes:duration>
+      <enclosure url="https://example.com/episode-2.mp3" length="2048" type="audio/mpeg"/>
+    </item>
+    <item>
+      <title>Missing enclosure</title>
+      <guid>episode-3</guid>
+      <pubDate>Mon, 19 Jan 2026 10:00:00 +0000</pubDate>
+    </item>
+  </channel>
+</rss>"#;
+        let channel = RssChannel::read_from(xml.as_bytes()).expect("should be able to read RSS");
+        let slug = Slug::from_str("lenient").expect("should be valid slug");
+        let mut warnings = Vec::new();
+
+        // Act
+        let result = PodcastFromRss::execute(channel, slug, &mut warnings);
+
+        // Assert
+        let feed = result.assert_ok();
+        assert_eq!(feed.episodes.len(), 2);
+        let first = feed.episodes.first().expect("should have an episode");
+        assert_eq!(first.source_duration, Some(3720));
+        let kinds: Vec<_> = warnings.iter().map(|warning| warning.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                FeedWarningKind::PublishedAtCoerced,
+                FeedWarningKind::DurationCoerced,
+                FeedWarningKind::PublishedAtCoerced,
+                FeedWarningKind::DurationIgnored,
+                FeedWarningKind::NoEnclosure,
+            ]
+        );
+        assert_yaml_snapshot!(warnings);
+    }
+
+    #[test]
+    fn podcast_from_rss__invalid_podcast_tags() {
+        // Arrange
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:podcast="https://podcastindex.org/namespace/1.0">
+  <channel>
+    <title>Invalid Tags Podcast</title>
+    <link>https://example.com/</link>
+    <description>A podcast with invalid Podcasting 2.0 tags</description>
+    <podcast:funding>Missing URL</podcast:funding>
+    <podcast:funding url="https://example.com/donate">Valid</podcast:funding>
+    <podcast:person img="not a url">Host</podcast:person>
+    <item>
+      <title>Episode</title>
+      <guid>episode-1</guid>
+      <pubDate>Mon, 05 Jan 2026 10:00:00 +0000</pubDate>
+      <enclosure url="https://example.com/episode-1.mp3" length="1024" type="audio/mpeg"/>
+      <podcast:transcript url="https://example.com/episode-1.vtt"/>
+      <podcast:transcript url="https://example.com/episode-1.srt" type="application/srt"/>
+      <podcast:chapters url="not a url" type="application/json+chapters"/>
+      <podcast:person href="https://example.com/guest">Guest</podcast:person>
+    </item>
+  </channel>
+</rss>"#;
+        let channel = RssChannel::read_from(xml.as_bytes()).expect("should be able to read RSS");
+        let slug = Slug::from_str("invalid-tags").expect("should be valid slug");
+        let mut warnings = Vec::new();
+
+        // Act
+        let result = PodcastFromRss::execute(channel, slug, &mut warnings);
+
+        // Assert
+        let feed = result.assert_ok();
+        assert_eq!(feed.podcast.funding.0.len(), 1);
+        assert!(feed.podcast.persons.0.is_empty());
+        let episode = feed.episodes.first().expect("should have an episode");
+        assert_eq!(episode.transcripts.0.len(), 1);
+        assert_eq!(episode.chapters_url, None);
+        assert_eq!(episode.chapters_content_type, None);
+        assert_eq!(episode.persons.0.len(), 1);
+        assert!(
+            warnings
+                .iter()
+                .all(|warning| warning.kind == FeedWarningKind::TagIgnored)
+        );
+        assert_yaml_snapshot!(warnings);
+    }
 }
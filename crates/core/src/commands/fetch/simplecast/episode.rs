@@ -71,6 +71,9 @@ impl From<SimplecastEpisode> for EpisodeInfo {
             podcast_key: None,
             file_sub_path: None,
             image_sub_path: None,
+            removed_from_feed_at: None,
+            enclosure_changed_at: None,
+            played_at: None,
             source_id: episode.id,
             title: episode.title,
             description: Some(episode.description),
@@ -85,6 +88,11 @@ impl From<SimplecastEpisode> for EpisodeInfo {
             source_duration: episode.duration,
             explicit: Some(episode.is_explicit),
             itunes_title: None,
+            transcripts: EpisodeTranscripts::default(),
+            chapters_url: None,
+            chapters_content_type: None,
+            persons: PodcastPersons::default(),
+            season_name: None,
         }
     }
 }
@@ -2,8 +2,10 @@ pub use episode::*;
 pub use playlist::*;
 pub use podcast::*;
 pub use shared::*;
+pub use simplecast_source::*;
 
 mod episode;
 mod playlist;
 mod podcast;
 mod shared;
+mod simplecast_source;
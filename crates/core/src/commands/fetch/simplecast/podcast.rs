@@ -26,6 +26,13 @@ impl From<SimplecastPodcast> for PodcastInfo {
             primary_key: u32::default(),
             slug: Slug::from_str(&podcast.id).expect("should be valid slug"),
             feed_url: None,
+            etag: None,
+            last_modified: None,
+            credentials: None,
+            refresh_interval: None,
+            auto_download: None,
+            retention: None,
+            path_template: None,
             title: podcast.title,
             description: podcast.description,
             image: podcast.image_url,
@@ -38,6 +45,10 @@ impl From<SimplecastPodcast> for PodcastInfo {
             copyright: podcast.copyright,
             new_feed_url: None,
             generator: None,
+            podcast_guid: None,
+            locked: None,
+            funding: PodcastFundings::default(),
+            persons: PodcastPersons::default(),
         }
     }
 }
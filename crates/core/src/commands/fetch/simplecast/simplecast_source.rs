@@ -1,17 +1,54 @@
-use super::simplecast::*;
+use super::*;
 use crate::prelude::*;
 
 #[allow(dead_code)]
 const CONCURRENCY: usize = 8;
 
-impl FetchHandler {
-    pub(super) async fn get_simplecast_rss(
+/// [`FeedSource`] for pages with an embedded Simplecast player.
+///
+/// - <https://simplecast.com>
+#[derive(Clone, FromServicesAsync)]
+pub struct SimplecastFeedSource {
+    http: Arc<dyn HttpFetch>,
+}
+
+#[async_trait]
+impl FeedSource for SimplecastFeedSource {
+    fn get_name(&self) -> &'static str {
+        "Simplecast"
+    }
+
+    async fn get_feed(
         &self,
         _slug: &Slug,
         url: &UrlWrapper,
+        html: &str,
+    ) -> Result<Option<FeedSourceResult>, Report<FeedSourceError>> {
+        let Some(episode_guid) = get_simplecast_episode_guid(&Html::parse_document(html)) else {
+            return Ok(None);
+        };
+        trace!(%url, episode_guid, "Found Simplecast player");
+        match self.get_simplecast_rss(&episode_guid).await {
+            Ok(feed_url) => Ok(Some(FeedSourceResult::Url(feed_url))),
+            Err(report) => {
+                let error = if report.current_context() == &FetchSimplecastError::NoFeed {
+                    FeedSourceError::NoFeed
+                } else {
+                    FeedSourceError::Request
+                };
+                Err(report.change_context(error).attach_url(url))
+            }
+        }
+    }
+}
+
+impl SimplecastFeedSource {
+    /// Resolve the feed URL of a Simplecast podcast from the GUID of an embedded episode.
+    async fn get_simplecast_rss(
+        &self,
+        episode_guid: &str,
     ) -> Result<UrlWrapper, Report<FetchSimplecastError>> {
-        let player_id = self.get_player_id(url).await?;
-        let episode = self.get_episode(&player_id).await?;
+        let episode = self.get_episode(episode_guid).await?;
         let podcast = self.get_podcast(&episode).await?;
         if let Some(url) = podcast.feed_url {
             return Ok(url);
@@ -23,22 +60,6 @@ impl FetchHandler {
         Err(report)
     }
 
-    async fn get_player_id(
-        &self,
-        url: &UrlWrapper,
-    ) -> Result<String, Report<FetchSimplecastError>> {
-        let html = self
-            .http
-            .get_html(url)
-            .await
-            .change_context(FetchSimplecastError::GetPage)
-            .attach_url(url)?;
-        let episode_guid = get_simplecast_episode_guid(&html)
-            .ok_or_else(|| Report::new(FetchSimplecastError::PlayerNotFound).attach_url(url))?;
-        trace!(episode_guid, "Found Simplecast player");
-        Ok(episode_guid)
-    }
-
     async fn get_episode(
         &self,
         id: &str,
@@ -125,6 +146,7 @@ impl FetchHandler {
     }
 }
 
+/// Get the episode GUID of an embedded Simplecast player.
 fn get_simplecast_episode_guid(html: &Html) -> Option<String> {
     let mut src = get_element_attr(html, "iframe", "src");
     src.append(&mut get_element_attr(html, "iframe", "data-src"));
@@ -165,3 +187,76 @@ fn convert(
     let episodes = episodes.into_iter().map(Into::into).collect();
     PodcastFeed { podcast, episodes }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn get_simplecast_episode_guid__player() {
+        // Arrange
+        let html = Html::parse_document(
+            r#"<html><body>
+<iframe src=""></iframe>
+<iframe data-src="https://player.simplecast.com/4c1e1f2a-9b7d-4bde-a8a4-3f0c1f3c9b2e?dark=false"></iframe>
+</body></html>"#,
+        );
+
+        // Act
+        let guid = get_simplecast_episode_guid(&html);
+
+        // Assert
+        assert_eq!(
+            guid.as_deref(),
+            Some("4c1e1f2a-9b7d-4bde-a8a4-3f0c1f3c9b2e")
+        );
+    }
+
+    #[tokio::test]
+    async fn get_feed__no_player() {
+        // Arrange
+        let source = MockServices::new()
+            .create()
+            .await
+            .get_async::<SimplecastFeedSource>()
+            .await
+            .expect("should be able to get source");
+        let url = UrlWrapper::from_str("https://example.com/").expect("URL should parse");
+
+        // Act
+        let result = source
+            .get_feed(
+                &MockFeeds::podcast_slug(),
+                &url,
+                r#"<iframe src="https://www.youtube.com/embed/abc"></iframe>"#,
+            )
+            .await;
+
+        // Assert
+        let feed = result.assert_ok_debug();
+        assert!(feed.is_none());
+    }
+
+    #[tokio::test]
+    async fn get_feed__api_unavailable() {
+        // Arrange
+        let source = MockServices::new()
+            .create()
+            .await
+            .get_async::<SimplecastFeedSource>()
+            .await
+            .expect("should be able to get source");
+        let url = UrlWrapper::from_str("https://example.com/").expect("URL should parse");
+        let html = r#"<iframe src="https://player.simplecast.com/abc"></iframe>"#;
+
+        // Act
+        let result = source
+            .get_feed(&MockFeeds::podcast_slug(), &url, html)
+            .await;
+
+        // Assert
+        let report = result.assert_err_debug();
+        assert_eq!(report.current_context(), &FeedSourceError::Request);
+    }
+}
@@ -6,9 +6,15 @@ impl MetadataRepository {
     /// Update an existing podcast feed.
     ///
     /// - Fails if the podcast doesn't exist
-    /// - Updates existing episodes by matching `source_id`, preserving download paths
-    /// - Inserts new episodes
+    /// - Gives items that reuse a GUID a unique `source_id`, see [`dedupe_episodes`]
+    /// - Updates existing episodes by matching `source_id`, then enclosure URL, then
+    ///   title and date, preserving download paths
+    /// - Inserts new episodes and returns their keys
     /// - Keeps episodes removed from feed (preserves downloaded content)
+    /// - Sets `removed_from_feed_at` for episodes missing from the feed
+    /// - Clears `removed_from_feed_at` for episodes that are back in the feed
+    /// - Reports changes to the title, enclosure URL or description of existing episodes
+    /// - Sets `enclosure_changed_at` for downloaded episodes whose enclosure URL or size changed
     pub async fn update_feed(
         &self,
         feed: PodcastFeed,
@@ -23,37 +29,77 @@ impl MetadataRepository {
         let existing = get_existing_episodes(&tx, key)
             .await
             .change_context(UpdateError::GetExistingEpisodes)?;
-        update_podcast(&tx, feed.podcast, key)
+        Box::pin(update_podcast(&tx, feed.podcast, key))
             .await
             .change_context(UpdateError::Podcast)?;
-        let (to_update, to_insert): (Vec<_>, Vec<_>) = feed
-            .episodes
-            .into_iter()
-            .partition(|ep| existing.contains_key(&ep.source_id));
-        let response = FetchResponse {
-            podcast_key: key,
-            podcast_slug: slug,
-            episodes_inserted: to_insert.len(),
-            episodes_updated: to_update.len(),
-        };
-        for episode in to_update {
-            let existing_key = existing
-                .get(&episode.source_id)
-                .copied()
-                .expect("partition guarantees existence");
-            update_episode_query(episode, existing_key, key)
+        let (episodes, collisions) = dedupe_episodes(feed.episodes);
+        let (to_update, to_insert) = match_existing_episodes(&existing, episodes);
+        let matched: HashSet<EpisodeKey> = to_update
+            .iter()
+            .map(|(_, existing)| existing.primary_key)
+            .collect();
+        let missing: Vec<EpisodeKey> = existing
+            .values()
+            .map(|existing| existing.primary_key)
+            .filter(|primary_key| !matched.contains(primary_key))
+            .collect();
+        let changes = to_update
+            .iter()
+            .filter_map(|(episode, existing)| get_episode_change(existing, episode))
+            .collect();
+        let now = Utc::now().fixed_offset();
+        let episodes_removed = if missing.is_empty() {
+            0
+        } else {
+            let result = mark_removed_query(missing, now)
                 .exec(&tx)
                 .await
                 .change_context(UpdateError::Episodes)?;
+            usize::try_from(result.rows_affected).unwrap_or(usize::MAX)
+        };
+        let episodes_updated = to_update.len();
+        let mut episodes_replaced = 0;
+        for (episode, existing_episode) in to_update {
+            let enclosure_changed_at =
+                is_enclosure_replaced(existing_episode, &episode).then(|| {
+                    debug!(podcast = %slug, episode = %episode, "Downloaded enclosure was replaced");
+                    episodes_replaced += 1;
+                    now
+                });
+            update_episode_query(
+                episode,
+                existing_episode.primary_key,
+                key,
+                enclosure_changed_at,
+            )
+            .exec(&tx)
+            .await
+            .change_context(UpdateError::Episodes)?;
         }
-        if !to_insert.is_empty() {
+        let episodes_inserted = to_insert.len();
+        let inserted_keys = if to_insert.is_empty() {
+            Vec::new()
+        } else {
             insert_episodes(to_insert, key)
-                .exec(&tx)
+                .exec_with_returning_keys(&tx)
                 .await
-                .change_context(UpdateError::Episodes)?;
-        }
+                .change_context(UpdateError::Episodes)?
+        };
         tx.commit().await.change_context(UpdateError::Commit)?;
-        Ok(response)
+        Ok(FetchResponse {
+            podcast_key: key,
+            podcast_slug: slug,
+            episodes_inserted,
+            episodes_updated,
+            episodes_removed,
+            episodes_replaced,
+            modified: true,
+            changes: EpisodeChanges(changes),
+            warnings: Vec::new(),
+            collisions,
+            inserted_keys,
+            auto_downloads: Vec::new(),
+        })
     }
 }
 
@@ -65,6 +111,13 @@ fn update_podcast_query(
         primary_key: Unchanged(primary_key),
         slug: Unchanged(podcast.slug),
         feed_url: Set(podcast.feed_url),
+        etag: Set(podcast.etag),
+        last_modified: Set(podcast.last_modified),
+        credentials: NotSet,
+        refresh_interval: NotSet,
+        auto_download: NotSet,
+        retention: NotSet,
+        path_template: NotSet,
         title: Set(podcast.title),
         description: Set(podcast.description),
         image: Set(podcast.image),
@@ -77,6 +130,10 @@ fn update_podcast_query(
         copyright: Set(podcast.copyright),
         new_feed_url: Set(podcast.new_feed_url),
         generator: Set(podcast.generator),
+        podcast_guid: Set(podcast.podcast_guid),
+        locked: Set(podcast.locked),
+        funding: Set(podcast.funding),
+        persons: Set(podcast.persons),
     };
     podcast::Entity::update(model)
 }
@@ -89,36 +146,107 @@ async fn update_podcast(
     update_podcast_query(podcast, primary_key).exec(tx).await
 }
 
+/// Stored values of an episode compared against the feed.
+#[derive(Debug, FromQueryResult)]
+struct ExistingEpisode {
+    primary_key: EpisodeKey,
+    source_id: String,
+    title: String,
+    source_url: UrlWrapper,
+    source_file_size: FileSize,
+    published_at: DateTimeWithTimeZone,
+    description: Option<String>,
+    file_sub_path: Option<PathWrapper>,
+}
+
 fn get_existing_episodes_query(podcast_key: PodcastKey) -> Select<episode::Entity> {
     episode::Entity::find()
         .select_only()
-        .columns([episode::Column::SourceId, episode::Column::PrimaryKey])
+        .columns([
+            episode::Column::PrimaryKey,
+            episode::Column::SourceId,
+            episode::Column::Title,
+            episode::Column::SourceUrl,
+            episode::Column::SourceFileSize,
+            episode::Column::PublishedAt,
+            episode::Column::Description,
+            episode::Column::FileSubPath,
+        ])
         .filter(episode::Column::PodcastKey.eq(podcast_key))
 }
 
 async fn get_existing_episodes(
     tx: &DatabaseTransaction,
     podcast_key: PodcastKey,
-) -> Result<HashMap<String, EpisodeKey>, DbErr> {
+) -> Result<HashMap<String, ExistingEpisode>, DbErr> {
     let hash_map = get_existing_episodes_query(podcast_key)
-        .into_tuple()
+        .into_model::<ExistingEpisode>()
         .all(tx)
         .await?
         .into_iter()
+        .map(|existing| (existing.source_id.clone(), existing))
         .collect();
     Ok(hash_map)
 }
 
+/// Pair feed episodes with stored episodes.
+///
+/// Episodes are matched by `source_id`. Otherwise an episode is matched to a stored
+/// episode missing from the feed by enclosure URL, then by title and date, so an
+/// episode whose GUID changed is updated rather than inserted again.
+fn match_existing_episodes(
+    existing: &HashMap<String, ExistingEpisode>,
+    episodes: Vec<EpisodeInfo>,
+) -> (Vec<(EpisodeInfo, &ExistingEpisode)>, Vec<EpisodeInfo>) {
+    let in_feed: HashSet<&str> = episodes
+        .iter()
+        .map(|episode| episode.source_id.as_str())
+        .collect();
+    let mut unmatched: Vec<&ExistingEpisode> = existing
+        .values()
+        .filter(|existing| !in_feed.contains(existing.source_id.as_str()))
+        .collect();
+    unmatched.sort_by_key(|existing| existing.primary_key);
+    let mut to_update = Vec::new();
+    let mut to_insert = Vec::new();
+    for episode in episodes {
+        if let Some(existing) = existing.get(&episode.source_id) {
+            to_update.push((episode, existing));
+            continue;
+        }
+        let position = unmatched
+            .iter()
+            .position(|existing| existing.source_url == episode.source_url)
+            .or_else(|| {
+                unmatched.iter().position(|existing| {
+                    existing.title == episode.title && existing.published_at == episode.published_at
+                })
+            });
+        if let Some(position) = position {
+            let existing = unmatched.remove(position);
+            trace!(episode = %episode, old_source_id = existing.source_id, "Matched episode with a new GUID");
+            to_update.push((episode, existing));
+        } else {
+            to_insert.push(episode);
+        }
+    }
+    (to_update, to_insert)
+}
+
 fn update_episode_query(
     episode: EpisodeInfo,
     existing_key: EpisodeKey,
     podcast_key: PodcastKey,
+    enclosure_changed_at: Option<DateTimeWithTimeZone>,
 ) -> UpdateOne<episode::ActiveModel> {
     let model = episode::ActiveModel {
         primary_key: Unchanged(existing_key),
         podcast_key: Unchanged(Some(podcast_key)),
         file_sub_path: Unchanged(None),
         image_sub_path: Unchanged(None),
+        removed_from_feed_at: Set(None),
+        enclosure_changed_at: enclosure_changed_at.map_or(NotSet, |at| Set(Some(at))),
+        played_at: NotSet,
         source_id: Set(episode.source_id),
         title: Set(episode.title),
         source_url: Set(episode.source_url),
@@ -133,10 +261,66 @@ fn update_episode_query(
         episode: Set(episode.episode),
         season: Set(episode.season),
         kind: Set(episode.kind),
+        transcripts: Set(episode.transcripts),
+        chapters_url: Set(episode.chapters_url),
+        chapters_content_type: Set(episode.chapters_content_type),
+        persons: Set(episode.persons),
+        season_name: Set(episode.season_name),
     };
     episode::Entity::update(model)
 }
 
+/// Whether a downloaded episode now has a different enclosure URL or file size.
+fn is_enclosure_replaced(existing: &ExistingEpisode, episode: &EpisodeInfo) -> bool {
+    existing.file_sub_path.is_some()
+        && (existing.source_url != episode.source_url
+            || existing.source_file_size != episode.source_file_size)
+}
+
+/// Compare the stored title, enclosure URL and description with the feed.
+fn get_episode_change(existing: &ExistingEpisode, episode: &EpisodeInfo) -> Option<EpisodeChange> {
+    let fields: Vec<FieldChange> = [
+        (
+            EpisodeField::Title,
+            Some(existing.title.clone()),
+            Some(episode.title.clone()),
+        ),
+        (
+            EpisodeField::SourceUrl,
+            Some(existing.source_url.to_string()),
+            Some(episode.source_url.to_string()),
+        ),
+        (
+            EpisodeField::Description,
+            existing.description.clone(),
+            episode.description.clone(),
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, old, new)| old != new)
+    .map(|(field, old, new)| FieldChange { field, old, new })
+    .collect();
+    if fields.is_empty() {
+        return None;
+    }
+    Some(EpisodeChange {
+        episode_key: existing.primary_key,
+        title: episode.title.clone(),
+        fields,
+    })
+}
+
+/// Set `removed_from_feed_at` unless the episode was already missing.
+fn mark_removed_query(
+    episode_keys: Vec<EpisodeKey>,
+    now: DateTimeWithTimeZone,
+) -> UpdateMany<episode::Entity> {
+    episode::Entity::update_many()
+        .col_expr(episode::Column::RemovedFromFeedAt, Expr::value(now))
+        .filter(episode::Column::PrimaryKey.is_in(episode_keys))
+        .filter(episode::Column::RemovedFromFeedAt.is_null())
+}
+
 fn get_podcast_key_by_slug_select(slug: &Slug) -> Select<podcast::Entity> {
     podcast::Entity::find()
         .select_only()
@@ -225,7 +409,7 @@ mod tests {
         let episode = EpisodeInfo::example();
 
         // Act
-        let statement = update_episode_query(episode, 42, MockFeeds::PODCAST_KEY)
+        let statement = update_episode_query(episode, 42, MockFeeds::PODCAST_KEY, None)
             .validate()
             .expect("query should be valid")
             .build(DB_BACKEND);
@@ -263,6 +447,173 @@ mod tests {
         assert_eq!(response.episodes_inserted, 1);
     }
 
+    #[test]
+    fn _mark_removed_query() {
+        // Arrange
+        let now = DateTimeWithTimeZone::default();
+
+        // Act
+        let statement = mark_removed_query(vec![1, 2], now).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[tokio::test]
+    pub async fn update_feed__changes() {
+        // Arrange
+        let metadata = MockServices::default()
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let mut feed = MockFeeds::default()
+            .feeds
+            .into_iter()
+            .next()
+            .expect("should have at least one feed");
+        feed.episodes.push(EpisodeInfo {
+            source_id: "changed-episode-source-id".to_owned(),
+            ..EpisodeInfo::example()
+        });
+        metadata.update_feed(feed.clone()).await.assert_ok_debug();
+        let episode = feed
+            .episodes
+            .last_mut()
+            .expect("should have at least one episode");
+        episode.title = "Retitled episode".to_owned();
+
+        // Act
+        let result = metadata.update_feed(feed).await;
+
+        // Assert
+        let response = result.assert_ok_debug();
+        let change = response
+            .changes
+            .0
+            .iter()
+            .find(|change| change.title == "Retitled episode")
+            .expect("should have a change");
+        assert_eq!(
+            change.fields,
+            vec![FieldChange {
+                field: EpisodeField::Title,
+                old: Some(EpisodeInfo::example().title),
+                new: Some("Retitled episode".to_owned()),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    pub async fn update_feed__enclosure_replaced() {
+        // Arrange
+        let edit_episode: fn(&mut EpisodeInfo) = |episode| {
+            episode.source_id = episode.title.clone();
+            episode.file_sub_path =
+                Some(PathWrapper::from_str("test-0/episode.mp3").expect("valid path"));
+        };
+        let metadata = MockServices::new()
+            .with_metadata_factory(MockFeedsFactory {
+                edit_episode: Some(edit_episode),
+                ..MockFeedsFactory::default()
+            })
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let mut feed = MockFeedsFactory {
+            edit_episode: Some(edit_episode),
+            ..MockFeedsFactory::default()
+        }
+        .create()
+        .feeds
+        .into_iter()
+        .next()
+        .expect("should have at least one feed");
+        for episode in &mut feed.episodes {
+            episode.source_url = UrlWrapper::from_str("https://example.com/replaced.mp3")
+                .expect("URL should be valid");
+        }
+        let slug = feed.podcast.slug.clone();
+
+        // Act
+        let result = metadata.update_feed(feed).await;
+
+        // Assert
+        let response = result.assert_ok_debug();
+        assert_eq!(response.episodes_replaced, response.episodes_updated);
+        let stored = metadata
+            .get_feed_by_slug(slug, None)
+            .await
+            .assert_ok_debug()
+            .expect("podcast should exist");
+        assert!(
+            stored
+                .episodes
+                .iter()
+                .all(|episode| episode.enclosure_changed_at.is_some())
+        );
+    }
+
+    #[tokio::test]
+    pub async fn update_feed__removed_from_feed() {
+        // Arrange
+        let metadata = MockServices::default()
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let feed = MockFeeds::default()
+            .feeds
+            .into_iter()
+            .next()
+            .expect("should have at least one feed");
+        let slug = feed.podcast.slug.clone();
+        let mut with_extra = feed.clone();
+        with_extra.episodes.push(EpisodeInfo {
+            source_id: "removed-episode-source-id".to_owned(),
+            ..EpisodeInfo::example()
+        });
+        metadata
+            .update_feed(with_extra.clone())
+            .await
+            .assert_ok_debug();
+
+        // Act
+        let removed = metadata.update_feed(feed.clone()).await;
+        let unchanged = metadata.update_feed(feed).await;
+
+        // Assert
+        assert_eq!(removed.assert_ok_debug().episodes_removed, 1);
+        assert_eq!(unchanged.assert_ok_debug().episodes_removed, 0);
+        let stored = metadata
+            .get_feed_by_slug(slug.clone(), None)
+            .await
+            .assert_ok_debug()
+            .expect("podcast should exist");
+        let episode = stored
+            .episodes
+            .iter()
+            .find(|episode| episode.source_id == "removed-episode-source-id")
+            .expect("removed episode should be kept");
+        assert!(episode.removed_from_feed_at.is_some());
+        metadata.update_feed(with_extra).await.assert_ok_debug();
+        let restored = metadata
+            .get_feed_by_slug(slug, None)
+            .await
+            .assert_ok_debug()
+            .expect("podcast should exist");
+        assert!(
+            restored
+                .episodes
+                .iter()
+                .all(|episode| episode.removed_from_feed_at.is_none())
+        );
+    }
+
     #[tokio::test]
     pub async fn update_feed__not_found() {
         // Arrange
@@ -286,4 +637,77 @@ mod tests {
         let err = result.expect_err("should fail when slug doesn't exist");
         assert_eq!(err.current_context(), &UpdateError::NotFound);
     }
+
+    #[tokio::test]
+    pub async fn update_feed__new_guid() {
+        // Arrange
+        let metadata = MockServices::default()
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let mut feed = MockFeeds::default()
+            .feeds
+            .into_iter()
+            .next()
+            .expect("should have at least one feed");
+        let count = feed.episodes.len();
+        let episode = feed
+            .episodes
+            .first_mut()
+            .expect("should have at least one episode");
+        episode.source_id = "new-guid".to_owned();
+
+        // Act
+        let result = metadata.update_feed(feed).await;
+
+        // Assert
+        let response = result.assert_ok_debug();
+        assert_eq!(response.episodes_updated, count);
+        assert_eq!(response.episodes_inserted, 0);
+        assert_eq!(response.episodes_removed, 0);
+    }
+
+    #[tokio::test]
+    pub async fn update_feed__collisions() {
+        // Arrange
+        let metadata = MockServices::default()
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let mut feed = MockFeeds::default()
+            .feeds
+            .into_iter()
+            .next()
+            .expect("should have at least one feed");
+        for (title, url) in [
+            ("First", "https://example.com/1.mp3"),
+            ("Second", "https://example.com/2.mp3"),
+        ] {
+            feed.episodes.push(EpisodeInfo {
+                source_id: "reused-guid".to_owned(),
+                title: title.to_owned(),
+                source_url: UrlWrapper::from_str(url).expect("should be valid URL"),
+                ..EpisodeInfo::example()
+            });
+        }
+
+        // Act
+        let result = metadata.update_feed(feed).await;
+
+        // Assert
+        let response = result.assert_ok_debug();
+        assert_eq!(response.episodes_inserted, 2);
+        assert_eq!(
+            response.collisions,
+            vec![GuidCollision {
+                guid: "reused-guid".to_owned(),
+                title: "Second".to_owned(),
+                resolution: GuidResolution::EnclosureUrl,
+            }]
+        );
+    }
 }
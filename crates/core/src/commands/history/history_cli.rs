@@ -0,0 +1,85 @@
+use crate::prelude::*;
+use std::fmt::Write as _;
+
+/// CLI command for showing the fetch history of a podcast.
+#[derive(FromServicesAsync)]
+pub struct HistoryCliCommand {
+    metadata: Arc<MetadataRepository>,
+}
+
+impl HistoryCliCommand {
+    /// Print the most recent fetches of the podcast, newest first.
+    pub async fn execute(&self, options: HistoryOptions) -> Result<(), Report<HistoryError>> {
+        let history = self
+            .metadata
+            .get_fetch_history(options.podcast.clone(), options.limit)
+            .await
+            .change_context(HistoryError::Repository)
+            .attach("Podcast", options.podcast.clone())?;
+        if history.is_empty() {
+            info!(podcast = %options.podcast, "Podcast has not been fetched");
+            return Ok(());
+        }
+        for entry in &history {
+            println!("{}", format_entry(entry));
+        }
+        Ok(())
+    }
+}
+
+/// Format a fetch and its episode changes as lines of text.
+fn format_entry(entry: &FetchHistoryInfo) -> String {
+    let mut output = format!(
+        "{} · {}",
+        entry.fetched_at.format("%Y-%m-%d %H:%M:%S %:z"),
+        entry.status
+    );
+    if let Some(url) = &entry.url {
+        let _ = write!(output, " · {}", url.to_redacted_string());
+    }
+    if entry.modified {
+        let _ = write!(
+            output,
+            " · {} inserted · {} updated · {} removed",
+            entry.episodes_inserted, entry.episodes_updated, entry.episodes_removed
+        );
+    } else {
+        output.push_str(" · not modified");
+    }
+    for change in &entry.changes.0 {
+        let _ = write!(output, "\n  {}", change.title);
+        for field in &change.fields {
+            let _ = match (field.field, &field.old, &field.new) {
+                (EpisodeField::Description, _, _) => {
+                    write!(output, "\n    {} changed", field.field)
+                }
+                (_, Some(old), Some(new)) => {
+                    write!(output, "\n    {} changed from {old} to {new}", field.field)
+                }
+                (_, old, new) => write!(
+                    output,
+                    "\n    {} changed from {old:?} to {new:?}",
+                    field.field
+                ),
+            };
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _format_entry() {
+        // Arrange
+        let entry = FetchHistoryInfo::example();
+
+        // Act
+        let output = format_entry(&entry);
+
+        // Assert
+        assert_snapshot!(output);
+    }
+}
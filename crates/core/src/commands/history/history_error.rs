@@ -0,0 +1,8 @@
+use crate::prelude::*;
+
+/// Errors from [`HistoryCliCommand`].
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum HistoryError {
+    #[error("Unable to query database")]
+    Repository,
+}
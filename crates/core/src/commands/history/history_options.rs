@@ -0,0 +1,12 @@
+use crate::prelude::*;
+
+/// CLI options for [`HistoryCliCommand`].
+#[derive(Clone, Debug, Args)]
+pub struct HistoryOptions {
+    /// Slug of the podcast.
+    #[arg(long)]
+    pub podcast: Slug,
+    /// Maximum number of fetches to show.
+    #[arg(long, default_value_t = 20)]
+    pub limit: u64,
+}
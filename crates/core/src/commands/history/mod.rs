@@ -0,0 +1,9 @@
+#[cfg(feature = "server")]
+mod history_cli;
+mod history_error;
+mod history_options;
+
+#[cfg(feature = "server")]
+pub use history_cli::*;
+pub use history_error::*;
+pub use history_options::*;
@@ -0,0 +1,71 @@
+use crate::prelude::*;
+
+/// CLI command for adding podcasts from an OPML file.
+#[derive(FromServicesAsync)]
+pub struct ImportCliCommand {
+    planner: Arc<ImportPlanner>,
+    cli_runner: Arc<CliRunner>,
+}
+
+impl ImportCliCommand {
+    /// Import the feeds of an OPML file.
+    ///
+    /// - Skips feeds that have already been added
+    /// - Adds every other feed with a slug generated from its title
+    /// - Reports the outcome of every feed
+    pub async fn execute(&self, options: ImportOptions) -> Result<(), Report<ImportError>> {
+        let xml = read_to_string(&options.path)
+            .await
+            .change_context(ImportError::Read)
+            .attach_path(&options.path)?;
+        let plan = self.planner.plan(&xml).await?;
+        let mut entries = plan.entries;
+        let status = self.cli_runner.run(plan.requests).await;
+        for (request, _response) in &status.succeeded {
+            set_status(
+                &mut entries,
+                request,
+                ImportStatus::Added(request.slug.clone()),
+            );
+        }
+        for (request, error) in &status.failed {
+            warn!("{}", error.render());
+            set_status(
+                &mut entries,
+                request,
+                ImportStatus::Failed(error.to_string()),
+            );
+        }
+        for entry in &entries {
+            match entry.status {
+                ImportStatus::Queued(_) | ImportStatus::Added(_) => info!("{entry}"),
+                ImportStatus::Duplicate(_) | ImportStatus::Failed(_) => warn!("{entry}"),
+            }
+        }
+        info!("Imported {} podcasts", status.succeeded.len());
+        let duplicates = entries
+            .iter()
+            .filter(|entry| matches!(entry.status, ImportStatus::Duplicate(_)))
+            .count();
+        if duplicates > 0 {
+            warn!("Skipped {duplicates} duplicate podcasts");
+        }
+        let failed = entries
+            .iter()
+            .filter(|entry| matches!(entry.status, ImportStatus::Failed(_)))
+            .count();
+        if failed > 0 {
+            warn!("Failed to import {failed} podcasts");
+        }
+        Ok(())
+    }
+}
+
+fn set_status(entries: &mut [ImportEntry], request: &AddRequest, status: ImportStatus) {
+    let entry = entries
+        .iter_mut()
+        .find(|entry| entry.status == ImportStatus::Queued(request.slug.clone()));
+    if let Some(entry) = entry {
+        entry.status = status;
+    }
+}
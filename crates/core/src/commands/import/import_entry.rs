@@ -0,0 +1,40 @@
+use crate::prelude::*;
+
+/// Outcome of importing a single feed from an OPML file.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ImportEntry {
+    /// Title of the OPML outline.
+    pub title: String,
+    /// Feed URL of the OPML outline.
+    pub feed_url: String,
+    /// Outcome of the import.
+    pub status: ImportStatus,
+}
+
+/// Status of an [`ImportEntry`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum ImportStatus {
+    /// An [`AddRequest`] has been queued with the generated slug.
+    Queued(Slug),
+    /// The podcast was added with the generated slug.
+    Added(Slug),
+    /// The feed URL already belongs to a podcast with this slug.
+    Duplicate(Slug),
+    /// The feed could not be imported.
+    Failed(String),
+}
+
+impl Display for ImportEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.status {
+            ImportStatus::Queued(slug) => write!(f, "Queued {slug} from {}", self.feed_url),
+            ImportStatus::Added(slug) => write!(f, "Added {slug} from {}", self.feed_url),
+            ImportStatus::Duplicate(slug) => {
+                write!(f, "Skipped {} as a duplicate of {slug}", self.feed_url)
+            }
+            ImportStatus::Failed(reason) => {
+                write!(f, "Failed to import {}: {reason}", self.feed_url)
+            }
+        }
+    }
+}
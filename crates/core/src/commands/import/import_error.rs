@@ -0,0 +1,12 @@
+use crate::prelude::*;
+
+/// Errors from importing an OPML file.
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum ImportError {
+    #[error("Unable to read OPML file")]
+    Read,
+    #[error("Unable to parse OPML")]
+    Parse,
+    #[error("Unable to query database")]
+    Repository,
+}
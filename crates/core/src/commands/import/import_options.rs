@@ -0,0 +1,8 @@
+use crate::prelude::*;
+
+/// CLI options for [`ImportCliCommand`].
+#[derive(Clone, Debug, Args)]
+pub struct ImportOptions {
+    /// Path of the OPML file to import.
+    pub path: PathBuf,
+}
@@ -0,0 +1,157 @@
+use crate::prelude::*;
+
+/// Fallback slug for outlines without a usable title.
+const DEFAULT_SLUG: &str = "podcast";
+
+/// Plans the import of an OPML file.
+///
+/// - Generates a unique [`Slug`] from each outline title
+/// - Skips feeds that have already been added or appear twice
+/// - Creates an [`AddRequest`] for every other feed
+#[derive(FromServicesAsync)]
+pub struct ImportPlanner {
+    metadata: Arc<MetadataRepository>,
+}
+
+/// Result of [`ImportPlanner::plan`].
+pub struct ImportPlan {
+    /// Requests to add each new feed.
+    pub requests: Vec<AddRequest>,
+    /// Outcome of every subscription in the OPML file.
+    pub entries: Vec<ImportEntry>,
+}
+
+impl ImportPlanner {
+    /// Plan the import of an OPML document.
+    pub async fn plan(&self, xml: &str) -> Result<ImportPlan, Report<ImportError>> {
+        let opml = Opml::parse(xml).change_context(ImportError::Parse)?;
+        let existing = self
+            .metadata
+            .get_all_feed_urls()
+            .await
+            .change_context(ImportError::Repository)?;
+        let mut slugs: HashSet<Slug> = existing.iter().map(|(slug, _)| slug.clone()).collect();
+        let mut feeds: HashMap<UrlWrapper, Slug> = existing
+            .into_iter()
+            .filter_map(|(slug, url)| Some((url?, slug)))
+            .collect();
+        let mut requests = Vec::new();
+        let mut entries = Vec::new();
+        for outline in opml.get_subscriptions() {
+            let title = outline.get_title().trim().to_owned();
+            let feed_url = outline.xml_url.clone().unwrap_or_default();
+            let status = match UrlWrapper::from_str(feed_url.trim()) {
+                Err(error) => ImportStatus::Failed(error.to_string()),
+                Ok(url) => {
+                    if let Some(slug) = feeds.get(&url) {
+                        ImportStatus::Duplicate(slug.clone())
+                    } else {
+                        let slug = get_unique_slug(&title, &slugs);
+                        slugs.insert(slug.clone());
+                        feeds.insert(url.clone(), slug.clone());
+                        requests.push(AddRequest {
+                            slug: slug.clone(),
+                            feed_url: url,
+                            credentials: None,
+                        });
+                        ImportStatus::Queued(slug)
+                    }
+                }
+            };
+            entries.push(ImportEntry {
+                title,
+                feed_url,
+                status,
+            });
+        }
+        Ok(ImportPlan { requests, entries })
+    }
+}
+
+/// Generate a slug from a title that is not in `taken`.
+///
+/// - Lowercases ASCII letters and digits
+/// - Replaces every other run of characters with a single dash
+/// - Appends `-2`, `-3`, etc. until the slug is unique
+fn get_unique_slug(title: &str, taken: &HashSet<Slug>) -> Slug {
+    let mut base = String::new();
+    for c in title.chars() {
+        if c.is_ascii_alphanumeric() {
+            base.push(c.to_ascii_lowercase());
+        } else if !base.is_empty() && !base.ends_with('-') {
+            base.push('-');
+        }
+    }
+    let base = base.trim_end_matches('-');
+    let base = if base.is_empty() { DEFAULT_SLUG } else { base };
+    let mut candidate = base.to_owned();
+    let mut suffix = 2;
+    loop {
+        let slug = Slug::from_str(&candidate).expect("should be a valid slug");
+        if !taken.contains(&slug) {
+            return slug;
+        }
+        candidate = format!("{base}-{suffix}");
+        suffix += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_unique_slug_from_title() {
+        // Arrange
+        let mut taken = HashSet::new();
+        taken.insert(Slug::from_str("the-daily").expect("should be valid slug"));
+
+        // Act
+        let slugs = [
+            get_unique_slug("  The Daily! ", &taken),
+            get_unique_slug("Café & Crème: 2024", &taken),
+            get_unique_slug("!!!", &taken),
+        ];
+
+        // Assert
+        let slugs: Vec<&str> = slugs.iter().map(Slug::as_str).collect();
+        assert_eq!(slugs, vec!["the-daily-2", "caf-cr-me-2024", "podcast"]);
+    }
+
+    #[tokio::test]
+    async fn plan() {
+        // Arrange
+        let services = MockServices::new()
+            .with_metadata_factory(MockFeedsFactory {
+                podcast_count: 1,
+                edit_podcast: Some(|podcast| {
+                    podcast.feed_url = Some(MockServices::rss_url());
+                }),
+                ..MockFeedsFactory::default()
+            })
+            .create()
+            .await;
+        let planner = services
+            .get_async::<ImportPlanner>()
+            .await
+            .expect("should be able to get import planner");
+        let xml = format!(
+            r#"<opml version="2.0"><body>
+  <outline text="Existing" xmlUrl="{}"/>
+  <outline text="Test 0" xmlUrl="https://example.com/new.xml"/>
+  <outline text="Test 0" xmlUrl="https://example.com/new.xml"/>
+  <outline text="Test 0" xmlUrl="https://example.com/other.xml"/>
+  <outline text="Broken" xmlUrl="not a url"/>
+</body></opml>"#,
+            MockServices::rss_url()
+        );
+
+        // Act
+        let result = planner.plan(&xml).await;
+
+        // Assert
+        let plan = result.assert_ok_debug();
+        assert_eq!(plan.requests.len(), 2);
+        assert_yaml_snapshot!(plan.entries);
+    }
+}
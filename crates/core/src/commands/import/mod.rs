@@ -0,0 +1,15 @@
+#[cfg(feature = "server")]
+mod import_cli;
+mod import_entry;
+mod import_error;
+mod import_options;
+#[cfg(feature = "server")]
+mod import_planner;
+
+#[cfg(feature = "server")]
+pub use import_cli::*;
+pub use import_entry::*;
+pub use import_error::*;
+pub use import_options::*;
+#[cfg(feature = "server")]
+pub use import_planner::*;
@@ -3,23 +3,45 @@
 mod add;
 #[cfg(feature = "server")]
 mod cli_runner;
+#[cfg(feature = "server")]
+mod command_queue;
+mod configure;
 mod cover;
 mod define;
 mod download;
 mod emulate;
+mod export;
 mod fetch;
+mod history;
+mod import;
 mod podcast_options;
 #[cfg(feature = "server")]
 mod podcast_selector;
+mod prune;
+mod queue;
+mod refresh;
+mod relayout;
+mod validate;
 
 pub use add::*;
 #[cfg(feature = "server")]
 pub use cli_runner::*;
+#[cfg(feature = "server")]
+pub use command_queue::*;
+pub use configure::*;
 pub use cover::*;
 pub use define::*;
 pub use download::*;
 pub use emulate::*;
+pub use export::*;
 pub use fetch::*;
+pub use history::*;
+pub use import::*;
 pub use podcast_options::*;
 #[cfg(feature = "server")]
 pub use podcast_selector::*;
+pub use prune::*;
+pub use queue::*;
+pub use refresh::*;
+pub use relayout::*;
+pub use validate::*;
@@ -0,0 +1,128 @@
+use crate::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+
+/// Downloaded files and play state of an episode.
+#[derive(Clone, Debug, FromQueryResult, PartialEq)]
+pub struct DownloadedEpisode {
+    /// Primary key of the episode
+    pub primary_key: EpisodeKey,
+    /// Title of the episode
+    pub title: String,
+    /// Date and time the episode was published
+    pub published_at: DateTime<FixedOffset>,
+    /// Date and time the episode was first played
+    pub played_at: Option<DateTime<FixedOffset>>,
+    /// Path of the audio file relative to the podcasts directory
+    pub file_sub_path: PathWrapper,
+    /// Path of the image file relative to the podcasts directory
+    pub image_sub_path: Option<PathWrapper>,
+}
+
+impl MetadataRepository {
+    /// Get the primary key and retention rules of a podcast.
+    ///
+    /// Returns `Ok(None)` if the podcast does not exist.
+    pub(crate) async fn get_retention(
+        &self,
+        slug: &Slug,
+    ) -> Result<Option<(PodcastKey, Option<RetentionRules>)>, DbErr> {
+        get_retention_query(slug)
+            .into_tuple::<(PodcastKey, Option<RetentionRules>)>()
+            .one(&self.db)
+            .await
+    }
+
+    /// Get the downloaded episodes of a podcast, newest first.
+    pub async fn get_downloaded_episodes(
+        &self,
+        podcast_key: PodcastKey,
+    ) -> Result<Vec<DownloadedEpisode>, DbErr> {
+        get_downloaded_episodes_query(podcast_key)
+            .into_model::<DownloadedEpisode>()
+            .all(&self.db)
+            .await
+    }
+
+    /// Clear the downloaded audio and image paths of episodes.
+    pub(crate) async fn clear_download_paths(
+        &self,
+        episode_keys: &[EpisodeKey],
+    ) -> Result<(), DbErr> {
+        clear_download_paths_query(episode_keys)
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+}
+
+fn get_retention_query(slug: &Slug) -> Select<podcast::Entity> {
+    podcast::Entity::find()
+        .select_only()
+        .column(podcast::Column::PrimaryKey)
+        .column(podcast::Column::Retention)
+        .filter(podcast::Column::Slug.eq(slug.to_string()))
+}
+
+fn get_downloaded_episodes_query(podcast_key: PodcastKey) -> Select<episode::Entity> {
+    episode::Entity::find()
+        .select_only()
+        .column(episode::Column::PrimaryKey)
+        .column(episode::Column::Title)
+        .column(episode::Column::PublishedAt)
+        .column(episode::Column::PlayedAt)
+        .column(episode::Column::FileSubPath)
+        .column(episode::Column::ImageSubPath)
+        .filter(episode::Column::PodcastKey.eq(podcast_key))
+        .filter(episode::Column::FileSubPath.is_not_null())
+        .order_by_desc(episode::Column::PublishedAt)
+}
+
+fn clear_download_paths_query(episode_keys: &[EpisodeKey]) -> UpdateMany<episode::Entity> {
+    episode::Entity::update_many()
+        .col_expr(
+            episode::Column::FileSubPath,
+            Expr::value(None::<PathWrapper>),
+        )
+        .col_expr(
+            episode::Column::ImageSubPath,
+            Expr::value(None::<PathWrapper>),
+        )
+        .filter(episode::Column::PrimaryKey.is_in(episode_keys.iter().copied()))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn _get_retention_query() {
+        // Arrange
+        // Act
+        let statement = get_retention_query(&MockFeeds::podcast_slug()).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[test]
+    fn _get_downloaded_episodes_query() {
+        // Arrange
+        // Act
+        let statement = get_downloaded_episodes_query(MockFeeds::PODCAST_KEY).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[test]
+    fn _clear_download_paths_query() {
+        // Arrange
+        // Act
+        let statement = clear_download_paths_query(&[1, 2]).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+}
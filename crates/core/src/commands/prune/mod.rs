@@ -0,0 +1,21 @@
+#[cfg(feature = "server")]
+mod get_downloaded_episodes;
+#[cfg(feature = "server")]
+mod prune_cli;
+mod prune_error;
+#[cfg(feature = "server")]
+mod prune_handler;
+mod prune_options;
+mod prune_request;
+mod prune_response;
+
+#[cfg(feature = "server")]
+pub use get_downloaded_episodes::*;
+#[cfg(feature = "server")]
+pub use prune_cli::*;
+pub use prune_error::*;
+#[cfg(feature = "server")]
+pub use prune_handler::*;
+pub use prune_options::*;
+pub use prune_request::*;
+pub use prune_response::*;
@@ -0,0 +1,46 @@
+use crate::prelude::*;
+
+/// CLI command for deleting downloaded episodes excluded by retention rules.
+#[derive(FromServicesAsync)]
+pub struct PruneCliCommand {
+    selector: Arc<PodcastSelector>,
+    cli_runner: Arc<CliRunner>,
+}
+
+impl PruneCliCommand {
+    /// Prune podcasts matching the options.
+    pub async fn execute(&self, options: PruneOptions) -> Result<(), Report<PodcastSelectorError>> {
+        let slugs = self.selector.execute(&options.selection).await?;
+        let requests = slugs.into_iter().map(|slug| PruneRequest {
+            slug,
+            dry_run: options.dry_run,
+        });
+        let status = self.cli_runner.run(requests).await;
+        for (_request, error) in &status.failed {
+            warn!("{}", error.render());
+        }
+        let action = if options.dry_run {
+            "Would delete"
+        } else {
+            "Deleted"
+        };
+        let mut count = 0;
+        let mut size = 0;
+        for (request, response) in &status.succeeded {
+            for episode in &response.episodes {
+                info!(podcast = %request.slug, "{action} {episode}");
+            }
+            count += response.episodes.len();
+            size += response.get_size();
+        }
+        info!(
+            "{action} {count} episodes from {} podcasts ({} MB)",
+            status.succeeded.len(),
+            size.div_ceil(1_000_000)
+        );
+        if !status.failed.is_empty() {
+            warn!("Failed to prune {} podcasts", status.failed.len());
+        }
+        Ok(())
+    }
+}
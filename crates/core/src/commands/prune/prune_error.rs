@@ -0,0 +1,12 @@
+use crate::prelude::*;
+
+/// Errors from [`PruneHandler`].
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum PruneError {
+    #[error("Unable to query database")]
+    Repository,
+    #[error("Podcast does not exist")]
+    NoPodcast,
+    #[error("Unable to update the database")]
+    Save,
+}
@@ -0,0 +1,391 @@
+use crate::prelude::*;
+use chrono::TimeDelta;
+use std::io::ErrorKind;
+use std::iter::once;
+
+/// Bytes in a megabyte of [`RetentionRules::max_size_mb`].
+const BYTES_PER_MB: u64 = 1_000_000;
+
+/// Delete downloaded episodes excluded by the retention rules.
+///
+/// - Rules of the podcast replace the server rules from [`AppOptions`]
+/// - Without any rules every download is kept
+/// - Deletes the audio and image files then clears their paths in the database
+#[derive(Clone, FromServicesAsync)]
+pub struct PruneHandler {
+    paths: Arc<PathProvider>,
+    metadata: Arc<MetadataRepository>,
+    options: Arc<AppOptions>,
+}
+
+/// Downloaded episode with the size of its files.
+struct PruneCandidate {
+    episode: DownloadedEpisode,
+    size: u64,
+}
+
+#[async_trait]
+impl Execute<PruneRequest, PruneResponse, Report<PruneError>> for PruneHandler {
+    /// Execute the prune handler.
+    async fn execute(&self, request: &PruneRequest) -> Result<PruneResponse, Report<PruneError>> {
+        let (podcast_key, retention) = self
+            .metadata
+            .get_retention(&request.slug)
+            .await
+            .change_context(PruneError::Repository)?
+            .ok_or(PruneError::NoPodcast)?;
+        let mut response = PruneResponse {
+            rules: retention.or_else(|| self.options.get_retention()),
+            episodes: Vec::new(),
+            dry_run: request.dry_run,
+        };
+        let Some(rules) = response.rules.clone().filter(|rules| !rules.is_empty()) else {
+            trace!(podcast = %request.slug, "No retention rules");
+            return Ok(response);
+        };
+        let episodes = self
+            .metadata
+            .get_downloaded_episodes(podcast_key)
+            .await
+            .change_context(PruneError::Repository)?;
+        let candidates = self.get_candidates(episodes).await;
+        let reasons = get_prune_reasons(&rules, &candidates, Utc::now().fixed_offset());
+        let mut episode_keys = Vec::new();
+        for (candidate, reason) in candidates.into_iter().zip(reasons) {
+            let Some(reason) = reason else {
+                continue;
+            };
+            if !request.dry_run && !self.delete_files(&request.slug, &candidate.episode).await {
+                continue;
+            }
+            episode_keys.push(candidate.episode.primary_key);
+            response.episodes.push(PrunedEpisode {
+                episode_key: candidate.episode.primary_key,
+                title: candidate.episode.title,
+                size: candidate.size,
+                reason,
+            });
+        }
+        if !request.dry_run && !episode_keys.is_empty() {
+            self.metadata
+                .clear_download_paths(&episode_keys)
+                .await
+                .change_context(PruneError::Save)
+                .attach("Podcast", request.slug.clone())?;
+        }
+        trace!(podcast = %request.slug, %rules, episodes = response.episodes.len(), dry_run = request.dry_run, "Pruned episodes");
+        Ok(response)
+    }
+}
+
+impl PruneHandler {
+    /// Get the size of the downloaded files of each episode.
+    ///
+    /// Files that no longer exist have no size.
+    async fn get_candidates(&self, episodes: Vec<DownloadedEpisode>) -> Vec<PruneCandidate> {
+        let podcasts_dir = self.paths.get_podcasts_dir();
+        let mut candidates = Vec::with_capacity(episodes.len());
+        for episode in episodes {
+            let mut size = 0;
+            for sub_path in get_sub_paths(&episode) {
+                if let Ok(file) = metadata(podcasts_dir.join(sub_path)).await {
+                    size += file.len();
+                }
+            }
+            candidates.push(PruneCandidate { episode, size });
+        }
+        candidates
+    }
+
+    /// Delete the downloaded files of an episode.
+    ///
+    /// Files that no longer exist are ignored.
+    /// Returns `false` and warns if a file could not be deleted.
+    async fn delete_files(&self, slug: &Slug, episode: &DownloadedEpisode) -> bool {
+        let podcasts_dir = self.paths.get_podcasts_dir();
+        for sub_path in get_sub_paths(episode) {
+            let path = podcasts_dir.join(sub_path);
+            match remove_file(&path).await {
+                Ok(()) => {
+                    debug!(podcast = %slug, episode = %episode.title, path = %path.display(), "Deleted file");
+                }
+                Err(error) if error.kind() == ErrorKind::NotFound => {
+                    debug!(podcast = %slug, episode = %episode.title, path = %path.display(), "File already deleted");
+                }
+                Err(error) => {
+                    warn!(podcast = %slug, episode = %episode.title, path = %path.display(), %error, "Failed to delete file");
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+fn get_sub_paths(episode: &DownloadedEpisode) -> impl Iterator<Item = &PathBuf> {
+    once(episode.file_sub_path.as_ref()).chain(episode.image_sub_path.as_deref())
+}
+
+/// Get the rule that excludes each candidate, if any.
+///
+/// Candidates must be ordered newest first so the size limit prunes the
+/// oldest episodes. Only kept episodes count towards the size limit.
+fn get_prune_reasons(
+    rules: &RetentionRules,
+    candidates: &[PruneCandidate],
+    now: DateTime<FixedOffset>,
+) -> Vec<Option<PruneReason>> {
+    let cutoff = rules
+        .keep_days
+        .map(|days| now - TimeDelta::days(i64::from(days)));
+    let max_size = rules
+        .max_size_mb
+        .map(|size| size.saturating_mul(BYTES_PER_MB));
+    let mut total: u64 = 0;
+    candidates
+        .iter()
+        .enumerate()
+        .map(|(index, candidate)| {
+            let episode = &candidate.episode;
+            if rules
+                .keep_latest
+                .is_some_and(|latest| index >= usize::try_from(latest).unwrap_or(usize::MAX))
+            {
+                return Some(PruneReason::Latest);
+            }
+            if cutoff.is_some_and(|cutoff| episode.published_at < cutoff) {
+                return Some(PruneReason::Age);
+            }
+            if rules.unplayed_only && episode.played_at.is_some() {
+                return Some(PruneReason::Played);
+            }
+            total = total.saturating_add(candidate.size);
+            max_size
+                .is_some_and(|max_size| total > max_size)
+                .then_some(PruneReason::Size)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+    use tokio::fs::write;
+
+    fn now() -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339("2026-01-31T00:00:00Z").expect("should be valid date")
+    }
+
+    fn candidate(days_ago: i64, played: bool, size: u64) -> PruneCandidate {
+        PruneCandidate {
+            episode: DownloadedEpisode {
+                primary_key: 1,
+                title: format!("{days_ago} days ago"),
+                published_at: now() - TimeDelta::days(days_ago),
+                played_at: played.then(now),
+                file_sub_path: PathWrapper::from(PathBuf::from("episode.mp3")),
+                image_sub_path: None,
+            },
+            size,
+        }
+    }
+
+    fn candidates() -> Vec<PruneCandidate> {
+        vec![
+            candidate(1, false, 400_000),
+            candidate(5, true, 400_000),
+            candidate(10, false, 400_000),
+            candidate(20, false, 400_000),
+        ]
+    }
+
+    #[test]
+    fn get_prune_reasons__empty() {
+        // Arrange
+        let rules = RetentionRules::default();
+
+        // Act
+        let reasons = get_prune_reasons(&rules, &candidates(), now());
+
+        // Assert
+        assert_eq!(reasons, vec![None; 4]);
+    }
+
+    #[test]
+    fn get_prune_reasons__keep_latest() {
+        // Arrange
+        let rules = RetentionRules {
+            keep_latest: Some(2),
+            ..RetentionRules::default()
+        };
+
+        // Act
+        let reasons = get_prune_reasons(&rules, &candidates(), now());
+
+        // Assert
+        let expected = vec![
+            None,
+            None,
+            Some(PruneReason::Latest),
+            Some(PruneReason::Latest),
+        ];
+        assert_eq!(reasons, expected);
+    }
+
+    #[test]
+    fn get_prune_reasons__keep_days() {
+        // Arrange
+        let rules = RetentionRules {
+            keep_days: Some(7),
+            ..RetentionRules::default()
+        };
+
+        // Act
+        let reasons = get_prune_reasons(&rules, &candidates(), now());
+
+        // Assert
+        let expected = vec![None, None, Some(PruneReason::Age), Some(PruneReason::Age)];
+        assert_eq!(reasons, expected);
+    }
+
+    #[test]
+    fn get_prune_reasons__unplayed_only() {
+        // Arrange
+        let rules = RetentionRules {
+            unplayed_only: true,
+            ..RetentionRules::default()
+        };
+
+        // Act
+        let reasons = get_prune_reasons(&rules, &candidates(), now());
+
+        // Assert
+        let expected = vec![None, Some(PruneReason::Played), None, None];
+        assert_eq!(reasons, expected);
+    }
+
+    #[test]
+    fn get_prune_reasons__max_size() {
+        // Arrange
+        let rules = RetentionRules {
+            unplayed_only: true,
+            max_size_mb: Some(1),
+            ..RetentionRules::default()
+        };
+
+        // Act
+        let reasons = get_prune_reasons(&rules, &candidates(), now());
+
+        // Assert
+        let expected = vec![
+            None,
+            Some(PruneReason::Played),
+            None,
+            Some(PruneReason::Size),
+        ];
+        assert_eq!(reasons, expected);
+    }
+
+    async fn setup() -> (ServiceProvider, Vec<PathBuf>) {
+        let services = MockServices::new()
+            .with_metadata_factory(MockFeedsFactory {
+                edit_episode: Some(|episode| {
+                    let path = PathBuf::from(format!("{}.mp3", episode.source_id));
+                    episode.file_sub_path = Some(PathWrapper::from(path));
+                }),
+                ..MockFeedsFactory::default()
+            })
+            .create()
+            .await;
+        let paths = services
+            .get_async::<PathProvider>()
+            .await
+            .expect("should be able to get paths");
+        let metadata = services
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let podcasts_dir = paths.get_podcasts_dir();
+        create_dir_all(&podcasts_dir)
+            .await
+            .expect("should be able to create podcasts dir");
+        let mut files = Vec::new();
+        for episode in metadata
+            .get_downloaded_episodes(MockFeeds::PODCAST_KEY)
+            .await
+            .assert_ok_debug()
+        {
+            let path = podcasts_dir.join(episode.file_sub_path.as_ref());
+            write(&path, b"audio")
+                .await
+                .expect("should be able to write file");
+            files.push(path);
+        }
+        let rules = RetentionRules {
+            keep_latest: Some(2),
+            ..RetentionRules::default()
+        };
+        metadata
+            .set_retention(&MockFeeds::podcast_slug(), Some(rules))
+            .await
+            .assert_ok_debug();
+        (services, files)
+    }
+
+    #[tokio::test]
+    pub async fn prune_handler() {
+        // Arrange
+        let (services, files) = setup().await;
+        let handler = services
+            .get_async::<PruneHandler>()
+            .await
+            .expect("should be able to get handler");
+        let request = PruneRequest {
+            slug: MockFeeds::podcast_slug(),
+            dry_run: false,
+        };
+
+        // Act
+        let result = handler.execute(&request).await;
+
+        // Assert
+        let response = result.assert_ok_debug();
+        assert_eq!(response.episodes.len(), files.len() - 2);
+        assert_eq!(files.iter().filter(|path| path.exists()).count(), 2);
+        let remaining = handler
+            .metadata
+            .get_downloaded_episodes(MockFeeds::PODCAST_KEY)
+            .await
+            .assert_ok_debug();
+        assert_eq!(remaining.len(), 2);
+    }
+
+    #[tokio::test]
+    pub async fn prune_handler__dry_run() {
+        // Arrange
+        let (services, files) = setup().await;
+        let handler = services
+            .get_async::<PruneHandler>()
+            .await
+            .expect("should be able to get handler");
+        let request = PruneRequest {
+            slug: MockFeeds::podcast_slug(),
+            dry_run: true,
+        };
+
+        // Act
+        let result = handler.execute(&request).await;
+
+        // Assert
+        let response = result.assert_ok_debug();
+        assert_eq!(response.episodes.len(), files.len() - 2);
+        assert!(files.iter().all(|path| path.exists()));
+        let remaining = handler
+            .metadata
+            .get_downloaded_episodes(MockFeeds::PODCAST_KEY)
+            .await
+            .assert_ok_debug();
+        assert_eq!(remaining.len(), files.len());
+    }
+}
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+
+/// CLI options for [`PruneCliCommand`].
+#[derive(Clone, Debug, Args)]
+pub struct PruneOptions {
+    /// Podcast selection options.
+    #[command(flatten)]
+    pub selection: PodcastOptions,
+    /// List the episodes that would be pruned without deleting them.
+    #[arg(long)]
+    pub dry_run: bool,
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+
+/// A request to execute a [`PruneHandler`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct PruneRequest {
+    /// User-defined identifier for the podcast.
+    pub slug: Slug,
+    /// Report the episodes that would be pruned without deleting them.
+    pub dry_run: bool,
+}
+
+impl Display for PruneRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "prune {}", self.slug)
+    }
+}
+
+impl Executable for PruneRequest {
+    type Response = PruneResponse;
+    type ExecutionError = Report<PruneError>;
+}
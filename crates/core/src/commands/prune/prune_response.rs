@@ -0,0 +1,61 @@
+use crate::prelude::*;
+
+/// A response returned by [`PruneHandler`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct PruneResponse {
+    /// Retention rules that were applied.
+    ///
+    /// `None` if neither the podcast nor the server has retention rules.
+    pub rules: Option<RetentionRules>,
+    /// Episodes whose downloaded files were deleted, or would be for a dry run.
+    pub episodes: Vec<PrunedEpisode>,
+    /// Whether the files were left in place.
+    pub dry_run: bool,
+}
+
+impl PruneResponse {
+    /// Total size in bytes of the pruned files.
+    #[must_use]
+    pub fn get_size(&self) -> u64 {
+        self.episodes.iter().map(|episode| episode.size).sum()
+    }
+}
+
+/// A downloaded episode selected by the retention rules.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct PrunedEpisode {
+    /// Primary key of the episode
+    pub episode_key: EpisodeKey,
+    /// Title of the episode
+    pub title: String,
+    /// Size in bytes of the downloaded audio and image files
+    pub size: u64,
+    /// Rule that excluded the episode
+    pub reason: PruneReason,
+}
+
+/// Retention rule that excluded a [`PrunedEpisode`].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PruneReason {
+    /// Not one of the most recently published episodes
+    Latest,
+    /// Published before the retention period
+    Age,
+    /// Already played
+    Played,
+    /// Total size of the newer episodes reached the limit
+    Size,
+}
+
+impl Display for PrunedEpisode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let reason = match self.reason {
+            PruneReason::Latest => "not in the latest episodes",
+            PruneReason::Age => "older than the retention period",
+            PruneReason::Played => "already played",
+            PruneReason::Size => "over the size limit",
+        };
+        write!(f, "{} ({reason})", self.title)
+    }
+}
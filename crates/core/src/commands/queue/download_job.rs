@@ -0,0 +1,35 @@
+use crate::prelude::*;
+use sea_orm::FromQueryResult;
+
+/// A job in the download queue with the titles needed to display it.
+#[derive(Clone, Debug, Deserialize, Eq, FromQueryResult, Hash, PartialEq, Serialize)]
+pub struct DownloadJob {
+    /// Primary key of the job
+    pub primary_key: DownloadJobKey,
+    /// Primary key of the podcast
+    pub podcast_key: PodcastKey,
+    /// Primary key of the episode
+    pub episode_key: EpisodeKey,
+    /// Replace an existing download
+    pub replace: bool,
+    /// Slug of the podcast
+    pub podcast_slug: Slug,
+    /// Title of the episode
+    pub episode_title: String,
+    /// Order in the queue, lowest first
+    pub position: u32,
+    /// State of the job
+    pub status: DownloadJobStatus,
+    /// Rendered error of the last failed attempt
+    pub error: Option<String>,
+    /// Date and time the job was added or retried
+    pub queued_at: DateTime<FixedOffset>,
+}
+
+impl DownloadJob {
+    /// Get the request to execute the job.
+    #[must_use]
+    pub fn get_request(&self) -> DownloadRequest {
+        DownloadRequest::new(self.podcast_key, self.episode_key, self.replace)
+    }
+}
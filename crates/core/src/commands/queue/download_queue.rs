@@ -0,0 +1,464 @@
+use crate::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Maximum jobs sent to the [`CommandRunner`] at once.
+const CONCURRENCY: u64 = 2;
+
+/// Persistent queue of download requests for the web server.
+///
+/// - Jobs are stored in the database so they survive a restart
+/// - Only [`CONCURRENCY`] jobs are sent to the [`CommandRunner`] at once so
+///   the rest can still be reordered or cancelled
+/// - Successful jobs are removed and failed jobs are kept to be retried
+/// - Cancelled jobs have their partial download removed
+pub struct DownloadQueue {
+    metadata: Arc<MetadataRepository>,
+    queue: Arc<CommandQueue>,
+    download: Arc<DownloadHandler>,
+    /// Held while the queue is changed so jobs are not dispatched twice
+    lock: Mutex<()>,
+    /// Set once the web server has restored the queue
+    restored: AtomicBool,
+}
+
+impl DownloadQueue {
+    /// Check if the queue has been restored by the web server.
+    ///
+    /// Until then nothing handles its events, so requests should be sent
+    /// straight to the [`CommandRunner`] instead.
+    #[must_use]
+    pub fn is_restored(&self) -> bool {
+        self.restored.load(Ordering::SeqCst)
+    }
+
+    /// Get every job in the queue in order.
+    pub async fn get_jobs(&self) -> Result<Vec<DownloadJob>, Report<DownloadQueueError>> {
+        self.metadata
+            .get_download_jobs()
+            .await
+            .change_context(DownloadQueueError::Repository)
+    }
+
+    /// Add a request to the end of the queue.
+    ///
+    /// - A failed job for the same episode is retried
+    /// - A queued or running job for the same episode is left as is
+    pub async fn add(
+        &self,
+        request: DownloadRequest,
+    ) -> Result<DownloadJobKey, Report<DownloadQueueError>> {
+        let _guard = self.lock.lock().await;
+        let existing = self
+            .metadata
+            .get_download_job_by_episode(request.episode)
+            .await
+            .change_context(DownloadQueueError::Repository)?;
+        let job_key = if let Some(job) = existing {
+            if job.status == DownloadJobStatus::Failed {
+                self.requeue(job.primary_key).await?;
+            }
+            job.primary_key
+        } else {
+            let position = self.get_next_position().await?;
+            self.metadata
+                .insert_download_job(request, position, Utc::now().fixed_offset())
+                .await
+                .change_context(DownloadQueueError::Repository)
+                .attach("Request", request.to_string())?
+        };
+        self.dispatch().await?;
+        Ok(job_key)
+    }
+
+    /// Move a job to a position in the queue.
+    ///
+    /// Positions past the end move the job to the end.
+    pub async fn move_to(
+        &self,
+        job_key: DownloadJobKey,
+        position: u32,
+    ) -> Result<(), Report<DownloadQueueError>> {
+        let _guard = self.lock.lock().await;
+        let mut job_keys = self
+            .metadata
+            .get_download_job_keys()
+            .await
+            .change_context(DownloadQueueError::Repository)?;
+        let index = job_keys
+            .iter()
+            .position(|&key| key == job_key)
+            .ok_or_else(|| Report::new(DownloadQueueError::NoJob).attach("Job", job_key))?;
+        job_keys.remove(index);
+        let index = usize::try_from(position)
+            .unwrap_or(usize::MAX)
+            .min(job_keys.len());
+        job_keys.insert(index, job_key);
+        self.metadata
+            .set_download_job_positions(&job_keys)
+            .await
+            .change_context(DownloadQueueError::Repository)
+    }
+
+    /// Remove a job that is not running from the queue.
+    ///
+    /// The partial file of an interrupted download is removed as it won't be resumed.
+    pub async fn cancel(&self, job_key: DownloadJobKey) -> Result<(), Report<DownloadQueueError>> {
+        let _guard = self.lock.lock().await;
+        let job = self.get_job(job_key).await?;
+        if job.status == DownloadJobStatus::Running {
+            return Err(Report::new(DownloadQueueError::Running).attach("Job", job_key));
+        }
+        self.metadata
+            .delete_download_job(job_key)
+            .await
+            .change_context(DownloadQueueError::Repository)?;
+        if let Err(report) = self.download.remove_partial(&job.get_request()).await {
+            warn!("{}", report.render());
+        }
+        Ok(())
+    }
+
+    /// Move a failed job to the end of the queue.
+    ///
+    /// Queued and running jobs are left as is.
+    pub async fn retry(&self, job_key: DownloadJobKey) -> Result<(), Report<DownloadQueueError>> {
+        let _guard = self.lock.lock().await;
+        let job = self.get_job(job_key).await?;
+        if job.status == DownloadJobStatus::Failed {
+            self.requeue(job_key).await?;
+        }
+        self.dispatch().await
+    }
+
+    /// Return jobs interrupted by a restart to the queue and dispatch them.
+    pub async fn restore(&self) -> Result<(), Report<DownloadQueueError>> {
+        let _guard = self.lock.lock().await;
+        let count = self
+            .metadata
+            .reset_running_download_jobs()
+            .await
+            .change_context(DownloadQueueError::Repository)?;
+        if count > 0 {
+            info!("Restored {count} interrupted downloads to the queue");
+        }
+        self.restored.store(true, Ordering::SeqCst);
+        self.dispatch().await
+    }
+
+    /// Update a job when its download completes then dispatch the next.
+    ///
+    /// Events for requests that were not added to the queue are ignored.
+    pub async fn handle_event(
+        &self,
+        event: &CommandEvent,
+    ) -> Result<(), Report<DownloadQueueError>> {
+        let CommandRequest::Download(request) = event.get_request() else {
+            return Ok(());
+        };
+        let succeeded = match event.get_kind() {
+            EventKind::Succeeded => true,
+            EventKind::Failed => false,
+            _ => return Ok(()),
+        };
+        let _guard = self.lock.lock().await;
+        let Some(job) = self
+            .metadata
+            .get_download_job_by_episode(request.episode)
+            .await
+            .change_context(DownloadQueueError::Repository)?
+            .filter(|job| job.status == DownloadJobStatus::Running)
+        else {
+            return Ok(());
+        };
+        if succeeded {
+            self.metadata
+                .delete_download_job(job.primary_key)
+                .await
+                .change_context(DownloadQueueError::Repository)?;
+        } else {
+            let error = self.take_error(request).await;
+            self.metadata
+                .set_download_job_status(job.primary_key, DownloadJobStatus::Failed, error)
+                .await
+                .change_context(DownloadQueueError::Repository)?;
+        }
+        self.dispatch().await
+    }
+
+    /// Send queued jobs to the [`CommandRunner`] until [`CONCURRENCY`] are running.
+    ///
+    /// Must be called while holding the lock.
+    async fn dispatch(&self) -> Result<(), Report<DownloadQueueError>> {
+        let running = self
+            .metadata
+            .count_running_download_jobs()
+            .await
+            .change_context(DownloadQueueError::Repository)?;
+        let jobs = self
+            .metadata
+            .get_queued_download_jobs(CONCURRENCY.saturating_sub(running))
+            .await
+            .change_context(DownloadQueueError::Repository)?;
+        for job in jobs {
+            let request = job.get_request();
+            let (status, error) = match self.queue.queue(request).await {
+                Ok(()) => (DownloadJobStatus::Running, None),
+                Err(report) => {
+                    warn!("{}", report.render());
+                    (DownloadJobStatus::Failed, Some(report.to_string()))
+                }
+            };
+            trace!(%request, %status, "Dispatched download job");
+            self.metadata
+                .set_download_job_status(job.primary_key, status, error)
+                .await
+                .change_context(DownloadQueueError::Repository)?;
+        }
+        Ok(())
+    }
+
+    async fn get_job(
+        &self,
+        job_key: DownloadJobKey,
+    ) -> Result<DownloadJobInfo, Report<DownloadQueueError>> {
+        self.metadata
+            .get_download_job(job_key)
+            .await
+            .change_context(DownloadQueueError::Repository)?
+            .ok_or_else(|| Report::new(DownloadQueueError::NoJob).attach("Job", job_key))
+    }
+
+    async fn get_next_position(&self) -> Result<u32, Report<DownloadQueueError>> {
+        self.metadata
+            .get_next_download_position()
+            .await
+            .change_context(DownloadQueueError::Repository)
+    }
+
+    async fn requeue(&self, job_key: DownloadJobKey) -> Result<(), Report<DownloadQueueError>> {
+        let position = self.get_next_position().await?;
+        self.metadata
+            .requeue_download_job(job_key, position, Utc::now().fixed_offset())
+            .await
+            .change_context(DownloadQueueError::Repository)
+    }
+
+    /// Take the error of a failed download from the [`CommandRunner`].
+    async fn take_error(&self, request: &DownloadRequest) -> Option<String> {
+        match self.queue.take_failure(request).await {
+            Ok(error) => error.map(|report| report.to_string()),
+            Err(report) => {
+                warn!("{}", report.render());
+                None
+            }
+        }
+    }
+}
+
+impl FromServicesAsync for DownloadQueue {
+    type Error = ResolveError;
+
+    async fn from_services_async(services: &ServiceProvider) -> Result<Self, Report<ResolveError>> {
+        Ok(Self {
+            metadata: services.get_async::<MetadataRepository>().await?,
+            queue: services.get::<CommandQueue>()?,
+            download: services.get_async::<DownloadHandler>().await?,
+            lock: Mutex::default(),
+            restored: AtomicBool::new(false),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    async fn setup() -> (Arc<DownloadQueue>, [DownloadRequest; 3]) {
+        let services = MockServices::default().create().await;
+        let metadata = services
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let podcast = metadata
+            .get_feed_by_slug(MockFeeds::podcast_slug(), None)
+            .await
+            .assert_ok_debug()
+            .expect("podcast should exist");
+        let requests: Vec<_> = podcast
+            .episodes
+            .iter()
+            .take(3)
+            .map(|episode| DownloadRequest::new(MockFeeds::PODCAST_KEY, episode.primary_key, false))
+            .collect();
+        let requests = requests.try_into().expect("should have three episodes");
+        let queue = services
+            .get_async::<DownloadQueue>()
+            .await
+            .expect("should be able to get download queue");
+        (queue, requests)
+    }
+
+    async fn add_all(
+        queue: &DownloadQueue,
+        requests: &[DownloadRequest; 3],
+    ) -> [DownloadJobKey; 3] {
+        let mut job_keys = [0; 3];
+        for (job_key, request) in job_keys.iter_mut().zip(requests) {
+            *job_key = queue.add(*request).await.assert_ok_debug();
+        }
+        job_keys
+    }
+
+    fn get_statuses(jobs: &[DownloadJob]) -> Vec<DownloadJobStatus> {
+        jobs.iter().map(|job| job.status).collect()
+    }
+
+    #[tokio::test]
+    pub async fn add__dispatches_up_to_concurrency() {
+        // Arrange
+        let (queue, requests) = setup().await;
+        let [first, _, _] = requests;
+
+        // Act
+        let [first_key, _, _] = add_all(&queue, &requests).await;
+        let repeat = queue.add(first).await;
+
+        // Assert
+        assert_eq!(repeat.assert_ok_debug(), first_key);
+        let jobs = queue.get_jobs().await.assert_ok_debug();
+        assert_eq!(
+            get_statuses(&jobs),
+            vec![
+                DownloadJobStatus::Running,
+                DownloadJobStatus::Running,
+                DownloadJobStatus::Queued,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    pub async fn move_to() {
+        // Arrange
+        let (queue, requests) = setup().await;
+        let [first_key, second_key, third_key] = add_all(&queue, &requests).await;
+
+        // Act
+        let result = queue.move_to(third_key, 0).await;
+
+        // Assert
+        result.assert_ok_debug();
+        let jobs = queue.get_jobs().await.assert_ok_debug();
+        let keys: Vec<_> = jobs.iter().map(|job| job.primary_key).collect();
+        assert_eq!(keys, vec![third_key, first_key, second_key]);
+    }
+
+    #[tokio::test]
+    pub async fn move_to__no_job() {
+        // Arrange
+        let (queue, _) = setup().await;
+
+        // Act
+        let result = queue.move_to(999, 0).await;
+
+        // Assert
+        let report = result.expect_err("should fail");
+        assert_eq!(report.current_context(), &DownloadQueueError::NoJob);
+    }
+
+    #[tokio::test]
+    pub async fn cancel() {
+        // Arrange
+        let (queue, requests) = setup().await;
+        let [first_key, _, third_key] = add_all(&queue, &requests).await;
+
+        // Act
+        let running = queue.cancel(first_key).await;
+        let queued = queue.cancel(third_key).await;
+
+        // Assert
+        let report = running.expect_err("should not cancel a running job");
+        assert_eq!(report.current_context(), &DownloadQueueError::Running);
+        queued.assert_ok_debug();
+        let jobs = queue.get_jobs().await.assert_ok_debug();
+        assert_eq!(jobs.len(), 2);
+    }
+
+    #[tokio::test]
+    pub async fn handle_event__failed_then_retry() {
+        // Arrange
+        let (queue, requests) = setup().await;
+        let [first, _, _] = requests;
+        let [first_key, second_key, third_key] = add_all(&queue, &requests).await;
+        let event = CommandEvent::new(EventKind::Failed, first.into(), None);
+
+        // Act
+        let failed = queue.handle_event(&event).await;
+        let retried = queue.retry(first_key).await;
+
+        // Assert
+        failed.assert_ok_debug();
+        retried.assert_ok_debug();
+        let jobs = queue.get_jobs().await.assert_ok_debug();
+        let keys: Vec<_> = jobs.iter().map(|job| job.primary_key).collect();
+        assert_eq!(keys, vec![second_key, third_key, first_key]);
+        assert_eq!(
+            get_statuses(&jobs),
+            vec![
+                DownloadJobStatus::Running,
+                DownloadJobStatus::Running,
+                DownloadJobStatus::Queued,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    pub async fn handle_event__succeeded() {
+        // Arrange
+        let (queue, requests) = setup().await;
+        let [first, second, third] = requests;
+        add_all(&queue, &requests).await;
+        let event = CommandEvent::new(EventKind::Succeeded, second.into(), None);
+
+        // Act
+        let result = queue.handle_event(&event).await;
+
+        // Assert
+        result.assert_ok_debug();
+        let jobs = queue.get_jobs().await.assert_ok_debug();
+        let episodes: Vec<_> = jobs.iter().map(|job| job.episode_key).collect();
+        assert_eq!(episodes, vec![first.episode, third.episode]);
+        assert_eq!(
+            get_statuses(&jobs),
+            vec![DownloadJobStatus::Running, DownloadJobStatus::Running]
+        );
+    }
+
+    #[tokio::test]
+    pub async fn restore() {
+        // Arrange
+        let (queue, requests) = setup().await;
+        add_all(&queue, &requests).await;
+        queue
+            .metadata
+            .reset_running_download_jobs()
+            .await
+            .assert_ok_debug();
+
+        // Act
+        let result = queue.restore().await;
+
+        // Assert
+        result.assert_ok_debug();
+        assert!(queue.is_restored());
+        let jobs = queue.get_jobs().await.assert_ok_debug();
+        assert_eq!(
+            get_statuses(&jobs),
+            vec![
+                DownloadJobStatus::Running,
+                DownloadJobStatus::Running,
+                DownloadJobStatus::Queued,
+            ]
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+
+/// Errors from [`DownloadQueue`].
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum DownloadQueueError {
+    #[error("Unable to query database")]
+    Repository,
+    #[error("Download job does not exist")]
+    NoJob,
+    #[error("Download job is already running")]
+    Running,
+}
@@ -0,0 +1,124 @@
+use crate::prelude::*;
+use sea_orm::*;
+
+impl MetadataRepository {
+    /// Get every job in the download queue in order.
+    pub async fn get_download_jobs(&self) -> Result<Vec<DownloadJob>, DbErr> {
+        get_download_jobs_query()
+            .into_model::<DownloadJob>()
+            .all(&self.db)
+            .await
+    }
+
+    /// Get a job in the download queue.
+    pub(crate) async fn get_download_job(
+        &self,
+        job_key: DownloadJobKey,
+    ) -> Result<Option<DownloadJobInfo>, DbErr> {
+        download_job::Entity::find_by_id(job_key)
+            .one(&self.db)
+            .await
+    }
+
+    /// Get the job in the download queue for an episode.
+    pub(crate) async fn get_download_job_by_episode(
+        &self,
+        episode_key: EpisodeKey,
+    ) -> Result<Option<DownloadJobInfo>, DbErr> {
+        download_job::Entity::find()
+            .filter(download_job::Column::EpisodeKey.eq(episode_key))
+            .one(&self.db)
+            .await
+    }
+
+    /// Get the first jobs in the queue that are waiting for a worker.
+    pub(crate) async fn get_queued_download_jobs(
+        &self,
+        limit: u64,
+    ) -> Result<Vec<DownloadJobInfo>, DbErr> {
+        get_queued_download_jobs_query(limit).all(&self.db).await
+    }
+
+    /// Count the jobs that have been sent to the command runner.
+    pub(crate) async fn count_running_download_jobs(&self) -> Result<u64, DbErr> {
+        download_job::Entity::find()
+            .filter(download_job::Column::Status.eq(DownloadJobStatus::Running))
+            .count(&self.db)
+            .await
+    }
+
+    /// Get the keys of every job in the download queue in order.
+    pub(crate) async fn get_download_job_keys(&self) -> Result<Vec<DownloadJobKey>, DbErr> {
+        download_job::Entity::find()
+            .select_only()
+            .column(download_job::Column::PrimaryKey)
+            .order_by_asc(download_job::Column::Position)
+            .into_tuple()
+            .all(&self.db)
+            .await
+    }
+
+    /// Get the position after the last job in the download queue.
+    pub(crate) async fn get_next_download_position(&self) -> Result<u32, DbErr> {
+        let last: Option<Option<u32>> = download_job::Entity::find()
+            .select_only()
+            .column_as(download_job::Column::Position.max(), "position")
+            .into_tuple()
+            .one(&self.db)
+            .await?;
+        Ok(last.flatten().map_or(0, |position| position + 1))
+    }
+}
+
+fn get_download_jobs_query() -> Select<download_job::Entity> {
+    download_job::Entity::find()
+        .select_only()
+        .columns([
+            download_job::Column::PrimaryKey,
+            download_job::Column::PodcastKey,
+            download_job::Column::EpisodeKey,
+            download_job::Column::Replace,
+            download_job::Column::Position,
+            download_job::Column::Status,
+            download_job::Column::Error,
+            download_job::Column::QueuedAt,
+        ])
+        .column_as(podcast::Column::Slug, "podcast_slug")
+        .column_as(episode::Column::Title, "episode_title")
+        .join(JoinType::InnerJoin, download_job::Relation::Episode.def())
+        .join(JoinType::InnerJoin, episode::Relation::Podcast.def())
+        .order_by_asc(download_job::Column::Position)
+}
+
+fn get_queued_download_jobs_query(limit: u64) -> Select<download_job::Entity> {
+    download_job::Entity::find()
+        .filter(download_job::Column::Status.eq(DownloadJobStatus::Queued))
+        .order_by_asc(download_job::Column::Position)
+        .limit(limit)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn _get_download_jobs_query() {
+        // Arrange
+        // Act
+        let statement = get_download_jobs_query().build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[test]
+    fn _get_queued_download_jobs_query() {
+        // Arrange
+        // Act
+        let statement = get_queued_download_jobs_query(2).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+}
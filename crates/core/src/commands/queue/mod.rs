@@ -0,0 +1,13 @@
+mod download_job;
+#[cfg(feature = "server")]
+mod download_queue;
+mod download_queue_error;
+#[cfg(feature = "server")]
+mod get_download_jobs;
+#[cfg(feature = "server")]
+mod update_download_jobs;
+
+pub use download_job::*;
+#[cfg(feature = "server")]
+pub use download_queue::*;
+pub use download_queue_error::*;
@@ -0,0 +1,160 @@
+use crate::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+
+impl MetadataRepository {
+    /// Add a request to the end of the download queue.
+    pub(crate) async fn insert_download_job(
+        &self,
+        request: DownloadRequest,
+        position: u32,
+        queued_at: DateTime<FixedOffset>,
+    ) -> Result<DownloadJobKey, DbErr> {
+        let result = insert_download_job_query(request, position, queued_at)
+            .exec(&self.db)
+            .await?;
+        Ok(result.last_insert_id)
+    }
+
+    /// Set the status and error of a download job.
+    pub(crate) async fn set_download_job_status(
+        &self,
+        job_key: DownloadJobKey,
+        status: DownloadJobStatus,
+        error: Option<String>,
+    ) -> Result<(), DbErr> {
+        set_download_job_status_query(job_key, status, error)
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    /// Move a download job to the end of the queue to be attempted again.
+    pub(crate) async fn requeue_download_job(
+        &self,
+        job_key: DownloadJobKey,
+        position: u32,
+        queued_at: DateTime<FixedOffset>,
+    ) -> Result<(), DbErr> {
+        set_download_job_status_query(job_key, DownloadJobStatus::Queued, None)
+            .col_expr(download_job::Column::Position, Expr::value(position))
+            .col_expr(download_job::Column::QueuedAt, Expr::value(queued_at))
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    /// Remove a job from the download queue.
+    pub(crate) async fn delete_download_job(&self, job_key: DownloadJobKey) -> Result<(), DbErr> {
+        download_job::Entity::delete_by_id(job_key)
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    /// Return jobs interrupted by a restart to the queue.
+    ///
+    /// Returns the number of jobs that were running.
+    pub(crate) async fn reset_running_download_jobs(&self) -> Result<u64, DbErr> {
+        let result = reset_running_download_jobs_query().exec(&self.db).await?;
+        Ok(result.rows_affected)
+    }
+
+    /// Set the position of each job to its index in `job_keys`.
+    pub(crate) async fn set_download_job_positions(
+        &self,
+        job_keys: &[DownloadJobKey],
+    ) -> Result<(), DbErr> {
+        let tx = self.db.begin().await?;
+        for (position, job_key) in (0..).zip(job_keys) {
+            download_job::Entity::update_many()
+                .col_expr(download_job::Column::Position, Expr::value(position))
+                .filter(download_job::Column::PrimaryKey.eq(*job_key))
+                .exec(&tx)
+                .await?;
+        }
+        tx.commit().await
+    }
+}
+
+fn insert_download_job_query(
+    request: DownloadRequest,
+    position: u32,
+    queued_at: DateTime<FixedOffset>,
+) -> Insert<download_job::ActiveModel> {
+    let model = download_job::ActiveModel {
+        primary_key: NotSet,
+        podcast_key: Set(request.podcast),
+        episode_key: Set(request.episode),
+        replace: Set(request.replace),
+        position: Set(position),
+        status: Set(DownloadJobStatus::Queued),
+        error: Set(None),
+        queued_at: Set(queued_at),
+    };
+    download_job::Entity::insert(model)
+}
+
+fn set_download_job_status_query(
+    job_key: DownloadJobKey,
+    status: DownloadJobStatus,
+    error: Option<String>,
+) -> UpdateMany<download_job::Entity> {
+    download_job::Entity::update_many()
+        .col_expr(download_job::Column::Status, Expr::value(status))
+        .col_expr(download_job::Column::Error, Expr::value(error))
+        .filter(download_job::Column::PrimaryKey.eq(job_key))
+}
+
+fn reset_running_download_jobs_query() -> UpdateMany<download_job::Entity> {
+    download_job::Entity::update_many()
+        .col_expr(
+            download_job::Column::Status,
+            Expr::value(DownloadJobStatus::Queued),
+        )
+        .filter(download_job::Column::Status.eq(DownloadJobStatus::Running))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn _insert_download_job_query() {
+        // Arrange
+        let request = DownloadRequest::new(MockFeeds::PODCAST_KEY, MockFeeds::EPISODE_KEY, false);
+
+        // Act
+        let statement =
+            insert_download_job_query(request, 3, DateTime::default()).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[test]
+    fn _set_download_job_status_query() {
+        // Arrange
+        // Act
+        let statement = set_download_job_status_query(
+            1,
+            DownloadJobStatus::Failed,
+            Some("Unable to download".to_owned()),
+        )
+        .build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[test]
+    fn _reset_running_download_jobs_query() {
+        // Arrange
+        // Act
+        let statement = reset_running_download_jobs_query().build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+}
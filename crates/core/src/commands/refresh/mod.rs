@@ -0,0 +1,9 @@
+mod refresh_error;
+mod refresh_schedule;
+#[cfg(feature = "server")]
+mod refresh_scheduler;
+
+pub use refresh_error::*;
+pub use refresh_schedule::*;
+#[cfg(feature = "server")]
+pub use refresh_scheduler::*;
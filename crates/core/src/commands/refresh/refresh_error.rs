@@ -0,0 +1,8 @@
+use crate::prelude::*;
+
+/// Errors from [`RefreshScheduler`].
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum RefreshError {
+    #[error("Unable to query database")]
+    Repository,
+}
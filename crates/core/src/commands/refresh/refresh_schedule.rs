@@ -0,0 +1,16 @@
+use crate::prelude::*;
+
+/// When a podcast was last refreshed and when it will next be refreshed.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RefreshSchedule {
+    /// Slug of the podcast
+    pub slug: Slug,
+    /// Minutes between scheduled refreshes
+    ///
+    /// `None` if scheduled refresh is disabled for the podcast.
+    pub interval: Option<u32>,
+    /// Date and time of the most recent fetch
+    pub last_refresh: Option<DateTime<FixedOffset>>,
+    /// Date and time the next scheduled refresh is due
+    pub next_refresh: Option<DateTime<FixedOffset>>,
+}
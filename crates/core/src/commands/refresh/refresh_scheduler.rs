@@ -0,0 +1,249 @@
+use crate::prelude::*;
+use chrono::TimeDelta;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// Decide when each podcast is due a scheduled refresh.
+///
+/// - Interval is the podcast `refresh_interval`, falling back to
+///   [`AppOptions::refresh_interval`]; `0` or unset disables the schedule
+/// - A refresh is due an interval after the last fetch or scheduled attempt
+/// - Nothing is due before the scheduler started, so a restart doesn't
+///   immediately refresh every stale podcast at once
+/// - A deterministic jitter up to [`AppOptions::refresh_jitter`] minutes is
+///   added so feeds with the same interval are spread out
+pub struct RefreshScheduler {
+    metadata: Arc<MetadataRepository>,
+    interval: Option<u32>,
+    jitter: Option<u32>,
+    started_at: DateTime<FixedOffset>,
+    attempts: StdMutex<HashMap<Slug, DateTime<FixedOffset>>>,
+}
+
+impl RefreshScheduler {
+    /// Create a scheduler with a default interval and jitter in minutes.
+    #[must_use]
+    pub fn new(
+        metadata: Arc<MetadataRepository>,
+        interval: Option<u32>,
+        jitter: Option<u32>,
+        started_at: DateTime<FixedOffset>,
+    ) -> Self {
+        Self {
+            metadata,
+            interval,
+            jitter,
+            started_at,
+            attempts: StdMutex::default(),
+        }
+    }
+
+    /// Get the refresh schedule of every podcast.
+    pub async fn get_schedules(&self) -> Result<Vec<RefreshSchedule>, Report<RefreshError>> {
+        let refreshes = self
+            .metadata
+            .get_podcast_refreshes()
+            .await
+            .change_context(RefreshError::Repository)?;
+        let attempts = self.attempts.lock().expect("lock should not be poisoned");
+        let schedules = refreshes
+            .into_iter()
+            .map(|refresh| {
+                let attempt = attempts.get(&refresh.slug).copied();
+                self.get_schedule(refresh, attempt)
+            })
+            .collect();
+        Ok(schedules)
+    }
+
+    /// Get the refresh schedule of a podcast.
+    ///
+    /// Returns `None` if the podcast does not exist.
+    pub async fn get_podcast_schedule(
+        &self,
+        slug: &Slug,
+    ) -> Result<Option<RefreshSchedule>, Report<RefreshError>> {
+        let schedules = self.get_schedules().await?;
+        Ok(schedules
+            .into_iter()
+            .find(|schedule| &schedule.slug == slug))
+    }
+
+    /// Get the podcasts that are due a refresh and record the attempt.
+    pub async fn take_due(
+        &self,
+        now: DateTime<FixedOffset>,
+    ) -> Result<Vec<Slug>, Report<RefreshError>> {
+        let due: Vec<Slug> = self
+            .get_schedules()
+            .await?
+            .into_iter()
+            .filter(|schedule| schedule.next_refresh.is_some_and(|next| next <= now))
+            .map(|schedule| schedule.slug)
+            .collect();
+        let mut attempts = self.attempts.lock().expect("lock should not be poisoned");
+        for slug in &due {
+            attempts.insert(slug.clone(), now);
+        }
+        Ok(due)
+    }
+
+    fn get_schedule(
+        &self,
+        refresh: PodcastRefresh,
+        attempt: Option<DateTime<FixedOffset>>,
+    ) -> RefreshSchedule {
+        let interval = refresh
+            .refresh_interval
+            .or(self.interval)
+            .filter(|&interval| interval > 0);
+        let next_refresh = interval.map(|interval| {
+            let base = refresh.last_refresh.max(attempt);
+            let jitter = self.jitter.unwrap_or_else(|| get_default_jitter(interval));
+            let due = base.map_or(self.started_at, |base| {
+                (base + minutes(interval)).max(self.started_at)
+            });
+            due + get_jitter_offset(&refresh.slug, base, jitter)
+        });
+        RefreshSchedule {
+            slug: refresh.slug,
+            interval,
+            last_refresh: refresh.last_refresh,
+            next_refresh,
+        }
+    }
+}
+
+impl FromServicesAsync for RefreshScheduler {
+    type Error = ResolveError;
+
+    async fn from_services_async(services: &ServiceProvider) -> Result<Self, Report<ResolveError>> {
+        let options = services.get::<AppOptions>()?;
+        Ok(Self::new(
+            services.get_async::<MetadataRepository>().await?,
+            options.refresh_interval,
+            options.refresh_jitter,
+            Utc::now().fixed_offset(),
+        ))
+    }
+}
+
+fn minutes(value: u32) -> TimeDelta {
+    TimeDelta::minutes(i64::from(value))
+}
+
+/// Default jitter of 10% of the interval.
+#[allow(clippy::integer_division)]
+fn get_default_jitter(interval: u32) -> u32 {
+    interval / 10
+}
+
+/// Offset of up to `jitter` minutes derived from the podcast and its last refresh.
+///
+/// Deterministic so the displayed next refresh is stable between requests.
+fn get_jitter_offset(slug: &Slug, base: Option<DateTime<FixedOffset>>, jitter: u32) -> TimeDelta {
+    if jitter == 0 {
+        return TimeDelta::zero();
+    }
+    let mut hasher = DefaultHasher::new();
+    slug.hash(&mut hasher);
+    base.map(|base| base.timestamp()).hash(&mut hasher);
+    let seconds = hasher.finish() % (u64::from(jitter) * 60 + 1);
+    TimeDelta::seconds(i64::try_from(seconds).expect("jitter should fit in i64"))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    async fn setup(interval: Option<u32>, jitter: Option<u32>) -> RefreshScheduler {
+        let metadata = MockServices::default()
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        RefreshScheduler::new(metadata, interval, jitter, started_at())
+    }
+
+    fn started_at() -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339("2026-01-01T12:00:00Z").expect("should be valid date")
+    }
+
+    #[tokio::test]
+    pub async fn refresh_scheduler__disabled() {
+        // Arrange
+        let scheduler = setup(None, None).await;
+
+        // Act
+        let result = scheduler.take_due(started_at() + minutes(60 * 24)).await;
+
+        // Assert
+        let due = result.assert_ok_debug();
+        assert_eq!(due, Vec::new());
+        let all = scheduler.get_schedules().await.assert_ok_debug();
+        assert!(all.iter().all(|schedule| schedule.next_refresh.is_none()));
+    }
+
+    #[tokio::test]
+    pub async fn refresh_scheduler__never_refreshed() {
+        // Arrange
+        let scheduler = setup(Some(60), Some(0)).await;
+
+        // Act
+        let result = scheduler.take_due(started_at()).await;
+
+        // Assert
+        let due = result.assert_ok_debug();
+        assert_eq!(
+            due.len(),
+            usize::try_from(MockFeeds::PODCAST_COUNT).expect("should fit")
+        );
+        let schedule = scheduler
+            .get_podcast_schedule(&MockFeeds::podcast_slug())
+            .await
+            .assert_ok_debug()
+            .expect("should have a schedule");
+        assert_eq!(schedule.next_refresh, Some(started_at() + minutes(60)));
+    }
+
+    #[tokio::test]
+    pub async fn refresh_scheduler__last_refresh() {
+        // Arrange
+        let scheduler = setup(Some(60), Some(0)).await;
+        let entry = FetchHistoryInfo {
+            fetched_at: started_at() + minutes(30),
+            ..FetchHistoryInfo::example()
+        };
+        scheduler
+            .metadata
+            .insert_fetch_history(entry)
+            .await
+            .assert_ok_debug();
+
+        // Act
+        let early = scheduler.take_due(started_at() + minutes(60)).await;
+        let late = scheduler.take_due(started_at() + minutes(90)).await;
+
+        // Assert
+        let early = early.assert_ok_debug();
+        assert!(!early.contains(&MockFeeds::podcast_slug()));
+        let late = late.assert_ok_debug();
+        assert_eq!(late, vec![MockFeeds::podcast_slug()]);
+    }
+
+    #[test]
+    fn get_jitter_offset__within_range() {
+        // Arrange
+        let slug = MockFeeds::podcast_slug();
+
+        // Act
+        let offset = get_jitter_offset(&slug, Some(started_at()), 5);
+        let repeat = get_jitter_offset(&slug, Some(started_at()), 5);
+
+        // Assert
+        assert_eq!(offset, repeat);
+        assert!(offset >= TimeDelta::zero());
+        assert!(offset <= minutes(5));
+    }
+}
@@ -0,0 +1,117 @@
+use crate::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+
+impl MetadataRepository {
+    /// Get a podcast with the values needed to render its episode paths.
+    ///
+    /// Returns `Ok(None)` if the podcast does not exist.
+    pub(crate) async fn get_relayout_podcast(
+        &self,
+        slug: &Slug,
+    ) -> Result<Option<DownloadPodcastPartial>, DbErr> {
+        get_relayout_podcast_query(slug)
+            .into_model::<DownloadPodcastPartial>()
+            .one(&self.db)
+            .await
+    }
+
+    /// Get the downloaded episodes of a podcast, oldest first.
+    pub(crate) async fn get_relayout_episodes(
+        &self,
+        podcast_key: PodcastKey,
+    ) -> Result<Vec<DownloadEpisodePartial>, DbErr> {
+        get_relayout_episodes_query(podcast_key)
+            .into_model::<DownloadEpisodePartial>()
+            .all(&self.db)
+            .await
+    }
+
+    /// Set the downloaded audio and image paths of moved episodes.
+    ///
+    /// Every episode is updated in a single transaction.
+    pub(crate) async fn set_download_paths(&self, moves: &[RelayoutMove]) -> Result<(), DbErr> {
+        let tx = self.db.begin().await?;
+        for item in moves {
+            set_download_paths_query(item).exec(&tx).await?;
+        }
+        tx.commit().await
+    }
+}
+
+fn get_relayout_podcast_query(slug: &Slug) -> Select<podcast::Entity> {
+    podcast::Entity::find()
+        .select_only()
+        .columns(DOWNLOAD_PODCAST_COLUMNS)
+        .filter(podcast::Column::Slug.eq(slug.to_string()))
+}
+
+fn get_relayout_episodes_query(podcast_key: PodcastKey) -> Select<episode::Entity> {
+    episode::Entity::find()
+        .select_only()
+        .columns(DOWNLOAD_EPISODE_COLUMNS)
+        .filter(episode::Column::PodcastKey.eq(podcast_key))
+        .filter(episode::Column::FileSubPath.is_not_null())
+        .order_by_asc(episode::Column::PublishedAt)
+}
+
+fn set_download_paths_query(item: &RelayoutMove) -> UpdateMany<episode::Entity> {
+    let image = item
+        .image
+        .as_ref()
+        .map(|(_, to)| PathWrapper::from(to.clone()));
+    episode::Entity::update_many()
+        .col_expr(
+            episode::Column::FileSubPath,
+            Expr::value(PathWrapper::from(item.file_to.clone())),
+        )
+        .col_expr(episode::Column::ImageSubPath, Expr::value(image))
+        .filter(episode::Column::PrimaryKey.eq(item.episode_key))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn _get_relayout_podcast_query() {
+        // Arrange
+        // Act
+        let statement = get_relayout_podcast_query(&MockFeeds::podcast_slug()).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[test]
+    fn _get_relayout_episodes_query() {
+        // Arrange
+        // Act
+        let statement = get_relayout_episodes_query(MockFeeds::PODCAST_KEY).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[test]
+    fn _set_download_paths_query() {
+        // Arrange
+        let item = RelayoutMove {
+            episode_key: MockFeeds::EPISODE_KEY,
+            title: "Hello World".to_owned(),
+            file_from: PathBuf::from("old/Hello World.mp3"),
+            file_to: PathBuf::from("new/Hello World.mp3"),
+            image: Some((
+                PathBuf::from("old/Hello World.jpg"),
+                PathBuf::from("new/Hello World.jpg"),
+            )),
+        };
+
+        // Act
+        let statement = set_download_paths_query(&item).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+}
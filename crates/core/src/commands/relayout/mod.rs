@@ -0,0 +1,19 @@
+#[cfg(feature = "server")]
+mod get_relayout_episodes;
+#[cfg(feature = "server")]
+mod relayout_cli;
+mod relayout_error;
+#[cfg(feature = "server")]
+mod relayout_handler;
+mod relayout_options;
+mod relayout_request;
+mod relayout_response;
+
+#[cfg(feature = "server")]
+pub use relayout_cli::*;
+pub use relayout_error::*;
+#[cfg(feature = "server")]
+pub use relayout_handler::*;
+pub use relayout_options::*;
+pub use relayout_request::*;
+pub use relayout_response::*;
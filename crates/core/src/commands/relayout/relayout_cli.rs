@@ -0,0 +1,49 @@
+use crate::prelude::*;
+
+/// CLI command for moving downloaded episodes to the paths of the current template.
+#[derive(FromServicesAsync)]
+pub struct RelayoutCliCommand {
+    selector: Arc<PodcastSelector>,
+    cli_runner: Arc<CliRunner>,
+}
+
+impl RelayoutCliCommand {
+    /// Relayout podcasts matching the options.
+    pub async fn execute(
+        &self,
+        options: RelayoutOptions,
+    ) -> Result<(), Report<PodcastSelectorError>> {
+        let slugs = self.selector.execute(&options.selection).await?;
+        let requests = slugs.into_iter().map(|slug| RelayoutRequest {
+            slug,
+            dry_run: options.dry_run,
+        });
+        let status = self.cli_runner.run(requests).await;
+        for (_request, error) in &status.failed {
+            warn!("{}", error.render());
+        }
+        let action = if options.dry_run {
+            "Would move"
+        } else {
+            "Moved"
+        };
+        let mut count = 0;
+        for (request, response) in &status.succeeded {
+            for episode in &response.moved {
+                info!(podcast = %request.slug, "{action} {episode}");
+            }
+            for episode in &response.skipped {
+                warn!(podcast = %request.slug, "Skipped {episode}");
+            }
+            count += response.moved.len();
+        }
+        info!(
+            "{action} {count} episodes of {} podcasts",
+            status.succeeded.len()
+        );
+        if !status.failed.is_empty() {
+            warn!("Failed to relayout {} podcasts", status.failed.len());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+use crate::prelude::*;
+
+/// Errors from [`RelayoutHandler`].
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum RelayoutError {
+    #[error("Unable to query database")]
+    Repository,
+    #[error("Podcast does not exist")]
+    NoPodcast,
+    #[error("Unable to render the episode path template")]
+    PathTemplate,
+    #[error("Unable to move the episode files")]
+    Move,
+    #[error("Unable to update the database")]
+    Save,
+}
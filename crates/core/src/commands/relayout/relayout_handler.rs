@@ -0,0 +1,446 @@
+use crate::prelude::*;
+use std::io::ErrorKind;
+use std::iter::once;
+use tokio::fs::remove_dir;
+
+/// Move downloaded episodes to the paths of the current template.
+///
+/// - Expected paths are rendered with the same rules as [`DownloadContext`]
+/// - Files are hard-linked on the same mount, otherwise copied
+/// - Paths are updated in a single transaction once every file is in place
+/// - Previous files are only deleted after the database is updated so a
+///   failure leaves the library as it was
+#[derive(Clone)]
+pub struct RelayoutHandler {
+    paths: Arc<PathProvider>,
+    metadata: Arc<MetadataRepository>,
+    options: Arc<AppOptions>,
+    /// `None` if mount points are not supported on this platform so files are always copied
+    mounts: Option<Arc<MountProvider>>,
+}
+
+#[async_trait]
+impl Execute<RelayoutRequest, RelayoutResponse, Report<RelayoutError>> for RelayoutHandler {
+    /// Execute the relayout handler.
+    async fn execute(
+        &self,
+        request: &RelayoutRequest,
+    ) -> Result<RelayoutResponse, Report<RelayoutError>> {
+        let podcast = self
+            .metadata
+            .get_relayout_podcast(&request.slug)
+            .await
+            .change_context(RelayoutError::Repository)?
+            .ok_or(RelayoutError::NoPodcast)?;
+        let episodes = self
+            .metadata
+            .get_relayout_episodes(podcast.primary_key)
+            .await
+            .change_context(RelayoutError::Repository)?;
+        let mut response = self.plan(&podcast, episodes)?;
+        if request.dry_run || response.moved.is_empty() {
+            trace!(podcast = %request.slug, moved = response.moved.len(), dry_run = request.dry_run, "Planned relayout");
+            return Ok(response);
+        }
+        let created = self
+            .place_files(&response.moved)
+            .await
+            .attach("Podcast", request.slug.clone())?;
+        if let Err(error) = self.metadata.set_download_paths(&response.moved).await {
+            remove_files(&created).await;
+            return Err(Report::new(error)
+                .change_context(RelayoutError::Save)
+                .attach("Podcast", request.slug.clone()));
+        }
+        self.remove_previous(&response.moved).await;
+        response.dry_run = false;
+        trace!(podcast = %request.slug, moved = response.moved.len(), "Relayout complete");
+        Ok(response)
+    }
+}
+
+impl RelayoutHandler {
+    /// Get the episodes whose files are not at the expected path.
+    fn plan(
+        &self,
+        podcast: &DownloadPodcastPartial,
+        episodes: Vec<DownloadEpisodePartial>,
+    ) -> Result<RelayoutResponse, Report<RelayoutError>> {
+        let podcasts_dir = self.paths.get_podcasts_dir();
+        let template = podcast.get_path_template(&self.options);
+        let mut response = RelayoutResponse {
+            moved: Vec::new(),
+            skipped: Vec::new(),
+            dry_run: true,
+        };
+        let mut targets = HashSet::new();
+        for episode in episodes {
+            let Some(file_from) = episode.file_sub_path.as_deref().cloned() else {
+                continue;
+            };
+            let image_from = episode.image_sub_path.as_deref().cloned();
+            let episode_key = episode.primary_key;
+            let title = episode.title.clone();
+            let context =
+                DownloadContext::new(podcast.clone(), episode, podcasts_dir.clone(), &template)
+                    .change_context(RelayoutError::PathTemplate)?;
+            let file_to = context
+                .get_file_path()
+                .strip_prefix(&podcasts_dir)
+                .expect("path should have prefix")
+                .to_path_buf();
+            let image = image_from.map(|from| {
+                let extension = from.extension().unwrap_or_default().to_os_string();
+                let to = file_to.with_extension(extension);
+                (from, to)
+            });
+            let image_moved = image.as_ref().is_some_and(|(from, to)| from != to);
+            if file_from == file_to && !image_moved {
+                continue;
+            }
+            let reason = if !podcasts_dir.join(&file_from).exists() {
+                Some(RelayoutSkipReason::Missing)
+            } else if is_conflict(&podcasts_dir, &mut targets, &file_from, &file_to)
+                || image
+                    .as_ref()
+                    .is_some_and(|(from, to)| is_conflict(&podcasts_dir, &mut targets, from, to))
+            {
+                Some(RelayoutSkipReason::Conflict)
+            } else {
+                None
+            };
+            if let Some(reason) = reason {
+                response.skipped.push(RelayoutSkip {
+                    episode_key,
+                    title,
+                    reason,
+                });
+                continue;
+            }
+            response.moved.push(RelayoutMove {
+                episode_key,
+                title,
+                file_from,
+                file_to,
+                image: image.filter(|(from, _)| podcasts_dir.join(from).exists()),
+            });
+        }
+        Ok(response)
+    }
+
+    /// Link or copy every file to its expected path.
+    ///
+    /// Returns the created files, or removes them if any file fails.
+    async fn place_files(
+        &self,
+        moves: &[RelayoutMove],
+    ) -> Result<Vec<PathBuf>, Report<RelayoutError>> {
+        let podcasts_dir = self.paths.get_podcasts_dir();
+        let mut created = Vec::new();
+        for (from, to) in get_file_moves(moves) {
+            let from = podcasts_dir.join(from);
+            let to = podcasts_dir.join(to);
+            if from == to {
+                continue;
+            }
+            if let Err(report) = self.place_file(&from, &to).await {
+                remove_files(&created).await;
+                return Err(report);
+            }
+            created.push(to);
+        }
+        Ok(created)
+    }
+
+    /// Hard-link a file on the same mount, otherwise copy it.
+    async fn place_file(&self, from: &Path, to: &Path) -> Result<(), Report<RelayoutError>> {
+        create_parent_dir_if_not_exist(to)
+            .await
+            .change_context(RelayoutError::Move)?;
+        let dir = to.parent().unwrap_or(to);
+        if self.is_same_mount(from, dir) {
+            match hard_link(from, to).await {
+                Ok(()) => {
+                    trace!(from = %from.display(), to = %to.display(), "Linked file");
+                    return Ok(());
+                }
+                Err(error) => {
+                    debug!(from = %from.display(), to = %to.display(), %error, "Unable to link file, copying instead");
+                }
+            }
+        }
+        copy(from, to)
+            .await
+            .change_context(RelayoutError::Move)
+            .attach_path(from)
+            .attach_path(to)?;
+        trace!(from = %from.display(), to = %to.display(), "Copied file");
+        Ok(())
+    }
+
+    /// Delete the previous files and any directories left empty.
+    ///
+    /// The database already refers to the new paths so failures only warn.
+    async fn remove_previous(&self, moves: &[RelayoutMove]) {
+        let podcasts_dir = self.paths.get_podcasts_dir();
+        for (from, to) in get_file_moves(moves) {
+            if from == to {
+                continue;
+            }
+            let path = podcasts_dir.join(from);
+            if let Err(error) = remove_file(&path).await {
+                warn!(path = %path.display(), %error, "Failed to delete previous file");
+                continue;
+            }
+            remove_empty_dirs(&path, &podcasts_dir).await;
+        }
+    }
+
+    /// Whether a file and a directory are on the same mount.
+    #[cfg(target_os = "linux")]
+    fn is_same_mount(&self, file: &Path, dir: &Path) -> bool {
+        let Some(mounts) = &self.mounts else {
+            return false;
+        };
+        match (mounts.get_mount_id(file), mounts.get_mount_id(dir)) {
+            (Ok(file), Ok(dir)) => file == dir,
+            _ => false,
+        }
+    }
+
+    /// Whether a file and a directory are on the same mount.
+    #[cfg(not(target_os = "linux"))]
+    fn is_same_mount(&self, _file: &Path, _dir: &Path) -> bool {
+        false
+    }
+}
+
+impl FromServicesAsync for RelayoutHandler {
+    type Error = ResolveError;
+
+    async fn from_services_async(services: &ServiceProvider) -> Result<Self, Report<ResolveError>> {
+        Ok(Self {
+            paths: services.get_async::<PathProvider>().await?,
+            metadata: services.get_async::<MetadataRepository>().await?,
+            options: services.get::<AppOptions>()?,
+            mounts: get_mounts(services)?,
+        })
+    }
+}
+
+/// Resolve the [`MountProvider`] if mount points are supported.
+#[cfg(target_os = "linux")]
+fn get_mounts(
+    services: &ServiceProvider,
+) -> Result<Option<Arc<MountProvider>>, Report<ResolveError>> {
+    services.get::<MountProvider>().map(Some)
+}
+
+/// Resolve the [`MountProvider`] if mount points are supported.
+#[cfg(not(target_os = "linux"))]
+#[expect(
+    clippy::unnecessary_wraps,
+    reason = "signature matches the linux implementation"
+)]
+fn get_mounts(
+    _services: &ServiceProvider,
+) -> Result<Option<Arc<MountProvider>>, Report<ResolveError>> {
+    Ok(None)
+}
+
+/// Whether the expected path is used by another file or an earlier episode.
+fn is_conflict(
+    podcasts_dir: &Path,
+    targets: &mut HashSet<PathBuf>,
+    from: &Path,
+    to: &Path,
+) -> bool {
+    if from == to {
+        return false;
+    }
+    podcasts_dir.join(to).exists() || !targets.insert(to.to_path_buf())
+}
+
+fn get_file_moves(moves: &[RelayoutMove]) -> impl Iterator<Item = (&PathBuf, &PathBuf)> {
+    moves.iter().flat_map(|item| {
+        once((&item.file_from, &item.file_to))
+            .chain(item.image.as_ref().map(|(from, to)| (from, to)))
+    })
+}
+
+/// Remove files created before a failure.
+async fn remove_files(paths: &[PathBuf]) {
+    for path in paths {
+        if let Err(error) = remove_file(path).await
+            && error.kind() != ErrorKind::NotFound
+        {
+            warn!(path = %path.display(), %error, "Failed to delete file");
+        }
+    }
+}
+
+/// Remove the empty parent directories of a file within the podcasts directory.
+async fn remove_empty_dirs(path: &Path, podcasts_dir: &Path) {
+    let mut dir = path.parent();
+    while let Some(current) = dir {
+        if current == podcasts_dir || !current.starts_with(podcasts_dir) {
+            break;
+        }
+        if remove_dir(current).await.is_err() {
+            break;
+        }
+        trace!(dir = %current.display(), "Removed empty directory");
+        dir = current.parent();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+    use tokio::fs::write;
+
+    const OLD_DIR: &str = "old-convention";
+
+    async fn setup() -> (Arc<RelayoutHandler>, PathBuf) {
+        let services = MockServices::new()
+            .with_metadata_factory(MockFeedsFactory {
+                edit_episode: Some(|episode| {
+                    let path = PathBuf::from(OLD_DIR).join(&episode.source_id);
+                    episode.file_sub_path = Some(PathWrapper::from(path.with_extension("mp3")));
+                    episode.image_sub_path = Some(PathWrapper::from(path.with_extension("jpg")));
+                }),
+                ..MockFeedsFactory::default()
+            })
+            .create()
+            .await;
+        let handler = services
+            .get_async::<RelayoutHandler>()
+            .await
+            .expect("should be able to get handler");
+        let podcasts_dir = handler.paths.get_podcasts_dir();
+        create_dir_all(podcasts_dir.join(OLD_DIR))
+            .await
+            .expect("should be able to create dir");
+        let episodes = handler
+            .metadata
+            .get_relayout_episodes(MockFeeds::PODCAST_KEY)
+            .await
+            .assert_ok_debug();
+        for episode in episodes {
+            for sub_path in [episode.file_sub_path, episode.image_sub_path]
+                .into_iter()
+                .flatten()
+            {
+                write(podcasts_dir.join(sub_path.as_ref()), b"content")
+                    .await
+                    .expect("should be able to write file");
+            }
+        }
+        (handler, podcasts_dir)
+    }
+
+    fn request(dry_run: bool) -> RelayoutRequest {
+        RelayoutRequest {
+            slug: MockFeeds::podcast_slug(),
+            dry_run,
+        }
+    }
+
+    #[tokio::test]
+    pub async fn relayout_handler() {
+        // Arrange
+        let (handler, podcasts_dir) = setup().await;
+
+        // Act
+        let result = handler.execute(&request(false)).await;
+
+        // Assert
+        let response = result.assert_ok_debug();
+        assert!(!response.moved.is_empty());
+        assert!(response.skipped.is_empty());
+        for item in &response.moved {
+            assert!(!podcasts_dir.join(&item.file_from).exists());
+            assert!(podcasts_dir.join(&item.file_to).exists());
+            let (image_from, image_to) = item.image.as_ref().expect("should have image");
+            assert!(!podcasts_dir.join(image_from).exists());
+            assert!(podcasts_dir.join(image_to).exists());
+        }
+        assert!(!podcasts_dir.join(OLD_DIR).exists());
+        let episodes = handler
+            .metadata
+            .get_relayout_episodes(MockFeeds::PODCAST_KEY)
+            .await
+            .assert_ok_debug();
+        for item in &response.moved {
+            let episode = episodes
+                .iter()
+                .find(|episode| episode.primary_key == item.episode_key)
+                .expect("should have episode");
+            assert_eq!(
+                episode.file_sub_path.as_deref(),
+                Some(&item.file_to),
+                "{}",
+                item.title
+            );
+        }
+        let repeat = handler.execute(&request(false)).await.assert_ok_debug();
+        assert!(repeat.moved.is_empty());
+    }
+
+    #[tokio::test]
+    pub async fn relayout_handler__dry_run() {
+        // Arrange
+        let (handler, podcasts_dir) = setup().await;
+
+        // Act
+        let result = handler.execute(&request(true)).await;
+
+        // Assert
+        let response = result.assert_ok_debug();
+        assert!(response.dry_run);
+        assert!(!response.moved.is_empty());
+        for item in &response.moved {
+            assert!(podcasts_dir.join(&item.file_from).exists());
+            assert!(!podcasts_dir.join(&item.file_to).exists());
+        }
+    }
+
+    #[tokio::test]
+    pub async fn relayout_handler__missing_and_conflict() {
+        // Arrange
+        let (handler, podcasts_dir) = setup().await;
+        let planned = handler.execute(&request(true)).await.assert_ok_debug();
+        let missing = planned.moved.first().expect("should plan a move");
+        let conflict = planned.moved.get(1).expect("should plan a second move");
+        remove_file(podcasts_dir.join(&missing.file_from))
+            .await
+            .expect("should be able to delete file");
+        create_parent_dir_if_not_exist(&podcasts_dir.join(&conflict.file_to))
+            .await
+            .expect("should be able to create dir");
+        write(podcasts_dir.join(&conflict.file_to), b"other")
+            .await
+            .expect("should be able to write file");
+
+        // Act
+        let result = handler.execute(&request(false)).await;
+
+        // Assert
+        let response = result.assert_ok_debug();
+        let skipped: Vec<_> = response
+            .skipped
+            .iter()
+            .map(|skip| (skip.episode_key, skip.reason))
+            .collect();
+        assert_eq!(
+            skipped,
+            vec![
+                (missing.episode_key, RelayoutSkipReason::Missing),
+                (conflict.episode_key, RelayoutSkipReason::Conflict),
+            ]
+        );
+        assert_eq!(response.moved.len(), planned.moved.len() - 2);
+        assert!(podcasts_dir.join(&conflict.file_from).exists());
+    }
+}
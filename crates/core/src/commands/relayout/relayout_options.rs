@@ -0,0 +1,12 @@
+use crate::prelude::*;
+
+/// CLI options for [`RelayoutCliCommand`].
+#[derive(Clone, Debug, Args)]
+pub struct RelayoutOptions {
+    /// Podcast selection options.
+    #[command(flatten)]
+    pub selection: PodcastOptions,
+    /// List the files that would be moved without moving them.
+    #[arg(long)]
+    pub dry_run: bool,
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+
+/// A request to execute a [`RelayoutHandler`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RelayoutRequest {
+    /// User-defined identifier for the podcast.
+    pub slug: Slug,
+    /// Report the files that would be moved without moving them.
+    pub dry_run: bool,
+}
+
+impl Display for RelayoutRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "relayout {}", self.slug)
+    }
+}
+
+impl Executable for RelayoutRequest {
+    type Response = RelayoutResponse;
+    type ExecutionError = Report<RelayoutError>;
+}
@@ -0,0 +1,71 @@
+use crate::prelude::*;
+
+/// A response returned by [`RelayoutHandler`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RelayoutResponse {
+    /// Episodes whose files were moved, or would be for a dry run.
+    pub moved: Vec<RelayoutMove>,
+    /// Episodes whose files were left in place.
+    pub skipped: Vec<RelayoutSkip>,
+    /// Whether the files were left in place.
+    pub dry_run: bool,
+}
+
+/// Files of a downloaded episode moved to the expected path.
+///
+/// Paths are relative to the podcasts directory.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RelayoutMove {
+    /// Primary key of the episode
+    pub episode_key: EpisodeKey,
+    /// Title of the episode
+    pub title: String,
+    /// Previous path of the audio file
+    pub file_from: PathBuf,
+    /// Expected path of the audio file
+    pub file_to: PathBuf,
+    /// Previous and expected path of the image file
+    pub image: Option<(PathBuf, PathBuf)>,
+}
+
+/// A downloaded episode whose files can't be moved.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RelayoutSkip {
+    /// Primary key of the episode
+    pub episode_key: EpisodeKey,
+    /// Title of the episode
+    pub title: String,
+    /// Reason the files were left in place
+    pub reason: RelayoutSkipReason,
+}
+
+/// Reason a [`RelayoutSkip`] was left in place.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RelayoutSkipReason {
+    /// Downloaded audio file no longer exists
+    Missing,
+    /// Expected path is already used by another file
+    Conflict,
+}
+
+impl Display for RelayoutMove {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} to {}",
+            self.file_from.display(),
+            self.file_to.display()
+        )
+    }
+}
+
+impl Display for RelayoutSkip {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let reason = match self.reason {
+            RelayoutSkipReason::Missing => "file does not exist",
+            RelayoutSkipReason::Conflict => "expected path is already used",
+        };
+        write!(f, "{} ({reason})", self.title)
+    }
+}
@@ -0,0 +1,23 @@
+#[cfg(feature = "server")]
+mod validate_cli;
+mod validate_error;
+#[cfg(feature = "server")]
+mod validate_feed;
+#[cfg(feature = "server")]
+mod validate_handler;
+mod validate_options;
+mod validate_request;
+mod validate_response;
+mod validate_target;
+mod validation_issue;
+
+#[cfg(feature = "server")]
+pub use validate_cli::*;
+pub use validate_error::*;
+#[cfg(feature = "server")]
+pub use validate_handler::*;
+pub use validate_options::*;
+pub use validate_request::*;
+pub use validate_response::*;
+pub use validate_target::*;
+pub use validation_issue::*;
@@ -0,0 +1,83 @@
+use crate::prelude::*;
+use std::fmt::Write as _;
+
+/// CLI command for validating a feed without saving it.
+#[derive(FromServicesAsync)]
+pub struct ValidateCliCommand {
+    handler: Arc<ValidateHandler>,
+}
+
+impl ValidateCliCommand {
+    /// Validate the feed and print the issues.
+    ///
+    /// - Fails if the feed has any [`ValidationSeverity::Error`] issues
+    pub async fn execute(&self, options: ValidateOptions) -> Result<(), Report<ValidateError>> {
+        let request = ValidateRequest {
+            target: options.target,
+        };
+        let response = self.handler.execute(&request).await?;
+        let output = if options.json {
+            serde_json::to_string_pretty(&response).change_context(ValidateError::Serialize)?
+        } else {
+            format_response(&response)
+        };
+        println!("{output}");
+        let errors = response.count(ValidationSeverity::Error);
+        if errors > 0 {
+            return Err(Report::new(ValidateError::Invalid).attach("Errors", errors));
+        }
+        Ok(())
+    }
+}
+
+/// Format the issues as lines of text.
+fn format_response(response: &ValidateResponse) -> String {
+    let mut output = format!(
+        "{} · {} · {} episodes",
+        response.title,
+        response.url.to_redacted_string(),
+        response.episodes
+    );
+    for issue in &response.issues {
+        let _ = write!(output, "\n  {issue}");
+    }
+    let _ = write!(
+        output,
+        "\n{} errors · {} warnings",
+        response.count(ValidationSeverity::Error),
+        response.count(ValidationSeverity::Warning)
+    );
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn _format_response() {
+        // Arrange
+        let response = ValidateResponse {
+            url: MockServices::rss_url(),
+            title: "Podcast Title".to_owned(),
+            episodes: 2,
+            issues: vec![
+                ValidationIssue::error(
+                    ValidationRule::NoLanguage,
+                    "Podcast does not have a language",
+                ),
+                ValidationIssue::warning(
+                    ValidationRule::NonStandardValue,
+                    "Duration could not be parsed: soon",
+                )
+                .with_item("Episode 1"),
+            ],
+        };
+
+        // Act
+        let output = format_response(&response);
+
+        // Assert
+        assert_snapshot!(output);
+    }
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+
+/// Errors from [`ValidateHandler`] and [`ValidateCliCommand`].
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum ValidateError {
+    #[error("Unable to query database")]
+    Repository,
+    #[error("Podcast does not exist")]
+    NoPodcast,
+    #[error("Podcast does not have a feed URL")]
+    NoFeedUrl,
+    #[error("Unable to fetch feed")]
+    Fetch,
+    #[error("Unable to create directory")]
+    CreateDirectory,
+    #[error("Unable to get image")]
+    GetImage,
+    #[error("Unable to read image")]
+    ReadImage,
+    #[error("Unable to serialize validation result")]
+    Serialize,
+    #[error("Feed has validation errors")]
+    Invalid,
+}
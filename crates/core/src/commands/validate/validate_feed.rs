@@ -0,0 +1,302 @@
+use crate::prelude::*;
+use image::ImageFormat;
+
+/// Minimum width and height of artwork in pixels.
+const MIN_ARTWORK_SIZE: u32 = 1400;
+
+/// Maximum width and height of artwork in pixels.
+const MAX_ARTWORK_SIZE: u32 = 3000;
+
+/// Top level Apple Podcasts categories.
+///
+/// <https://podcasters.apple.com/support/1691-apple-podcasts-categories>
+const APPLE_CATEGORIES: [&str; 19] = [
+    "Arts",
+    "Business",
+    "Comedy",
+    "Education",
+    "Fiction",
+    "Government",
+    "Health & Fitness",
+    "History",
+    "Kids & Family",
+    "Leisure",
+    "Music",
+    "News",
+    "Religion & Spirituality",
+    "Science",
+    "Society & Culture",
+    "Sports",
+    "Technology",
+    "True Crime",
+    "TV & Film",
+];
+
+/// Check the podcast level requirements that don't need a request.
+pub(super) fn get_podcast_issues(podcast: &PodcastInfo) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    if podcast.title.trim().is_empty() {
+        issues.push(ValidationIssue::error(
+            ValidationRule::NoTitle,
+            "Podcast does not have a title",
+        ));
+    }
+    if podcast.description.trim().is_empty() {
+        issues.push(ValidationIssue::error(
+            ValidationRule::NoDescription,
+            "Podcast does not have a description",
+        ));
+    }
+    if podcast.image.is_none() {
+        issues.push(ValidationIssue::error(
+            ValidationRule::NoArtwork,
+            "Podcast does not have artwork",
+        ));
+    }
+    match &podcast.language {
+        None => issues.push(ValidationIssue::error(
+            ValidationRule::NoLanguage,
+            "Podcast does not have a language",
+        )),
+        Some(language) if !is_language_code(language) => issues.push(
+            ValidationIssue::error(
+                ValidationRule::InvalidLanguage,
+                "Language is not an ISO 639 code",
+            )
+            .with_item(language),
+        ),
+        Some(_) => {}
+    }
+    if podcast.categories.0.is_empty() {
+        issues.push(ValidationIssue::error(
+            ValidationRule::NoCategories,
+            "Podcast does not have a category",
+        ));
+    }
+    for category in &podcast.categories.0 {
+        if !APPLE_CATEGORIES.contains(&category.category.as_str()) {
+            issues.push(
+                ValidationIssue::warning(
+                    ValidationRule::UnknownCategory,
+                    "Category is not an Apple Podcasts category",
+                )
+                .with_item(&category.category),
+            );
+        }
+    }
+    if podcast.podcast_guid.is_none() {
+        issues.push(ValidationIssue::warning(
+            ValidationRule::NoPodcastGuid,
+            "Podcast does not have a `podcast:guid`",
+        ));
+    }
+    issues
+}
+
+/// Check the format and dimensions of the artwork.
+pub(super) fn get_artwork_issues(
+    format: Option<ImageFormat>,
+    width: u32,
+    height: u32,
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    if !matches!(format, Some(ImageFormat::Jpeg | ImageFormat::Png)) {
+        let name = format.map_or("unknown", |format| {
+            format
+                .extensions_str()
+                .first()
+                .copied()
+                .unwrap_or("unknown")
+        });
+        issues.push(ValidationIssue::error(
+            ValidationRule::ArtworkFormat,
+            format!("Artwork is {name} but must be JPEG or PNG"),
+        ));
+    }
+    let range = MIN_ARTWORK_SIZE..=MAX_ARTWORK_SIZE;
+    if width != height || !range.contains(&width) {
+        issues.push(ValidationIssue::error(
+            ValidationRule::ArtworkSize,
+            format!(
+                "Artwork is {width} x {height} px but must be square and between {MIN_ARTWORK_SIZE} and {MAX_ARTWORK_SIZE} px"
+            ),
+        ));
+    }
+    issues
+}
+
+/// Check that episode GUIDs are unique.
+pub(super) fn get_episode_issues(episodes: &[EpisodeInfo]) -> Vec<ValidationIssue> {
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for episode in episodes {
+        match counts
+            .iter_mut()
+            .find(|(source_id, _)| *source_id == episode.source_id)
+        {
+            Some((_, count)) => *count += 1,
+            None => counts.push((&episode.source_id, 1)),
+        }
+    }
+    counts
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(source_id, count)| {
+            ValidationIssue::error(
+                ValidationRule::DuplicateGuid,
+                format!("GUID is used by {count} episodes"),
+            )
+            .with_item(source_id)
+        })
+        .collect()
+}
+
+/// Convert the items that were skipped or coerced while parsing.
+pub(super) fn get_warning_issues(warnings: &[FeedWarning]) -> Vec<ValidationIssue> {
+    warnings.iter().map(get_warning_issue).collect()
+}
+
+fn get_warning_issue(warning: &FeedWarning) -> ValidationIssue {
+    let value = warning.value.clone().unwrap_or_default();
+    let issue = match warning.kind {
+        FeedWarningKind::Skipped => ValidationIssue::error(
+            ValidationRule::UnparseableItem,
+            warning.reason.clone().unwrap_or_default(),
+        ),
+        FeedWarningKind::NoEnclosure => ValidationIssue::error(
+            ValidationRule::NoEnclosure,
+            "Episode does not have an enclosure",
+        ),
+        FeedWarningKind::PublishedAtCoerced => ValidationIssue::warning(
+            ValidationRule::NonStandardValue,
+            format!("Published date is not RFC 2822: {value}"),
+        ),
+        FeedWarningKind::DurationCoerced => ValidationIssue::warning(
+            ValidationRule::NonStandardValue,
+            format!("Duration is not seconds or HH:MM:SS: {value}"),
+        ),
+        FeedWarningKind::DurationIgnored => ValidationIssue::warning(
+            ValidationRule::NonStandardValue,
+            format!("Duration could not be parsed: {value}"),
+        ),
+        FeedWarningKind::TagIgnored => ValidationIssue::warning(
+            ValidationRule::NonStandardValue,
+            format!(
+                "{value} tag was ignored: {}",
+                warning.reason.clone().unwrap_or_default()
+            ),
+        ),
+    };
+    issue.with_item(&warning.item)
+}
+
+/// Check a language is an ISO 639 code with optional region subtags.
+///
+/// Examples: `en`, `en-us`, `haw`
+fn is_language_code(value: &str) -> bool {
+    let mut parts = value.split('-');
+    let Some(primary) = parts.next() else {
+        return false;
+    };
+    (2..=3).contains(&primary.len())
+        && primary.chars().all(|c| c.is_ascii_alphabetic())
+        && parts.all(|part| {
+            (1..=8).contains(&part.len()) && part.chars().all(|c| c.is_ascii_alphanumeric())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn get_podcast_issues__example() {
+        // Arrange
+        let mut podcast = PodcastInfo::example();
+        podcast.podcast_guid = Some("917393e3-1b1e-5cef-ace4-edaa54e1f810".to_owned());
+        podcast.categories = PodcastCategories(vec![PodcastCategory {
+            category: "Technology".to_owned(),
+            sub_category: None,
+        }]);
+
+        // Act
+        let issues = get_podcast_issues(&podcast);
+
+        // Assert
+        assert_eq!(issues, Vec::new());
+    }
+
+    #[test]
+    fn get_podcast_issues__invalid() {
+        // Arrange
+        let mut podcast = PodcastInfo::example();
+        podcast.description = String::new();
+        podcast.image = None;
+        podcast.language = Some("English".to_owned());
+        podcast.categories = PodcastCategories(vec![PodcastCategory {
+            category: "Podcasts".to_owned(),
+            sub_category: None,
+        }]);
+
+        // Act
+        let issues = get_podcast_issues(&podcast);
+
+        // Assert
+        assert_yaml_snapshot!(issues);
+    }
+
+    #[test]
+    fn get_artwork_issues__valid() {
+        // Arrange
+        // Act
+        let issues = get_artwork_issues(Some(ImageFormat::Jpeg), 3000, 3000);
+
+        // Assert
+        assert_eq!(issues, Vec::new());
+    }
+
+    #[test]
+    fn get_artwork_issues__invalid() {
+        // Arrange
+        // Act
+        let issues = get_artwork_issues(Some(ImageFormat::WebP), 1400, 1000);
+
+        // Assert
+        assert_yaml_snapshot!(issues);
+    }
+
+    #[test]
+    fn get_episode_issues__duplicate() {
+        // Arrange
+        let episodes = vec![
+            EpisodeInfo::example(),
+            EpisodeInfo::example(),
+            EpisodeInfo {
+                source_id: "unique".to_owned(),
+                ..EpisodeInfo::example()
+            },
+        ];
+
+        // Act
+        let issues = get_episode_issues(&episodes);
+
+        // Assert
+        assert_eq!(issues.len(), 1);
+        let issue = issues.first().expect("should have an issue");
+        assert_eq!(issue.rule, ValidationRule::DuplicateGuid);
+        assert_eq!(
+            issue.item.as_deref(),
+            Some(EpisodeInfo::example().source_id.as_str())
+        );
+    }
+
+    #[test]
+    fn _is_language_code() {
+        assert!(is_language_code("en"));
+        assert!(is_language_code("en-us"));
+        assert!(is_language_code("haw"));
+        assert!(!is_language_code("English"));
+        assert!(!is_language_code("en_US"));
+        assert!(!is_language_code(""));
+    }
+}
@@ -0,0 +1,194 @@
+use super::validate_feed::*;
+use crate::prelude::*;
+use image::{ImageFormat, ImageReader};
+
+/// Slug used to parse a feed that has not been added.
+const VALIDATE_SLUG: &str = "validate";
+
+/// Check a feed against the Apple Podcasts and Podcastindex requirements.
+///
+/// - Fetches and parses the feed without saving
+/// - Uses the stored feed URL and credentials of an existing podcast
+/// - Downloads the artwork to check its format and dimensions
+/// - Reports duplicate GUIDs and items that were skipped or coerced while parsing
+#[derive(Clone, FromServicesAsync)]
+pub struct ValidateHandler {
+    paths: Arc<PathProvider>,
+    http: Arc<dyn HttpFetch>,
+    metadata: Arc<MetadataRepository>,
+    fetch: Arc<FetchHandler>,
+}
+
+#[async_trait]
+impl Execute<ValidateRequest, ValidateResponse, Report<ValidateError>> for ValidateHandler {
+    /// Execute the validate handler.
+    async fn execute(
+        &self,
+        request: &ValidateRequest,
+    ) -> Result<ValidateResponse, Report<ValidateError>> {
+        let (slug, url, credentials) = self.resolve(&request.target).await?;
+        trace!(%slug, url = %url.to_redacted_string(), "Fetching feed to validate");
+        let FetchedFeed {
+            feed,
+            url,
+            warnings,
+            ..
+        } = self
+            .fetch
+            .fetch_feed_if_modified(
+                &slug,
+                &url,
+                &CacheValidators::default(),
+                credentials.as_ref(),
+            )
+            .await
+            .change_context(ValidateError::Fetch)?
+            .ok_or_else(|| Report::new(ValidateError::Fetch).attach("Status", 304))?;
+        let mut issues = get_podcast_issues(&feed.podcast);
+        if let Some(image) = &feed.podcast.image {
+            issues.extend(self.check_artwork(&slug, image).await);
+        }
+        issues.extend(get_episode_issues(&feed.episodes));
+        issues.extend(get_warning_issues(&warnings));
+        Ok(ValidateResponse {
+            url: feed.podcast.feed_url.unwrap_or(url),
+            title: feed.podcast.title,
+            episodes: feed.episodes.len(),
+            issues,
+        })
+    }
+}
+
+impl ValidateHandler {
+    /// Get the slug, feed URL and credentials to fetch.
+    async fn resolve(
+        &self,
+        target: &ValidateTarget,
+    ) -> Result<(Slug, UrlWrapper, Option<PodcastCredentials>), Report<ValidateError>> {
+        match target {
+            ValidateTarget::Url(url) => {
+                let slug = Slug::from_str(VALIDATE_SLUG).expect("should be valid slug");
+                Ok((slug, url.clone(), None))
+            }
+            ValidateTarget::Podcast(slug) => {
+                let url = self
+                    .metadata
+                    .get_feed_url(slug)
+                    .await
+                    .change_context(ValidateError::Repository)?
+                    .ok_or(ValidateError::NoPodcast)?
+                    .ok_or_else(|| {
+                        Report::new(ValidateError::NoFeedUrl).attach("Podcast", slug.clone())
+                    })?;
+                let credentials = self
+                    .metadata
+                    .get_credentials(slug)
+                    .await
+                    .change_context(ValidateError::Repository)?;
+                Ok((slug.clone(), url, credentials))
+            }
+        }
+    }
+
+    /// Download the artwork and check its format and dimensions.
+    async fn check_artwork(&self, slug: &Slug, url: &UrlWrapper) -> Vec<ValidationIssue> {
+        let path = temp_path(&self.paths.get_validate_artwork_path(slug));
+        let result = self.get_artwork_dimensions(url, &path).await;
+        if path.exists()
+            && let Err(error) = remove_file(&path).await
+        {
+            warn!(%slug, %error, path = %path.display(), "Failed to remove temp artwork file");
+        }
+        match result {
+            Ok((format, width, height)) => get_artwork_issues(format, width, height),
+            Err(error) => {
+                debug!(%slug, error = %error.render(), "Unable to check artwork");
+                vec![
+                    ValidationIssue::error(
+                        ValidationRule::ArtworkUnavailable,
+                        error.current_context().to_string(),
+                    )
+                    .with_item(url.to_redacted_string()),
+                ]
+            }
+        }
+    }
+
+    async fn get_artwork_dimensions(
+        &self,
+        url: &UrlWrapper,
+        path: &Path,
+    ) -> Result<(Option<ImageFormat>, u32, u32), Report<ValidateError>> {
+        create_parent_dir_if_not_exist(path)
+            .await
+            .change_context(ValidateError::CreateDirectory)?;
+        self.http
+            .download(url, path.to_path_buf())
+            .await
+            .change_context(ValidateError::GetImage)
+            .attach_url(url)?;
+        let reader = ImageReader::open(path)
+            .change_context(ValidateError::ReadImage)
+            .attach_path(path)?
+            .with_guessed_format()
+            .change_context(ValidateError::ReadImage)
+            .attach_path(path)?;
+        let format = reader.format();
+        let (width, height) = reader
+            .into_dimensions()
+            .change_context(ValidateError::ReadImage)
+            .attach_path(path)?;
+        Ok((format, width, height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[tokio::test]
+    #[serial]
+    pub async fn validate_handler__url() {
+        // Arrange
+        let handler = MockServices::new()
+            .with_rss_feed()
+            .create()
+            .await
+            .get_async::<ValidateHandler>()
+            .await
+            .expect("should be able to get handler");
+        let request = ValidateRequest {
+            target: ValidateTarget::Url(MockServices::rss_url()),
+        };
+
+        // Act
+        let result = handler.execute(&request).await;
+
+        // Assert
+        let response = result.assert_ok_debug();
+        assert_yaml_snapshot!(response);
+    }
+
+    #[tokio::test]
+    #[serial]
+    pub async fn validate_handler__no_feed_url() {
+        // Arrange
+        let handler = MockServices::default()
+            .create()
+            .await
+            .get_async::<ValidateHandler>()
+            .await
+            .expect("should be able to get handler");
+        let request = ValidateRequest {
+            target: ValidateTarget::Podcast(MockFeeds::podcast_slug()),
+        };
+
+        // Act
+        let result = handler.execute(&request).await;
+
+        // Assert
+        let error = result.expect_err("should fail without a feed URL");
+        assert_eq!(error.current_context(), &ValidateError::NoFeedUrl);
+    }
+}
@@ -0,0 +1,11 @@
+use crate::prelude::*;
+
+/// CLI options for [`ValidateCliCommand`].
+#[derive(Clone, Debug, Args)]
+pub struct ValidateOptions {
+    /// Feed URL or slug of an existing podcast.
+    pub target: ValidateTarget,
+    /// Print the result as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
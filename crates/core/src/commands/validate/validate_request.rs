@@ -0,0 +1,19 @@
+use crate::prelude::*;
+
+/// A request to execute a [`ValidateHandler`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ValidateRequest {
+    /// Feed to validate.
+    pub target: ValidateTarget,
+}
+
+impl Display for ValidateRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "validate {}", self.target)
+    }
+}
+
+impl Executable for ValidateRequest {
+    type Response = ValidateResponse;
+    type ExecutionError = Report<ValidateError>;
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+
+/// A response returned by [`ValidateHandler`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ValidateResponse {
+    /// URL the feed was fetched from after redirects
+    pub url: UrlWrapper,
+    /// Title of the podcast
+    pub title: String,
+    /// Number of episodes that could be parsed
+    pub episodes: usize,
+    /// Problems found in the feed
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidateResponse {
+    /// Count the issues with the given severity.
+    #[must_use]
+    pub fn count(&self, severity: ValidationSeverity) -> usize {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == severity)
+            .count()
+    }
+}
@@ -0,0 +1,82 @@
+use crate::prelude::*;
+
+/// Feed to validate.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidateTarget {
+    /// Slug of an existing podcast using its stored feed URL and credentials
+    Podcast(Slug),
+    /// URL of a feed or a page that links to a feed
+    Url(UrlWrapper),
+}
+
+impl Display for ValidateTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ValidateTarget::Podcast(slug) => write!(f, "{slug}"),
+            ValidateTarget::Url(url) => write!(f, "{}", url.to_redacted_string()),
+        }
+    }
+}
+
+impl FromStr for ValidateTarget {
+    type Err = ValidateTargetError;
+
+    /// Parse a slug, or a URL if the value is not a valid slug.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if let Ok(slug) = Slug::from_str(value) {
+            return Ok(ValidateTarget::Podcast(slug));
+        }
+        UrlWrapper::from_str(value)
+            .map(ValidateTarget::Url)
+            .map_err(|_| ValidateTargetError::Invalid)
+    }
+}
+
+/// Errors from parsing a [`ValidateTarget`].
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum ValidateTargetError {
+    #[error("Must be a feed URL or podcast slug")]
+    Invalid,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn from_str__slug() {
+        // Arrange
+        // Act
+        let result = ValidateTarget::from_str(MockFeeds::PODCAST_SLUG);
+
+        // Assert
+        let target = result.assert_ok();
+        assert_eq!(target, ValidateTarget::Podcast(MockFeeds::podcast_slug()));
+    }
+
+    #[test]
+    fn from_str__url() {
+        // Arrange
+        let url = "https://example.com/feed.rss";
+
+        // Act
+        let result = ValidateTarget::from_str(url);
+
+        // Assert
+        let target = result.assert_ok();
+        let expected = UrlWrapper::from_str(url).expect("should be valid URL");
+        assert_eq!(target, ValidateTarget::Url(expected));
+    }
+
+    #[test]
+    fn from_str__invalid() {
+        // Arrange
+        // Act
+        let result = ValidateTarget::from_str("Not a URL");
+
+        // Assert
+        assert_eq!(result, Err(ValidateTargetError::Invalid));
+    }
+}
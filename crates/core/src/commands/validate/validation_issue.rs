@@ -0,0 +1,114 @@
+use crate::prelude::*;
+
+/// A problem found by [`ValidateHandler`].
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ValidationIssue {
+    /// Whether directories will reject the feed
+    pub severity: ValidationSeverity,
+    /// Requirement that is not met
+    pub rule: ValidationRule,
+    /// Title or GUID of the item the issue relates to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<String>,
+    /// Human readable details
+    pub message: String,
+}
+
+/// Severity of a [`ValidationIssue`].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationSeverity {
+    /// Directories are likely to reject the feed or skip the item
+    Error,
+    /// Recommended but not required
+    Warning,
+}
+
+/// Requirements checked by [`ValidateHandler`].
+///
+/// - <https://podcasters.apple.com/support/823-podcast-requirements>
+/// - <https://github.com/Podcastindex-org/podcast-namespace>
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationRule {
+    /// Podcast must have a title
+    NoTitle,
+    /// Podcast must have a description
+    NoDescription,
+    /// Podcast must have artwork
+    NoArtwork,
+    /// Artwork must be downloadable and readable
+    ArtworkUnavailable,
+    /// Artwork must be JPEG or PNG
+    ArtworkFormat,
+    /// Artwork must be square and between 1400 and 3000 px
+    ArtworkSize,
+    /// Podcast must have a language
+    NoLanguage,
+    /// Language must be an ISO 639 code
+    InvalidLanguage,
+    /// Podcast must have at least one category
+    NoCategories,
+    /// Category should be an Apple Podcasts category
+    UnknownCategory,
+    /// Podcast should have a `podcast:guid`
+    NoPodcastGuid,
+    /// Episode GUIDs must be unique
+    DuplicateGuid,
+    /// Episode must have an enclosure
+    NoEnclosure,
+    /// Episode could not be parsed
+    UnparseableItem,
+    /// Episode value was not in the standard format
+    NonStandardValue,
+}
+
+impl ValidationIssue {
+    /// Create an [`ValidationSeverity::Error`] issue.
+    #[must_use]
+    pub fn error(rule: ValidationRule, message: impl Into<String>) -> Self {
+        Self {
+            severity: ValidationSeverity::Error,
+            rule,
+            item: None,
+            message: message.into(),
+        }
+    }
+
+    /// Create an [`ValidationSeverity::Warning`] issue.
+    #[must_use]
+    pub fn warning(rule: ValidationRule, message: impl Into<String>) -> Self {
+        Self {
+            severity: ValidationSeverity::Warning,
+            rule,
+            item: None,
+            message: message.into(),
+        }
+    }
+
+    /// Set the item the issue relates to.
+    #[must_use]
+    pub fn with_item(mut self, item: impl Into<String>) -> Self {
+        self.item = Some(item.into());
+        self
+    }
+}
+
+impl Display for ValidationSeverity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ValidationSeverity::Error => write!(f, "error"),
+            ValidationSeverity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+impl Display for ValidationIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}: {}", self.severity, self.message)?;
+        if let Some(item) = &self.item {
+            write!(f, " ({item})")?;
+        }
+        Ok(())
+    }
+}
@@ -9,3 +9,15 @@ pub const JSON_EXTENSION: &str = "json";
 pub const MP3_EXTENSION: &str = "mp3";
 /// File extension for RSS files
 pub const RSS_EXTENSION: &str = "rss";
+
+/// Prefix of the Podcasting 2.0 namespace
+pub const PODCAST_NAMESPACE_PREFIX: &str = "podcast";
+/// URI of the Podcasting 2.0 namespace
+///
+/// <https://podcastindex.org/namespace/1.0>
+pub const PODCAST_NAMESPACE: &str = "https://podcastindex.org/namespace/1.0";
+
+/// Prefix of the Media RSS namespace
+///
+/// <https://www.rssboard.org/media-rss>
+pub const MEDIA_NAMESPACE_PREFIX: &str = "media";
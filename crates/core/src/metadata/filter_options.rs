@@ -26,6 +26,10 @@ pub struct FilterOptions {
     #[arg(long)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub to_year: Option<i32>,
+    /// Only include episodes that have (`true`) or have not (`false`) been removed from the feed
+    #[arg(long)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub removed_from_feed: Option<bool>,
 }
 
 impl Display for FilterOptions {
@@ -79,6 +83,11 @@ impl PodcastFeed {
                     return false;
                 }
             }
+            if let Some(removed) = options.removed_from_feed
+                && episode.removed_from_feed_at.is_some() != removed
+            {
+                return false;
+            }
             true
         });
         let after = self.episodes.len();
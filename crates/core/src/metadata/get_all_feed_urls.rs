@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use sea_orm::*;
+
+impl MetadataRepository {
+    /// Get the slug and feed URL of every podcast.
+    pub async fn get_all_feed_urls(&self) -> Result<Vec<(Slug, Option<UrlWrapper>)>, DbErr> {
+        get_all_feed_urls_query().into_tuple().all(&self.db).await
+    }
+}
+
+fn get_all_feed_urls_query() -> Select<podcast::Entity> {
+    podcast::Entity::find()
+        .select_only()
+        .columns([podcast::Column::Slug, podcast::Column::FeedUrl])
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn _get_all_feed_urls_query() {
+        // Arrange
+        // Act
+        let statement = get_all_feed_urls_query().build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+}
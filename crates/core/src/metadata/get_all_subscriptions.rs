@@ -0,0 +1,42 @@
+use crate::prelude::*;
+use sea_orm::*;
+
+impl MetadataRepository {
+    /// Get the slug, title, feed URL and website of every podcast ordered by title.
+    pub async fn get_all_subscriptions(
+        &self,
+    ) -> Result<Vec<(Slug, String, Option<UrlWrapper>, Option<UrlWrapper>)>, DbErr> {
+        get_all_subscriptions_query()
+            .into_tuple()
+            .all(&self.db)
+            .await
+    }
+}
+
+fn get_all_subscriptions_query() -> Select<podcast::Entity> {
+    podcast::Entity::find()
+        .select_only()
+        .columns([
+            podcast::Column::Slug,
+            podcast::Column::Title,
+            podcast::Column::FeedUrl,
+            podcast::Column::Link,
+        ])
+        .order_by_asc(podcast::Column::Title)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn _get_all_subscriptions_query() {
+        // Arrange
+        // Act
+        let statement = get_all_subscriptions_query().build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+}
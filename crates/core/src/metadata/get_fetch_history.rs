@@ -0,0 +1,66 @@
+use crate::prelude::*;
+use sea_orm::*;
+
+impl MetadataRepository {
+    /// Get the most recent fetches of a podcast, newest first.
+    pub async fn get_fetch_history(
+        &self,
+        slug: Slug,
+        limit: u64,
+    ) -> Result<Vec<FetchHistoryInfo>, DbErr> {
+        get_fetch_history_query(slug, limit).all(&self.db).await
+    }
+}
+
+fn get_fetch_history_query(slug: Slug, limit: u64) -> Select<fetch_history::Entity> {
+    fetch_history::Entity::find()
+        .join(JoinType::InnerJoin, fetch_history::Relation::Podcast.def())
+        .filter(podcast::Column::Slug.eq(slug))
+        .order_by_desc(fetch_history::Column::FetchedAt)
+        .order_by_desc(fetch_history::Column::PrimaryKey)
+        .limit(limit)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn _get_fetch_history_query() {
+        // Arrange
+        // Act
+        let statement = get_fetch_history_query(MockFeeds::podcast_slug(), 10).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[tokio::test]
+    pub async fn get_fetch_history() {
+        // Arrange
+        let metadata = MockServices::default()
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        for status in [200, 304] {
+            let entry = FetchHistoryInfo {
+                status,
+                ..FetchHistoryInfo::example()
+            };
+            metadata.insert_fetch_history(entry).await.assert_ok_debug();
+        }
+
+        // Act
+        let result = metadata
+            .get_fetch_history(MockFeeds::podcast_slug(), 10)
+            .await;
+
+        // Assert
+        let history = result.assert_ok_debug();
+        let statuses: Vec<u16> = history.iter().map(|entry| entry.status).collect();
+        assert_eq!(statuses, vec![304, 200]);
+    }
+}
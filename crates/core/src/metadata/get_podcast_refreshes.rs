@@ -0,0 +1,81 @@
+use crate::prelude::*;
+use sea_orm::*;
+
+/// Refresh interval and most recent fetch of a podcast.
+#[derive(Clone, Debug, FromQueryResult, PartialEq)]
+pub struct PodcastRefresh {
+    /// Slug of the podcast
+    pub slug: Slug,
+    /// Minutes between scheduled refreshes, overriding the default
+    pub refresh_interval: Option<u32>,
+    /// Date and time of the most recent fetch
+    pub last_refresh: Option<DateTime<FixedOffset>>,
+}
+
+impl MetadataRepository {
+    /// Get the refresh interval and most recent fetch of every podcast.
+    pub async fn get_podcast_refreshes(&self) -> Result<Vec<PodcastRefresh>, DbErr> {
+        get_podcast_refreshes_query()
+            .into_model::<PodcastRefresh>()
+            .all(&self.db)
+            .await
+    }
+}
+
+fn get_podcast_refreshes_query() -> Select<podcast::Entity> {
+    podcast::Entity::find()
+        .select_only()
+        .column(podcast::Column::Slug)
+        .column(podcast::Column::RefreshInterval)
+        .column_as(fetch_history::Column::FetchedAt.max(), "last_refresh")
+        .join_rev(JoinType::LeftJoin, fetch_history::Relation::Podcast.def())
+        .group_by(podcast::Column::PrimaryKey)
+        .order_by_asc(podcast::Column::Slug)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn _get_podcast_refreshes_query() {
+        // Arrange
+        // Act
+        let statement = get_podcast_refreshes_query().build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[tokio::test]
+    pub async fn get_podcast_refreshes() {
+        // Arrange
+        let metadata = MockServices::default()
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+        let entry = FetchHistoryInfo::example();
+        metadata
+            .insert_fetch_history(entry.clone())
+            .await
+            .assert_ok_debug();
+
+        // Act
+        let result = metadata.get_podcast_refreshes().await;
+
+        // Assert
+        let refreshes = result.assert_ok_debug();
+        assert_eq!(
+            refreshes.len(),
+            usize::try_from(MockFeeds::PODCAST_COUNT).expect("should fit")
+        );
+        let first = refreshes.first().expect("should have a podcast");
+        assert_eq!(first.slug, MockFeeds::podcast_slug());
+        assert_eq!(first.last_refresh, Some(entry.fetched_at));
+        let second = refreshes.get(1).expect("should have a second podcast");
+        assert_eq!(second.last_refresh, None);
+    }
+}
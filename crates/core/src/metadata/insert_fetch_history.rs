@@ -0,0 +1,36 @@
+use crate::prelude::*;
+use sea_orm::*;
+
+impl MetadataRepository {
+    /// Record a fetch of a podcast feed.
+    pub async fn insert_fetch_history(&self, entry: FetchHistoryInfo) -> Result<(), DbErr> {
+        insert_fetch_history_query(entry).exec(&self.db).await?;
+        Ok(())
+    }
+}
+
+fn insert_fetch_history_query(entry: FetchHistoryInfo) -> Insert<fetch_history::ActiveModel> {
+    let model = fetch_history::ActiveModel {
+        primary_key: NotSet,
+        ..fetch_history::ActiveModel::from(entry)
+    };
+    fetch_history::Entity::insert(model)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn _insert_fetch_history_query() {
+        // Arrange
+        let entry = FetchHistoryInfo::example();
+
+        // Act
+        let statement = insert_fetch_history_query(entry).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+}
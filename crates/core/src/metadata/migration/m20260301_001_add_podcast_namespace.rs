@@ -0,0 +1,160 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Podcasts::Table)
+                    .add_column(ColumnDef::new(Podcasts::PodcastGuid).string().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Podcasts::Table)
+                    .add_column(ColumnDef::new(Podcasts::Locked).boolean().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Podcasts::Table)
+                    .add_column(
+                        ColumnDef::new(Podcasts::Funding)
+                            .json()
+                            .not_null()
+                            .default("[]"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Podcasts::Table)
+                    .add_column(
+                        ColumnDef::new(Podcasts::Persons)
+                            .json()
+                            .not_null()
+                            .default("[]"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Episodes::Table)
+                    .add_column(
+                        ColumnDef::new(Episodes::Transcripts)
+                            .json()
+                            .not_null()
+                            .default("[]"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Episodes::Table)
+                    .add_column(ColumnDef::new(Episodes::ChaptersUrl).string().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Episodes::Table)
+                    .add_column(
+                        ColumnDef::new(Episodes::ChaptersContentType)
+                            .string()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Episodes::Table)
+                    .add_column(
+                        ColumnDef::new(Episodes::Persons)
+                            .json()
+                            .not_null()
+                            .default("[]"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Episodes::Table)
+                    .add_column(ColumnDef::new(Episodes::SeasonName).string().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            Episodes::Transcripts,
+            Episodes::ChaptersUrl,
+            Episodes::ChaptersContentType,
+            Episodes::Persons,
+            Episodes::SeasonName,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Episodes::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        for column in [
+            Podcasts::PodcastGuid,
+            Podcasts::Locked,
+            Podcasts::Funding,
+            Podcasts::Persons,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Podcasts::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Podcasts {
+    Table,
+    PodcastGuid,
+    Locked,
+    Funding,
+    Persons,
+}
+
+#[derive(DeriveIden)]
+enum Episodes {
+    Table,
+    Transcripts,
+    ChaptersUrl,
+    ChaptersContentType,
+    Persons,
+    SeasonName,
+}
@@ -0,0 +1,93 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FetchHistory::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(FetchHistory::PrimaryKey)
+                            .unsigned()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(FetchHistory::PodcastKey)
+                            .unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FetchHistory::FetchedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FetchHistory::Status)
+                            .small_unsigned()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(FetchHistory::Url).string().null())
+                    .col(ColumnDef::new(FetchHistory::Modified).boolean().not_null())
+                    .col(
+                        ColumnDef::new(FetchHistory::EpisodesInserted)
+                            .unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FetchHistory::EpisodesUpdated)
+                            .unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FetchHistory::EpisodesRemoved)
+                            .unsigned()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(FetchHistory::Changes).json().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_fetch_history_podcast_key")
+                            .from(FetchHistory::Table, FetchHistory::PodcastKey)
+                            .to(Podcasts::Table, Podcasts::PrimaryKey)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(FetchHistory::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum FetchHistory {
+    Table,
+    PrimaryKey,
+    PodcastKey,
+    FetchedAt,
+    Status,
+    Url,
+    Modified,
+    EpisodesInserted,
+    EpisodesUpdated,
+    EpisodesRemoved,
+    Changes,
+}
+
+#[derive(DeriveIden)]
+enum Podcasts {
+    Table,
+    PrimaryKey,
+}
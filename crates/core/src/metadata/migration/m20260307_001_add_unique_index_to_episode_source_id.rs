@@ -0,0 +1,54 @@
+use sea_orm_migration::prelude::*;
+
+const INDEX_NAME: &str = "idx_episodes_podcast_key_source_id";
+
+/// Enforce a unique `source_id` per podcast.
+///
+/// - Appends `#<primary_key>` to the `source_id` of every duplicate except the first
+/// - Adds a unique index on `podcast_key` and `source_id`
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE episodes SET source_id = source_id || '#' || primary_key
+                 WHERE primary_key NOT IN (
+                     SELECT MIN(primary_key) FROM episodes GROUP BY podcast_key, source_id
+                 )",
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name(INDEX_NAME)
+                    .table(Episodes::Table)
+                    .col(Episodes::PodcastKey)
+                    .col(Episodes::SourceId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(INDEX_NAME)
+                    .table(Episodes::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Episodes {
+    Table,
+    PodcastKey,
+    SourceId,
+}
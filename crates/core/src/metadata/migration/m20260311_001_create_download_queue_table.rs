@@ -0,0 +1,96 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DownloadQueue::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(DownloadQueue::PrimaryKey)
+                            .unsigned()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(DownloadQueue::PodcastKey)
+                            .unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(DownloadQueue::EpisodeKey)
+                            .unsigned()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(DownloadQueue::Replace).boolean().not_null())
+                    .col(
+                        ColumnDef::new(DownloadQueue::Position)
+                            .unsigned()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(DownloadQueue::Status).string().not_null())
+                    .col(ColumnDef::new(DownloadQueue::Error).string().null())
+                    .col(
+                        ColumnDef::new(DownloadQueue::QueuedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_download_queue_podcast_key")
+                            .from(DownloadQueue::Table, DownloadQueue::PodcastKey)
+                            .to(Podcasts::Table, Podcasts::PrimaryKey)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_download_queue_episode_key")
+                            .from(DownloadQueue::Table, DownloadQueue::EpisodeKey)
+                            .to(Episodes::Table, Episodes::PrimaryKey)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DownloadQueue::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum DownloadQueue {
+    Table,
+    PrimaryKey,
+    PodcastKey,
+    EpisodeKey,
+    Replace,
+    Position,
+    Status,
+    Error,
+    QueuedAt,
+}
+
+#[derive(DeriveIden)]
+enum Podcasts {
+    Table,
+    PrimaryKey,
+}
+
+#[derive(DeriveIden)]
+enum Episodes {
+    Table,
+    PrimaryKey,
+}
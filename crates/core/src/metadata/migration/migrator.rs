@@ -13,6 +13,19 @@ impl MigratorTrait for Migrator {
             Box::new(m20251119_002_create_episodes_table::Migration),
             Box::new(m20260107_001_add_feed_url_to_podcasts::Migration),
             Box::new(m20260110_001_sanitize_episode_paths::Migration::new()),
+            Box::new(m20260301_001_add_podcast_namespace::Migration),
+            Box::new(m20260302_001_add_cache_validators_to_podcasts::Migration),
+            Box::new(m20260303_001_add_credentials_to_podcasts::Migration),
+            Box::new(m20260304_001_add_removed_from_feed_at_to_episodes::Migration),
+            Box::new(m20260305_001_create_fetch_history_table::Migration),
+            Box::new(m20260306_001_add_enclosure_changed_at_to_episodes::Migration),
+            Box::new(m20260307_001_add_unique_index_to_episode_source_id::Migration),
+            Box::new(m20260308_001_add_refresh_interval_to_podcasts::Migration),
+            Box::new(m20260309_001_add_auto_download_to_podcasts::Migration),
+            Box::new(m20260310_001_add_played_at_to_episodes::Migration),
+            Box::new(m20260310_002_add_retention_to_podcasts::Migration),
+            Box::new(m20260311_001_create_download_queue_table::Migration),
+            Box::new(m20260312_001_add_path_template_to_podcasts::Migration),
         ]
     }
 }
@@ -1,6 +1,16 @@
 mod filter_options;
 #[cfg(feature = "server")]
+mod get_all_feed_urls;
+#[cfg(feature = "server")]
 mod get_all_podcast_slugs;
+#[cfg(feature = "server")]
+mod get_all_subscriptions;
+#[cfg(feature = "server")]
+mod get_fetch_history;
+#[cfg(feature = "server")]
+mod get_podcast_refreshes;
+#[cfg(feature = "server")]
+mod insert_fetch_history;
 mod metadata_error;
 #[cfg(feature = "server")]
 mod migration;
@@ -9,8 +19,12 @@ mod read;
 #[cfg(feature = "server")]
 mod repository;
 mod schema;
+#[cfg(feature = "server")]
+mod set_played_at;
 
 pub use filter_options::*;
+#[cfg(feature = "server")]
+pub use get_podcast_refreshes::*;
 pub use metadata_error::*;
 #[cfg(feature = "server")]
 pub(crate) use migration::*;
@@ -70,6 +70,14 @@ fn get_filter_condition(iden: DynIden, options: FilterOptions) -> Condition {
     if let Some(season) = options.to_season {
         expressions.push(Expr::col((iden.clone(), episode::Column::Season)).lte(season));
     }
+    if let Some(removed) = options.removed_from_feed {
+        let column = Expr::col((iden.clone(), episode::Column::RemovedFromFeedAt));
+        expressions.push(if removed {
+            column.is_not_null()
+        } else {
+            column.is_null()
+        });
+    }
     expressions
         .into_iter()
         .fold(Condition::all(), Condition::add)
@@ -178,6 +186,21 @@ mod tests {
         assert_eq!(count, expected as usize);
     }
 
+    #[tokio::test]
+    pub async fn get_feed_by_slug__filter_removed_from_feed() {
+        // Arrange
+        let options = FilterOptions {
+            removed_from_feed: Some(true),
+            ..FilterOptions::default()
+        };
+
+        // Act
+        let count = _get_feed_by_slug(Some(options)).await;
+
+        // Assert
+        assert_eq!(count, 0);
+    }
+
     async fn _get_feed_by_slug(options: Option<FilterOptions>) -> usize {
         // Arrange
         let metadata = MockServices::default()
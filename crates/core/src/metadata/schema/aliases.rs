@@ -13,3 +13,5 @@ pub type Duration = u32;
 pub type EpisodeNumber = u32;
 
 pub type SeasonNumber = u32;
+
+pub type DownloadJobKey = u32;
@@ -0,0 +1,46 @@
+use crate::prelude::*;
+use sea_orm::entity::prelude::*;
+
+/// Rules for which new episodes of a podcast are downloaded after a fetch.
+///
+/// Only episodes inserted by the fetch are considered. Every rule that is set
+/// must match.
+#[derive(
+    Clone, Debug, Default, Deserialize, Eq, FromJsonQueryResult, Hash, PartialEq, Serialize,
+)]
+pub struct AutoDownloadRules {
+    /// Only download episodes of these types
+    ///
+    /// Empty to download every type.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub kinds: Vec<EpisodeKind>,
+    /// Only download the most recently published of the new episodes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latest: Option<u32>,
+    /// Only download episodes in this season or later
+    ///
+    /// Episodes without a season are excluded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_season: Option<u32>,
+}
+
+impl Display for AutoDownloadRules {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut rules = Vec::new();
+        if !self.kinds.is_empty() {
+            let kinds: Vec<String> = self.kinds.iter().map(ToString::to_string).collect();
+            rules.push(kinds.join(" or "));
+        }
+        if let Some(latest) = self.latest {
+            rules.push(format!("latest {latest}"));
+        }
+        if let Some(season) = self.from_season {
+            rules.push(format!("season {season} onwards"));
+        }
+        if rules.is_empty() {
+            write!(f, "all new episodes")
+        } else {
+            write!(f, "new episodes: {}", rules.join(", "))
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::prelude::*;
+use chrono::DateTime;
+use sea_orm::entity::prelude::*;
+
+/// A download request persisted in the download queue
+pub type DownloadJobInfo = Model;
+
+/// `SeaORM` Entity for [`DownloadJobInfo`]
+#[allow(clippy::struct_field_names)]
+#[sea_orm::model]
+#[derive(Clone, Debug, DeriveEntityModel, Deserialize, PartialEq, Serialize)]
+#[sea_orm(table_name = "download_queue")]
+pub struct Model {
+    /// Primary key
+    ///
+    /// This is auto-incremented by the database
+    #[sea_orm(primary_key)]
+    pub primary_key: DownloadJobKey,
+
+    pub podcast_key: PodcastKey,
+
+    #[sea_orm(unique)]
+    pub episode_key: EpisodeKey,
+
+    #[sea_orm(belongs_to, from = "episode_key", to = "primary_key")]
+    pub episode: HasOne<episode::Entity>,
+
+    /// Replace an existing download
+    pub replace: bool,
+    /// Order in the queue, lowest first
+    pub position: u32,
+    /// State of the job
+    pub status: DownloadJobStatus,
+    /// Rendered error of the last failed attempt
+    pub error: Option<String>,
+    /// Date and time the job was added or retried
+    pub queued_at: DateTime<FixedOffset>,
+}
+
+impl DownloadJobInfo {
+    /// Get the request to execute the job.
+    #[must_use]
+    pub fn get_request(&self) -> DownloadRequest {
+        DownloadRequest::new(self.podcast_key, self.episode_key, self.replace)
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
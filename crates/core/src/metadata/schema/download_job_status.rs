@@ -0,0 +1,46 @@
+use crate::prelude::*;
+use sea_orm::entity::prelude::*;
+
+/// State of a job in the download queue
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    Eq,
+    Hash,
+    PartialEq,
+    Serialize,
+    DeriveValueType,
+)]
+#[sea_orm(value_type = "String")]
+pub enum DownloadJobStatus {
+    /// Waiting for a free worker
+    #[default]
+    Queued,
+    /// Sent to the command runner
+    Running,
+    /// Download failed and is waiting to be retried
+    Failed,
+}
+
+impl FromStr for DownloadJobStatus {
+    type Err = DownloadJobStatusError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let lower = value.to_lowercase();
+        match lower.as_str() {
+            "queued" => Ok(DownloadJobStatus::Queued),
+            "running" => Ok(DownloadJobStatus::Running),
+            "failed" => Ok(DownloadJobStatus::Failed),
+            _ => Err(DownloadJobStatusError(value.to_owned())),
+        }
+    }
+}
+
+/// Errors from parsing a [`DownloadJobStatus`].
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Unable to parse download job status: {0}")]
+pub struct DownloadJobStatusError(String);
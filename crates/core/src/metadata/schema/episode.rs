@@ -35,6 +35,19 @@ pub struct Model {
     ///
     /// Value will be `None` until the file is downloaded with [`DownloadContext`].
     pub image_sub_path: Option<PathWrapper>,
+    /// Date and time the episode was first missing from the feed.
+    ///
+    /// Value will be `None` while the episode is in the feed.
+    pub removed_from_feed_at: Option<DateTime<FixedOffset>>,
+    /// Date and time the enclosure URL or file size changed after the episode was downloaded.
+    ///
+    /// Value will be `None` until the feed replaces a downloaded enclosure and again after
+    /// the episode is re-downloaded.
+    pub enclosure_changed_at: Option<DateTime<FixedOffset>>,
+    /// Date and time the episode was first played in the web player.
+    ///
+    /// Value will be `None` until the episode is played.
+    pub played_at: Option<DateTime<FixedOffset>>,
 
     // Required
     /// GUID or Apple Podcasts Episode ID
@@ -72,6 +85,22 @@ pub struct Model {
     pub season: Option<SeasonNumber>,
     /// Episode type
     pub kind: Option<EpisodeKind>,
+
+    // Podcasting 2.0
+    /// Transcript files
+    pub transcripts: EpisodeTranscripts,
+    /// URL of the chapters file
+    ///
+    /// <https://podcastindex.org/namespace/1.0#chapters>
+    pub chapters_url: Option<UrlWrapper>,
+    /// Mime type of the chapters file
+    pub chapters_content_type: Option<String>,
+    /// People involved in the episode
+    pub persons: PodcastPersons,
+    /// Name of the season
+    ///
+    /// <https://podcastindex.org/namespace/1.0#season>
+    pub season_name: Option<String>,
 }
 
 impl EpisodeInfo {
@@ -82,6 +111,9 @@ impl EpisodeInfo {
             podcast_key: None,
             file_sub_path: None,
             image_sub_path: None,
+            removed_from_feed_at: None,
+            enclosure_changed_at: None,
+            played_at: None,
             title: "Lorem ipsum dolor sit amet".to_owned(),
             source_url: UrlWrapper::from_str("https://example.com/season-1/episode-1.mp3").expect("URL should be valid"),
             source_file_size: 1024,
@@ -96,6 +128,11 @@ impl EpisodeInfo {
             episode: Some(3),
             season: Some(2),
             kind: Some(EpisodeKind::default()),
+            transcripts: EpisodeTranscripts::default(),
+            chapters_url: None,
+            chapters_content_type: None,
+            persons: PodcastPersons::default(),
+            season_name: None,
         }
     }
 }
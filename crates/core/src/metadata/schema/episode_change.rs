@@ -0,0 +1,60 @@
+use crate::prelude::*;
+use sea_orm::entity::prelude::*;
+
+/// Changes to existing episodes detected by a single fetch.
+#[derive(
+    Clone, Debug, Default, Deserialize, Eq, FromJsonQueryResult, Hash, PartialEq, Serialize,
+)]
+pub struct EpisodeChanges(pub Vec<EpisodeChange>);
+
+/// Fields of an existing episode that changed in the feed.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct EpisodeChange {
+    /// Database key of the episode
+    pub episode_key: EpisodeKey,
+    /// Title after the change
+    pub title: String,
+    /// Fields that changed
+    pub fields: Vec<FieldChange>,
+}
+
+/// Previous and current value of a changed field.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct FieldChange {
+    /// Field that changed
+    pub field: EpisodeField,
+    /// Value before the fetch
+    pub old: Option<String>,
+    /// Value after the fetch
+    pub new: Option<String>,
+}
+
+/// Episode fields tracked by the fetch history.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EpisodeField {
+    /// [`EpisodeInfo::title`]
+    Title,
+    /// [`EpisodeInfo::source_url`]
+    SourceUrl,
+    /// [`EpisodeInfo::description`]
+    Description,
+}
+
+impl EpisodeChanges {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Display for EpisodeField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let name = match self {
+            EpisodeField::Title => "title",
+            EpisodeField::SourceUrl => "enclosure URL",
+            EpisodeField::Description => "description",
+        };
+        write!(f, "{name}")
+    }
+}
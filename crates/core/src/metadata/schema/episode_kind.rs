@@ -3,7 +3,17 @@ use sea_orm::entity::prelude::*;
 
 /// Episode type
 #[derive(
-    Clone, Copy, Debug, Default, Deserialize, Display, PartialEq, Serialize, DeriveValueType,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    Eq,
+    Hash,
+    PartialEq,
+    Serialize,
+    DeriveValueType,
 )]
 #[sea_orm(value_type = "String")]
 pub enum EpisodeKind {
@@ -40,4 +40,8 @@ pub struct EpisodePartial {
     ///
     /// Value will be `None` until the file is downloaded with [`DownloadContext`].
     pub image_sub_path: Option<PathWrapper>,
+    /// Date and time the episode was found to be missing from the feed.
+    ///
+    /// Value will be `None` while the episode is still in the feed.
+    pub removed_from_feed_at: Option<DateTime<FixedOffset>>,
 }
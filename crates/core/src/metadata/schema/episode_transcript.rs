@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use sea_orm::entity::prelude::*;
+
+/// - <https://podcastindex.org/namespace/1.0#transcript>
+#[derive(Clone, Debug, Default, Deserialize, FromJsonQueryResult, PartialEq, Serialize)]
+pub struct EpisodeTranscripts(pub Vec<EpisodeTranscript>);
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct EpisodeTranscript {
+    /// URL of the transcript file
+    pub url: UrlWrapper,
+    /// Mime type of the transcript file
+    pub content_type: String,
+    /// Language of the transcript if it differs from the podcast
+    pub language: Option<String>,
+    /// Set to `captions` if the transcript is intended as closed captions
+    pub rel: Option<String>,
+}
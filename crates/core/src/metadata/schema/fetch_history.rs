@@ -0,0 +1,72 @@
+use crate::prelude::*;
+use chrono::DateTime;
+use sea_orm::entity::prelude::*;
+
+/// Record of a single fetch of a podcast feed
+pub type FetchHistoryInfo = Model;
+
+/// `SeaORM` Entity for [`FetchHistoryInfo`]
+#[allow(clippy::struct_field_names)]
+#[sea_orm::model]
+#[derive(Clone, Debug, DeriveEntityModel, Deserialize, PartialEq, Serialize)]
+#[sea_orm(table_name = "fetch_history")]
+pub struct Model {
+    /// Primary key
+    ///
+    /// This is auto-incremented by the database
+    #[sea_orm(primary_key)]
+    pub primary_key: u32,
+
+    pub podcast_key: PodcastKey,
+
+    #[sea_orm(belongs_to, from = "podcast_key", to = "primary_key")]
+    pub podcast: HasOne<podcast::Entity>,
+
+    /// Date and time of the fetch
+    pub fetched_at: DateTime<FixedOffset>,
+    /// HTTP status of the feed response
+    ///
+    /// `304` if the feed has not been modified.
+    pub status: u16,
+    /// Feed URL after redirects
+    pub url: Option<UrlWrapper>,
+    /// Whether the feed changed since the last fetch
+    pub modified: bool,
+    /// Number of episodes inserted
+    pub episodes_inserted: u32,
+    /// Number of episodes updated
+    pub episodes_updated: u32,
+    /// Number of episodes newly missing from the feed
+    pub episodes_removed: u32,
+    /// Changes to the title, enclosure URL or description of existing episodes
+    pub changes: EpisodeChanges,
+}
+
+impl FetchHistoryInfo {
+    #[must_use]
+    #[cfg(test)]
+    pub fn example() -> Self {
+        Self {
+            primary_key: u32::default(),
+            podcast_key: MockFeeds::PODCAST_KEY,
+            fetched_at: DateTime::default(),
+            status: 200,
+            url: Some(MockServices::rss_url()),
+            modified: true,
+            episodes_inserted: 1,
+            episodes_updated: 2,
+            episodes_removed: 0,
+            changes: EpisodeChanges(vec![EpisodeChange {
+                episode_key: MockFeeds::EPISODE_KEY,
+                title: "Lorem ipsum dolor sit amet".to_owned(),
+                fields: vec![FieldChange {
+                    field: EpisodeField::Title,
+                    old: Some("Lorem ipsum".to_owned()),
+                    new: Some("Lorem ipsum dolor sit amet".to_owned()),
+                }],
+            }]),
+        }
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
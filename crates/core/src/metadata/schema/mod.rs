@@ -1,17 +1,35 @@
+mod auto_download_rules;
+pub use auto_download_rules::*;
+pub mod download_job;
+pub use download_job::DownloadJobInfo;
+mod download_job_status;
+pub use download_job_status::*;
 pub mod episode;
 pub use episode::EpisodeInfo;
+mod episode_change;
+pub use episode_change::*;
 mod episode_kind;
+pub mod fetch_history;
 pub use episode_kind::*;
+pub use fetch_history::FetchHistoryInfo;
 pub mod podcast;
 pub use podcast::PodcastInfo;
 mod podcast_category;
 pub use podcast_category::*;
+mod podcast_credentials;
+pub use podcast_credentials::*;
 mod podcast_feed;
 pub use podcast_feed::*;
+mod podcast_funding;
+pub use podcast_funding::*;
 mod podcast_kind;
+mod podcast_person;
 pub use episode_partial::*;
 pub use podcast_kind::*;
+pub use podcast_person::*;
 mod episode_partial;
+mod episode_transcript;
+pub use episode_transcript::*;
 
 pub use podcast_partial::*;
 mod podcast_partial;
@@ -22,5 +40,9 @@ pub use url_wrapper::*;
 mod path_wrapper;
 mod url_wrapper;
 pub use path_wrapper::*;
+mod path_template;
+pub use path_template::*;
+mod retention_rules;
+pub use retention_rules::*;
 
 pub use slug::*;
@@ -0,0 +1,376 @@
+use crate::prelude::*;
+use sea_orm::DeriveValueType;
+
+/// Template used when no template is set for the podcast or the server.
+///
+/// Example: `irl/S00/1970/1970-01-01 001 Hello World`
+const DEFAULT_PATH_TEMPLATE: &str = "{slug}/S{season:2}/{year}/{stem}";
+
+/// Placeholders that identify an episode within a podcast.
+///
+/// The file name must include at least one so episodes don't overwrite each other.
+const EPISODE_PLACEHOLDERS: [Placeholder; 4] = [
+    Placeholder::Title,
+    Placeholder::Guid,
+    Placeholder::Stem,
+    Placeholder::Episode,
+];
+
+/// Template for the path of a downloaded episode relative to the podcasts directory.
+///
+/// - Directories are separated by `/`
+/// - The file extension is added from the content type
+/// - Values are sanitized with [`Sanitizer`] so they can't add directories
+///
+/// Placeholders:
+/// - `{slug}`: slug of the podcast
+/// - `{podcast}`: title of the podcast
+/// - `{title}`: title of the episode
+/// - `{season}` and `{episode}`: numbers, `0` if not set
+/// - `{kind}`: `full`, `trailer` or `bonus`
+/// - `{year}`, `{month}`, `{day}` and `{date}`: published date
+/// - `{guid}`: GUID of the episode
+/// - `{stem}`: default file name of date, numbers and title
+///
+/// Numbers can be zero padded with a width: `{episode:3}`.
+#[derive(Clone, Debug, Deserialize, DeriveValueType, Eq, Hash, PartialEq, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PathTemplate(String);
+
+/// Values substituted for the placeholders of a [`PathTemplate`].
+#[derive(Clone, Debug)]
+pub struct PathTemplateValues {
+    /// Slug of the podcast
+    pub slug: Slug,
+    /// Title of the podcast
+    pub podcast_title: String,
+    /// Title of the episode
+    pub title: String,
+    /// Season number
+    pub season: Option<SeasonNumber>,
+    /// Episode number
+    pub episode: Option<EpisodeNumber>,
+    /// Episode type
+    pub kind: Option<EpisodeKind>,
+    /// Date and time the episode was released
+    pub published_at: DateTime<FixedOffset>,
+    /// GUID of the episode
+    pub guid: String,
+}
+
+impl PathTemplate {
+    /// Render the sub path of an episode without a file extension.
+    ///
+    /// Directories that render empty are skipped.
+    pub fn render(&self, values: &PathTemplateValues) -> Result<PathBuf, PathTemplateError> {
+        let mut path = PathBuf::new();
+        for segment in self.0.split('/') {
+            let mut output = String::new();
+            for token in parse_segment(segment)? {
+                match token {
+                    Token::Text(text) => output.push_str(text),
+                    Token::Placeholder(placeholder, width) => {
+                        output.push_str(&placeholder.render(values, width));
+                    }
+                }
+            }
+            let output = Sanitizer::execute(&output);
+            let output = output.trim();
+            if !output.is_empty() {
+                path.push(output);
+            }
+        }
+        if path.as_os_str().is_empty() {
+            return Err(PathTemplateError::Empty);
+        }
+        Ok(path)
+    }
+}
+
+impl Default for PathTemplate {
+    fn default() -> Self {
+        Self(DEFAULT_PATH_TEMPLATE.to_owned())
+    }
+}
+
+impl Display for PathTemplate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for PathTemplate {
+    type Err = PathTemplateError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.trim().is_empty() {
+            return Err(PathTemplateError::Empty);
+        }
+        if value.starts_with('/') || value.starts_with('\\') {
+            return Err(PathTemplateError::Absolute);
+        }
+        for segment in value.split('/') {
+            if parse_segment(segment)?.is_empty() {
+                return Err(PathTemplateError::EmptyDirectory);
+            }
+            if matches!(segment.trim(), "." | "..") {
+                return Err(PathTemplateError::Relative);
+            }
+        }
+        let file_name = value
+            .rsplit('/')
+            .next()
+            .map(parse_segment)
+            .transpose()?
+            .unwrap_or_default();
+        let identifies_episode = file_name.iter().any(|token| {
+            matches!(token, Token::Placeholder(placeholder, _) if EPISODE_PLACEHOLDERS.contains(placeholder))
+        });
+        if !identifies_episode {
+            return Err(PathTemplateError::NoEpisodePlaceholder);
+        }
+        Ok(Self(value.to_owned()))
+    }
+}
+
+impl TryFrom<String> for PathTemplate {
+    type Error = PathTemplateError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(&value)
+    }
+}
+
+impl From<PathTemplate> for String {
+    fn from(template: PathTemplate) -> Self {
+        template.0
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Placeholder {
+    Slug,
+    Podcast,
+    Title,
+    Season,
+    Episode,
+    Kind,
+    Year,
+    Month,
+    Day,
+    Date,
+    Guid,
+    Stem,
+}
+
+impl Placeholder {
+    fn from_name(name: &str) -> Option<Self> {
+        let placeholder = match name {
+            "slug" => Self::Slug,
+            "podcast" => Self::Podcast,
+            "title" => Self::Title,
+            "season" => Self::Season,
+            "episode" => Self::Episode,
+            "kind" => Self::Kind,
+            "year" => Self::Year,
+            "month" => Self::Month,
+            "day" => Self::Day,
+            "date" => Self::Date,
+            "guid" => Self::Guid,
+            "stem" => Self::Stem,
+            _ => return None,
+        };
+        Some(placeholder)
+    }
+
+    fn render(self, values: &PathTemplateValues, width: Option<usize>) -> String {
+        let value = match self {
+            Self::Slug => values.slug.to_string(),
+            Self::Podcast => values.podcast_title.clone(),
+            Self::Title => values.title.clone(),
+            Self::Season => values.season.unwrap_or_default().to_string(),
+            Self::Episode => values.episode.unwrap_or_default().to_string(),
+            Self::Kind => values.kind.unwrap_or_default().to_string().to_lowercase(),
+            Self::Year => values.published_at.format("%Y").to_string(),
+            Self::Month => values.published_at.format("%m").to_string(),
+            Self::Day => values.published_at.format("%d").to_string(),
+            Self::Date => values.published_at.format("%Y-%m-%d").to_string(),
+            Self::Guid => values.guid.clone(),
+            Self::Stem => get_episode_file_stem(
+                values.title.clone(),
+                values.published_at,
+                values.season,
+                values.episode,
+                None,
+            ),
+        };
+        match width {
+            Some(width) => format!("{value:0>width$}"),
+            None => value,
+        }
+    }
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    Placeholder(Placeholder, Option<usize>),
+}
+
+/// Split a directory or file name of a template into text and placeholders.
+fn parse_segment(segment: &str) -> Result<Vec<Token<'_>>, PathTemplateError> {
+    let mut tokens = Vec::new();
+    let mut rest = segment;
+    while let Some((text, after)) = rest.split_once('{') {
+        push_text(&mut tokens, text)?;
+        let (inner, remaining) = after.split_once('}').ok_or(PathTemplateError::Unclosed)?;
+        let (name, width) = match inner.split_once(':') {
+            Some((name, width)) => {
+                let width = width
+                    .parse::<usize>()
+                    .map_err(|_| PathTemplateError::Width(inner.to_owned()))?;
+                (name, Some(width))
+            }
+            None => (inner, None),
+        };
+        let placeholder = Placeholder::from_name(name)
+            .ok_or_else(|| PathTemplateError::Unknown(name.to_owned()))?;
+        tokens.push(Token::Placeholder(placeholder, width));
+        rest = remaining;
+    }
+    push_text(&mut tokens, rest)?;
+    Ok(tokens)
+}
+
+fn push_text<'a>(tokens: &mut Vec<Token<'a>>, text: &'a str) -> Result<(), PathTemplateError> {
+    if text.contains('}') {
+        return Err(PathTemplateError::Unopened);
+    }
+    if !text.is_empty() {
+        tokens.push(Token::Text(text));
+    }
+    Ok(())
+}
+
+/// Errors from parsing or rendering a [`PathTemplate`].
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum PathTemplateError {
+    #[error("Must not be empty")]
+    Empty,
+    #[error("Must be a relative path")]
+    Absolute,
+    #[error("Must not contain `.` or `..` directories")]
+    Relative,
+    #[error("Must not contain empty directories")]
+    EmptyDirectory,
+    #[error("Placeholder is missing a closing brace")]
+    Unclosed,
+    #[error("Closing brace is missing a placeholder")]
+    Unopened,
+    #[error("Unknown placeholder: {0}")]
+    Unknown(String),
+    #[error("Placeholder width must be a number: {0}")]
+    Width(String),
+    #[error("File name must include {{title}}, {{guid}}, {{stem}} or {{episode}}")]
+    NoEpisodePlaceholder,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    fn values() -> PathTemplateValues {
+        PathTemplateValues {
+            slug: MockFeeds::podcast_slug(),
+            podcast_title: "Podcast: Title".to_owned(),
+            title: "Part 1/2".to_owned(),
+            season: Some(2),
+            episode: Some(3),
+            kind: Some(EpisodeKind::Bonus),
+            published_at: DateTime::parse_from_rfc3339("2026-01-02T12:00:00Z")
+                .expect("should be valid date"),
+            guid: "550e8400-e29b-41d4-a716-446655440000".to_owned(),
+        }
+    }
+
+    #[test]
+    fn render__default() {
+        // Arrange
+        let template = PathTemplate::default();
+
+        // Act
+        let result = template.render(&values());
+
+        // Assert
+        let path = result.assert_ok_debug();
+        let expected = PathBuf::from(format!(
+            "{}/S02/2026/2026-01-02 S02E03 Part 1-2",
+            MockFeeds::podcast_slug()
+        ));
+        assert_eq!(path, expected);
+    }
+
+    #[test]
+    fn render__placeholders() {
+        // Arrange
+        let template = PathTemplate::from_str(
+            "{podcast}/{year}/{month}-{day}/{date} - {kind} {episode:3} {title} {guid}",
+        )
+        .assert_ok_debug();
+
+        // Act
+        let result = template.render(&values());
+
+        // Assert
+        let path = result.assert_ok_debug();
+        let expected = PathBuf::from(
+            "Podcast Title/2026/01-02/2026-01-02 - bonus 003 Part 1-2 550e8400-e29b-41d4-a716-446655440000",
+        );
+        assert_eq!(path, expected);
+    }
+
+    #[test]
+    fn render__empty_directory_skipped() {
+        // Arrange
+        let template = PathTemplate::from_str("{slug}/{guid}/{title}").assert_ok_debug();
+        let values = PathTemplateValues {
+            guid: "#".to_owned(),
+            ..values()
+        };
+
+        // Act
+        let result = template.render(&values);
+
+        // Assert
+        let path = result.assert_ok_debug();
+        let expected = PathBuf::from(format!("{}/Part 1-2", MockFeeds::podcast_slug()));
+        assert_eq!(path, expected);
+    }
+
+    #[test]
+    fn from_str__invalid() {
+        // Arrange
+        let cases = [
+            ("", PathTemplateError::Empty),
+            ("/{title}", PathTemplateError::Absolute),
+            ("../{title}", PathTemplateError::Relative),
+            ("{slug}//{title}", PathTemplateError::EmptyDirectory),
+            ("{title", PathTemplateError::Unclosed),
+            ("title}", PathTemplateError::Unopened),
+            ("{name}", PathTemplateError::Unknown("name".to_owned())),
+            (
+                "{episode:x}",
+                PathTemplateError::Width("episode:x".to_owned()),
+            ),
+            ("{title}/{year}", PathTemplateError::NoEpisodePlaceholder),
+        ];
+
+        for (template, expected) in cases {
+            // Act
+            let result = PathTemplate::from_str(template);
+
+            // Assert
+            assert_eq!(result, Err(expected), "{template}");
+        }
+    }
+}
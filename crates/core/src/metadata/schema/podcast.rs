@@ -29,8 +29,42 @@ pub struct Model {
     #[sea_orm(unique)]
     pub slug: Slug,
     /// RSS feed URL for re-scraping
+    ///
+    /// Redacted when serialized so tokens in the URL are not exposed by the web API.
+    #[serde(serialize_with = "UrlWrapper::serialize_redacted")]
     pub feed_url: Option<UrlWrapper>,
 
+    // App
+    /// `ETag` of the last feed response
+    ///
+    /// Sent as `If-None-Match` when fetching the feed.
+    pub etag: Option<String>,
+    /// `Last-Modified` of the last feed response
+    ///
+    /// Sent as `If-Modified-Since` when fetching the feed.
+    pub last_modified: Option<String>,
+    /// Credentials applied to feed and enclosure requests
+    ///
+    /// Never serialized so it is not exposed by the web API.
+    #[serde(skip)]
+    pub credentials: Option<PodcastCredentials>,
+    /// Minutes between scheduled refreshes by the web server
+    ///
+    /// Overrides [`AppOptions::refresh_interval`]. `0` disables scheduled refresh.
+    pub refresh_interval: Option<u32>,
+    /// Rules for downloading new episodes after each fetch
+    ///
+    /// `None` disables automatic downloads.
+    pub auto_download: Option<AutoDownloadRules>,
+    /// Rules for which downloaded episodes are kept
+    ///
+    /// Overrides the retention rules of [`AppOptions`]. `None` uses the defaults.
+    pub retention: Option<RetentionRules>,
+    /// Template for the paths of downloaded episodes
+    ///
+    /// Overrides [`AppOptions::path_template`]. `None` uses the default.
+    pub path_template: Option<PathTemplate>,
+
     // Required
     /// Title
     pub title: String,
@@ -68,6 +102,20 @@ pub struct Model {
     pub new_feed_url: Option<UrlWrapper>,
     /// Program or hosting provider used to create the RSS feed
     pub generator: Option<String>,
+
+    // Podcasting 2.0
+    /// Globally unique identifier for the podcast
+    ///
+    /// <https://podcastindex.org/namespace/1.0#guid>
+    pub podcast_guid: Option<String>,
+    /// Whether the podcast owner has asked for the feed not to be imported elsewhere
+    ///
+    /// <https://podcastindex.org/namespace/1.0#locked>
+    pub locked: Option<bool>,
+    /// Donation and membership links
+    pub funding: PodcastFundings,
+    /// People involved in the podcast
+    pub persons: PodcastPersons,
 }
 
 impl PodcastInfo {
@@ -77,6 +125,13 @@ impl PodcastInfo {
         Self {
             slug: Slug::from_str("test").expect("should be able to parse slug"),
             feed_url: None,
+            etag: None,
+            last_modified: None,
+            credentials: None,
+            refresh_interval: None,
+            auto_download: None,
+            retention: None,
+            path_template: None,
             primary_key: u32::default(),
             title: "Podcast Title".to_owned(),
             description: "Sed ac volutpat tortor. Pellentesque habitant morbi tristique senectus et netus et malesuada fames ac turpis egestas. Suspendisse placerat leo augue, id elementum orci venenatis eu.".to_owned(),
@@ -92,6 +147,10 @@ impl PodcastInfo {
             copyright: None,
             new_feed_url: None,
             generator: None,
+            podcast_guid: None,
+            locked: None,
+            funding: PodcastFundings::default(),
+            persons: PodcastPersons::default(),
         }
     }
 }
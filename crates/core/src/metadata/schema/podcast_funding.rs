@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use sea_orm::entity::prelude::*;
+
+/// - <https://podcastindex.org/namespace/1.0#funding>
+#[derive(Clone, Debug, Default, Deserialize, FromJsonQueryResult, PartialEq, Serialize)]
+pub struct PodcastFundings(pub Vec<PodcastFunding>);
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PodcastFunding {
+    /// URL of the donation or membership page
+    pub url: UrlWrapper,
+    /// Call to action text
+    pub message: Option<String>,
+}
@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use sea_orm::entity::prelude::*;
+
+/// - <https://podcastindex.org/namespace/1.0#person>
+#[derive(Clone, Debug, Default, Deserialize, FromJsonQueryResult, PartialEq, Serialize)]
+pub struct PodcastPersons(pub Vec<PodcastPerson>);
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PodcastPerson {
+    /// Full name or alias
+    pub name: String,
+    /// Role from the podcast taxonomy, for example `host` or `guest`
+    pub role: Option<String>,
+    /// Group from the podcast taxonomy, for example `cast` or `writing`
+    pub group: Option<String>,
+    /// URL of a picture or avatar
+    pub image: Option<UrlWrapper>,
+    /// URL of a relevant resource such as a personal website
+    pub link: Option<UrlWrapper>,
+}
@@ -0,0 +1,56 @@
+use crate::prelude::*;
+use sea_orm::entity::prelude::*;
+
+/// Rules for which downloaded episodes of a podcast are kept.
+///
+/// A downloaded episode is pruned if any rule that is set excludes it.
+#[derive(
+    Clone, Debug, Default, Deserialize, Eq, FromJsonQueryResult, Hash, PartialEq, Serialize,
+)]
+pub struct RetentionRules {
+    /// Keep only the most recently published downloaded episodes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keep_latest: Option<u32>,
+    /// Keep only episodes published within this many days
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keep_days: Option<u32>,
+    /// Keep only episodes that have not been played
+    #[serde(default)]
+    pub unplayed_only: bool,
+    /// Maximum total size of the downloaded episodes in megabytes
+    ///
+    /// The oldest episodes are pruned until the total is within the limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_size_mb: Option<u64>,
+}
+
+impl RetentionRules {
+    /// Check if every episode is kept.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+impl Display for RetentionRules {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut rules = Vec::new();
+        if let Some(latest) = self.keep_latest {
+            rules.push(format!("latest {latest}"));
+        }
+        if let Some(days) = self.keep_days {
+            rules.push(format!("last {days} days"));
+        }
+        if self.unplayed_only {
+            rules.push("unplayed".to_owned());
+        }
+        if let Some(size) = self.max_size_mb {
+            rules.push(format!("up to {size} MB"));
+        }
+        if rules.is_empty() {
+            write!(f, "keep all episodes")
+        } else {
+            write!(f, "keep {}", rules.join(", "))
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 use sea_orm::sea_query::*;
 use sea_orm::*;
+use serde::Serializer;
 
 /// A validated URL wrapper for `SeaORM` model fields.
 ///
@@ -14,6 +15,21 @@ use sea_orm::*;
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct UrlWrapper(Url);
 
+impl UrlWrapper {
+    /// Serialize an optional URL with [`UrlExtensions::to_redacted_string`].
+    ///
+    /// For `#[serde(serialize_with)]` on URLs that are sent to the web client.
+    pub fn serialize_redacted<S: Serializer>(
+        url: &Option<UrlWrapper>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match url {
+            Some(url) => serializer.serialize_some(&url.to_redacted_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
 // Convenience traits
 impl Display for UrlWrapper {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -176,6 +192,18 @@ mod tests {
         discriminant(report.current_context()) == discriminant(&expected)
     }
 
+    #[test]
+    fn serialize_redacted() {
+        let url = UrlWrapper::from_str("https://example.com/feed.xml?auth=secret").ok();
+        let mut json = Vec::new();
+        let mut serializer = serde_json::Serializer::new(&mut json);
+        UrlWrapper::serialize_redacted(&url, &mut serializer).expect("should serialize");
+        assert_eq!(
+            String::from_utf8(json).expect("should be UTF-8"),
+            r#""https://example.com/feed.xml?auth=redacted""#
+        );
+    }
+
     #[test]
     fn accepts_http() {
         let result = UrlWrapper::from_str("http://example.com/path");
@@ -0,0 +1,68 @@
+use crate::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::*;
+
+impl MetadataRepository {
+    /// Record when an episode was first played.
+    ///
+    /// Returns `Ok(false)` if the episode does not exist or was already played.
+    pub async fn set_played_at(
+        &self,
+        episode_key: EpisodeKey,
+        played_at: DateTime<FixedOffset>,
+    ) -> Result<bool, DbErr> {
+        let result = set_played_at_query(episode_key, played_at)
+            .exec(&self.db)
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+}
+
+fn set_played_at_query(
+    episode_key: EpisodeKey,
+    played_at: DateTime<FixedOffset>,
+) -> UpdateMany<episode::Entity> {
+    episode::Entity::update_many()
+        .col_expr(episode::Column::PlayedAt, Expr::value(played_at))
+        .filter(episode::Column::PrimaryKey.eq(episode_key))
+        .filter(episode::Column::PlayedAt.is_null())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    fn played_at() -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339("2026-01-01T12:00:00Z").expect("should be valid date")
+    }
+
+    #[test]
+    fn _set_played_at_query() {
+        // Arrange
+        // Act
+        let statement = set_played_at_query(1, played_at()).build(DB_BACKEND);
+
+        // Assert
+        assert_snapshot!(format_sql(&statement));
+    }
+
+    #[tokio::test]
+    pub async fn set_played_at() {
+        // Arrange
+        let metadata = MockServices::default()
+            .create()
+            .await
+            .get_async::<MetadataRepository>()
+            .await
+            .expect("should be able to get metadata repository");
+
+        // Act
+        let first = metadata.set_played_at(1, played_at()).await;
+        let second = metadata.set_played_at(1, played_at()).await;
+
+        // Assert
+        assert!(first.assert_ok_debug());
+        assert!(!second.assert_ok_debug());
+    }
+}
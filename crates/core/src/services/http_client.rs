@@ -1,39 +1,264 @@
 use crate::prelude::*;
 use crate::services::HttpRateLimiter;
 use crate::services::ipinfo::IpInfoProvider;
-use reqwest::Client as ReqwestClient;
-use reqwest::Response;
-use reqwest::header::CONTENT_TYPE;
+use reqwest::header::{
+    CONTENT_RANGE, CONTENT_TYPE, ETAG, HeaderName, IF_MODIFIED_SINCE, IF_NONE_MATCH, IF_RANGE,
+    LAST_MODIFIED, LOCATION, RANGE,
+};
+use reqwest::redirect::Policy;
+use reqwest::{Client as ReqwestClient, RequestBuilder, Response, StatusCode};
+use std::io::ErrorKind;
+use tokio::fs::{OpenOptions, rename, write};
+use tokio::sync::OwnedMutexGuard;
+use url::Origin;
 
 const DEFAULT_DOMAIN: &str = "__unknown";
 
+/// Maximum number of times an interrupted download is resumed.
+const MAX_RESUME_ATTEMPTS: u32 = 5;
+
+/// Maximum number of redirects followed for a request with credentials.
+const MAX_REDIRECTS: usize = 10;
+
 /// A client for making HTTP requests with rate limiting.
 ///
 /// - Uses `HttpRateLimiter` for per-domain rate limiting
 /// - Uses `reqwest::Client` for actual HTTP requests
+/// - Applies [`PodcastCredentials`] if set by [`HttpFetch::with_credentials`]
+///   only to requests with the same origin as the feed
+/// - Downloads to a partial file named after the destination, rather than a
+///   random [`temp_path`], so an interrupted download can be resumed by a
+///   later call. A lock per partial file stops two downloads to the same
+///   destination writing to it at once.
 #[derive(Clone)]
 pub struct HttpClient {
     rate_limiter: Arc<HttpRateLimiter>,
     client: ReqwestClient,
+    /// Client that doesn't follow redirects so credentials can be dropped
+    /// when a redirect leaves the origin of the feed.
+    manual_redirect_client: ReqwestClient,
+    credentials: Option<OriginCredentials>,
+    /// Locks of the partial files that are being written or removed
+    partial_locks: Arc<Mutex<HashMap<PathBuf, Arc<Mutex<()>>>>>,
+}
+
+/// Credentials scoped to the origin of a feed.
+#[derive(Clone)]
+struct OriginCredentials {
+    /// Scheme, host and port of the feed URL
+    origin: Origin,
+    credentials: PodcastCredentials,
 }
 
 impl HttpClient {
     /// Send a rate-limited GET request and check the response status.
     async fn send_get(&self, url: &UrlWrapper) -> Result<Response, Report<HttpError>> {
+        let response = self.send(url, self.client.get(url.as_str())).await?;
+        check_status(response, url)
+    }
+
+    /// Send a rate-limited request without checking the response status.
+    async fn send(
+        &self,
+        url: &UrlWrapper,
+        request: RequestBuilder,
+    ) -> Result<Response, Report<HttpError>> {
         let domain = url.domain().unwrap_or(DEFAULT_DOMAIN);
         self.rate_limiter.wait_for_permit(domain).await;
-        let response = self
+        let Some(credentials) = self.get_credentials(url) else {
+            return request
+                .send()
+                .await
+                .change_context(HttpError::Request)
+                .attach_url(url);
+        };
+        self.send_with_credentials(request, credentials)
+            .await
+            .attach_url(url)
+    }
+
+    /// Get the credentials if `url` has the same origin as the feed.
+    fn get_credentials(&self, url: &UrlWrapper) -> Option<&OriginCredentials> {
+        self.credentials
+            .as_ref()
+            .filter(|credentials| url.origin() == credentials.origin)
+    }
+
+    /// Send a request with credentials, following redirects manually.
+    ///
+    /// - Credentials are dropped once a redirect leaves the origin of the feed
+    /// - Follows up to [`MAX_REDIRECTS`] redirects
+    async fn send_with_credentials(
+        &self,
+        request: RequestBuilder,
+        credentials: &OriginCredentials,
+    ) -> Result<Response, Report<HttpError>> {
+        let request = request.build().change_context(HttpError::Request)?;
+        let mut current = request.url().clone();
+        let mut same_origin = true;
+        for _ in 0..=MAX_REDIRECTS {
+            let mut next = request
+                .try_clone()
+                .ok_or_else(|| Report::new(HttpError::Request))?;
+            *next.url_mut() = current.clone();
+            let mut builder = RequestBuilder::from_parts(self.manual_redirect_client.clone(), next);
+            if same_origin {
+                builder = apply_credentials(builder, &credentials.credentials);
+            }
+            let response = builder
+                .send()
+                .await
+                .change_context(HttpError::Request)
+                .attach_with("Redirected URL", || current.to_redacted_string())?;
+            if !is_redirect(response.status()) {
+                return Ok(response);
+            }
+            let Some(location) = get_header(&response, LOCATION) else {
+                return Ok(response);
+            };
+            current = current
+                .join(&location)
+                .change_context(HttpError::Redirect)?;
+            if same_origin && current.origin() != credentials.origin {
+                debug!(url = %current.to_redacted_string(), "Dropping credentials for redirect to another origin");
+                same_origin = false;
+            }
+        }
+        Err(Report::new(HttpError::Redirect))
+    }
+
+    /// Send a rate-limited GET request for the remainder of a resource.
+    ///
+    /// - `validator` is sent as `If-Range` so a changed resource is sent in full
+    async fn send_range(
+        &self,
+        url: &UrlWrapper,
+        start: u64,
+        validator: Option<&str>,
+    ) -> Result<Response, Report<HttpError>> {
+        let mut request = self
             .client
             .get(url.as_str())
-            .send()
+            .header(RANGE, format!("bytes={start}-"));
+        if let Some(validator) = validator {
+            request = request.header(IF_RANGE, validator);
+        }
+        let response = self.send(url, request).await?;
+        check_status(response, url)
+    }
+
+    /// Wait until no other download or removal is using a partial file.
+    async fn lock_partial(&self, partial: &Path) -> OwnedMutexGuard<()> {
+        let lock = self
+            .partial_locks
+            .lock()
             .await
-            .change_context(HttpError::Request)
-            .attach_url(url)?;
-        if !response.status().is_success() {
-            let report = Report::new(HttpError::Status(response.status().as_u16())).attach_url(url);
+            .entry(partial.to_path_buf())
+            .or_default()
+            .clone();
+        lock.lock_owned().await
+    }
+
+    /// Release the lock of a partial file and forget it if nothing else is waiting.
+    async fn unlock_partial(&self, partial: &Path, guard: OwnedMutexGuard<()>) {
+        drop(guard);
+        let mut locks = self.partial_locks.lock().await;
+        if locks
+            .get(partial)
+            .is_some_and(|lock| Arc::strong_count(lock) == 1)
+        {
+            locks.remove(partial);
+        }
+    }
+
+    /// Download to the partial file then move it to `destination`.
+    ///
+    /// Must be called while holding the lock of the partial file.
+    async fn download_locked(
+        &self,
+        url: &UrlWrapper,
+        destination: &Path,
+        partial: &Path,
+    ) -> Result<(), Report<HttpError>> {
+        if let Err(report) = self.download_to_file(url, partial).await {
+            if report.current_context() != &HttpError::Chunk {
+                remove_partial_files(partial).await;
+            }
+            return Err(report.attach_url(url));
+        }
+        rename(partial, destination)
+            .await
+            .change_context(HttpError::MoveDestination)
+            .attach_path(destination)?;
+        remove_if_exists(&get_validator_path(partial)?).await;
+        Ok(())
+    }
+
+    /// Stream a resource to a file, resuming if the connection drops.
+    ///
+    /// - Resumes a partial file left by an earlier call if its validator was saved
+    /// - Resumes with a `Range` request up to [`MAX_RESUME_ATTEMPTS`] times
+    /// - Starts again from zero if the server responds with the full resource
+    ///   or a `Content-Range` that does not start at the end of the file
+    /// - Errors if zero bytes are written
+    async fn download_to_file(
+        &self,
+        url: &UrlWrapper,
+        path: &Path,
+    ) -> Result<(), Report<HttpError>> {
+        let validator_path = get_validator_path(path)?;
+        let mut validator = read_validator(&validator_path).await;
+        let mut bytes_written = match validator {
+            Some(_) => get_file_length(path).await,
+            None => 0,
+        };
+        let mut response = if bytes_written > 0 {
+            debug!(url = %url.to_redacted_string(), bytes_written, "Resuming partial download");
+            self.send_range(url, bytes_written, validator.as_deref())
+                .await?
+        } else {
+            self.send_get(url).await?
+        };
+        let mut attempts = 0;
+        loop {
+            if bytes_written > 0 && !is_resumed(&response, bytes_written) {
+                debug!(url = %url.to_redacted_string(), status = response.status().as_u16(), "Server did not resume range so restarting download");
+                if response.status() == StatusCode::PARTIAL_CONTENT {
+                    response = self.send_get(url).await?;
+                }
+                bytes_written = 0;
+            }
+            let mut file = if bytes_written > 0 {
+                open_file_append(path).await?
+            } else {
+                validator =
+                    get_header(&response, ETAG).or_else(|| get_header(&response, LAST_MODIFIED));
+                write_validator(&validator_path, validator.as_deref()).await?;
+                create_file(path).await?
+            };
+            let result =
+                write_response_to_file(&mut response, &mut file, &mut bytes_written, path).await;
+            file.sync_all()
+                .await
+                .change_context(HttpError::WriteDestination)
+                .attach_path(path)?;
+            let Err(report) = result else {
+                break;
+            };
+            if report.current_context() != &HttpError::Chunk || attempts >= MAX_RESUME_ATTEMPTS {
+                return Err(report);
+            }
+            attempts += 1;
+            warn!(url = %url.to_redacted_string(), bytes_written, attempts, "Resuming interrupted download");
+            response = self
+                .send_range(url, bytes_written, validator.as_deref())
+                .await?;
+        }
+        if bytes_written == 0 {
+            let report = Report::new(HttpError::Size).attach_path(path);
             return Err(report);
         }
-        Ok(response)
+        Ok(())
     }
 }
 
@@ -46,9 +271,16 @@ impl FromServicesAsync for HttpClient {
             .validate()
             .await
             .change_context(ResolveError::Factory)?;
+        let manual_redirect_client = ReqwestClient::builder()
+            .redirect(Policy::none())
+            .build()
+            .change_context(ResolveError::Factory)?;
         Ok(Self {
             rate_limiter: services.get()?,
             client: ReqwestClient::new(),
+            manual_redirect_client,
+            credentials: None,
+            partial_locks: Arc::default(),
         })
     }
 }
@@ -69,16 +301,45 @@ impl HttpFetch for HttpClient {
             .attach_url(url)
     }
 
-    async fn head(&self, url: &UrlWrapper) -> Result<String, Report<HttpError>> {
-        let domain = url.domain().unwrap_or(DEFAULT_DOMAIN);
-        self.rate_limiter.wait_for_permit(domain).await;
-        let response = self
-            .client
-            .head(url.as_str())
-            .send()
+    async fn get_conditional(
+        &self,
+        url: &UrlWrapper,
+        validators: &CacheValidators,
+    ) -> Result<ConditionalResponse, Report<HttpError>> {
+        let mut request = self.client.get(url.as_str());
+        if let Some(etag) = &validators.etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &validators.last_modified {
+            request = request.header(IF_MODIFIED_SINCE, last_modified);
+        }
+        let response = self.send(url, request).await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(ConditionalResponse::NotModified);
+        }
+        let response = check_status(response, url)?;
+        let validators = CacheValidators {
+            etag: get_header(&response, ETAG),
+            last_modified: get_header(&response, LAST_MODIFIED),
+        };
+        let status = response.status().as_u16();
+        let final_url =
+            UrlWrapper::from_str(response.url().as_str()).unwrap_or_else(|_| url.clone());
+        let body = response
+            .text()
             .await
             .change_context(HttpError::Request)
             .attach_url(url)?;
+        Ok(ConditionalResponse::Modified {
+            body,
+            validators,
+            status,
+            url: final_url,
+        })
+    }
+
+    async fn head(&self, url: &UrlWrapper) -> Result<String, Report<HttpError>> {
+        let response = self.send(url, self.client.head(url.as_str())).await?;
         let content_type = get_content_type(response).unwrap_or_default();
         Ok(content_type)
     }
@@ -88,54 +349,207 @@ impl HttpFetch for HttpClient {
         url: &UrlWrapper,
         destination: PathBuf,
     ) -> Result<(), Report<HttpError>> {
-        let mut response = self.send_get(url).await?;
-        write_response_to_file(&mut response, &destination).await?;
+        create_parent_dir_if_not_exist(&destination)
+            .await
+            .change_context(HttpError::CreateDestinationDirectory)?;
+        let partial = get_partial_path(&destination)?;
+        let guard = self.lock_partial(&partial).await;
+        let result = self.download_locked(url, &destination, &partial).await;
+        self.unlock_partial(&partial, guard).await;
+        result
+    }
+
+    async fn remove_partial(&self, destination: &Path) -> Result<(), Report<HttpError>> {
+        let partial = get_partial_path(destination)?;
+        let guard = self.lock_partial(&partial).await;
+        remove_partial_files(&partial).await;
+        self.unlock_partial(&partial, guard).await;
         Ok(())
     }
+
+    fn with_credentials(
+        &self,
+        credentials: Option<PodcastCredentials>,
+        feed_url: &UrlWrapper,
+    ) -> Arc<dyn HttpFetch> {
+        let mut client = self.clone();
+        client.credentials = credentials
+            .filter(|credentials| !credentials.is_empty())
+            .map(|credentials| OriginCredentials {
+                origin: feed_url.origin(),
+                credentials,
+            });
+        Arc::new(client)
+    }
+}
+
+fn check_status(response: Response, url: &UrlWrapper) -> Result<Response, Report<HttpError>> {
+    if !response.status().is_success() {
+        let report = Report::new(HttpError::Status(response.status().as_u16())).attach_url(url);
+        return Err(report);
+    }
+    Ok(response)
+}
+
+/// Add the `Authorization` and extra headers of the credentials.
+fn apply_credentials(
+    mut request: RequestBuilder,
+    credentials: &PodcastCredentials,
+) -> RequestBuilder {
+    match &credentials.auth {
+        Some(PodcastAuth::Basic { username, password }) => {
+            request = request.basic_auth(username, password.as_ref());
+        }
+        Some(PodcastAuth::Bearer { token }) => {
+            request = request.bearer_auth(token);
+        }
+        None => {}
+    }
+    for (name, value) in &credentials.headers {
+        request = request.header(name.as_str(), value.as_str());
+    }
+    request
 }
 
-/// Stream a response body to a file on disk.
+/// Check if the status is a redirect with a `Location`.
 ///
-/// - Creates parent directories if needed
-/// - Removes any existing file at the destination
-/// - Writes chunks incrementally and syncs
-/// - Errors if zero bytes are written
-async fn write_response_to_file(
-    response: &mut Response,
-    destination: &Path,
-) -> Result<(), Report<HttpError>> {
-    create_parent_dir_if_not_exist(destination)
-        .await
-        .change_context(HttpError::CreateDestinationDirectory)?;
-    if destination.exists() {
-        remove_file(destination)
-            .await
-            .change_context(HttpError::RemoveExisting)?;
+/// `304 Not Modified` is not a redirect.
+fn is_redirect(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::MOVED_PERMANENTLY
+            | StatusCode::FOUND
+            | StatusCode::SEE_OTHER
+            | StatusCode::TEMPORARY_REDIRECT
+            | StatusCode::PERMANENT_REDIRECT
+    )
+}
+
+fn get_header(response: &Response, name: HeaderName) -> Option<String> {
+    let value = response.headers().get(name)?.to_str().ok()?;
+    Some(value.to_owned())
+}
+
+/// Path of the partial file a download is written to before it is moved into place.
+///
+/// Appends `.partial` so a later call for the same destination can resume it.
+fn get_partial_path(destination: &Path) -> Result<PathBuf, Report<HttpError>> {
+    append_to_file_name(destination, ".partial")
+}
+
+/// Path of the file that stores the `If-Range` validator of a partial download.
+fn get_validator_path(partial: &Path) -> Result<PathBuf, Report<HttpError>> {
+    append_to_file_name(partial, ".validator")
+}
+
+fn append_to_file_name(path: &Path, suffix: &str) -> Result<PathBuf, Report<HttpError>> {
+    let mut name = path
+        .file_name()
+        .ok_or_else(|| Report::new(HttpError::DestinationFileName).attach_path(path))?
+        .to_owned();
+    name.push(suffix);
+    Ok(path.with_file_name(name))
+}
+
+/// Read the saved validator of a partial download.
+///
+/// Returns `None` if there is no validator so the partial file can't be resumed safely.
+async fn read_validator(path: &Path) -> Option<String> {
+    let validator = read_to_string(path).await.ok()?;
+    if validator.is_empty() {
+        None
+    } else {
+        Some(validator)
+    }
+}
+
+/// Save the validator of a partial download, or remove it if there is none.
+async fn write_validator(path: &Path, validator: Option<&str>) -> Result<(), Report<HttpError>> {
+    let result = match validator {
+        Some(validator) => write(path, validator).await,
+        None => match remove_file(path).await {
+            Err(error) if error.kind() != ErrorKind::NotFound => Err(error),
+            _ => Ok(()),
+        },
+    };
+    result
+        .change_context(HttpError::WriteDestination)
+        .attach_path(path)
+}
+
+/// Remove a partial download and its validator.
+async fn remove_partial_files(partial: &Path) {
+    remove_if_exists(partial).await;
+    if let Ok(validator_path) = get_validator_path(partial) {
+        remove_if_exists(&validator_path).await;
+    }
+}
+
+async fn remove_if_exists(path: &Path) {
+    if let Err(error) = remove_file(path).await
+        && error.kind() != ErrorKind::NotFound
+    {
+        warn!(%error, path = %path.display(), "Failed to remove partial download");
     }
-    let mut file = AsyncFile::create(destination)
+}
+
+/// Get the length of a file, or `0` if it does not exist.
+async fn get_file_length(path: &Path) -> u64 {
+    metadata(path).await.map_or(0, |metadata| metadata.len())
+}
+
+/// Check a response is the remainder of a resource starting at `start`.
+fn is_resumed(response: &Response, start: u64) -> bool {
+    response.status() == StatusCode::PARTIAL_CONTENT
+        && get_content_range_start(response) == Some(start)
+}
+
+/// Get the first byte position of the `Content-Range` header.
+fn get_content_range_start(response: &Response) -> Option<u64> {
+    let value = get_header(response, CONTENT_RANGE)?;
+    let (start, _) = value.strip_prefix("bytes ")?.split_once('-')?;
+    start.trim().parse().ok()
+}
+
+/// Open a partial file to append the remainder of a download.
+async fn open_file_append(path: &Path) -> Result<AsyncFile, Report<HttpError>> {
+    OpenOptions::new()
+        .append(true)
+        .open(path)
+        .await
+        .change_context(HttpError::WriteDestination)
+        .attach_path(path)
+}
+
+/// Create or truncate a file to download to.
+async fn create_file(path: &Path) -> Result<AsyncFile, Report<HttpError>> {
+    AsyncFile::create(path)
         .await
         .change_context(HttpError::WriteDestination)
-        .attach_path(destination)?;
-    let mut bytes_written: usize = 0;
+        .attach_path(path)
+}
+
+/// Append a response body to a file chunk by chunk.
+///
+/// `bytes_written` is updated after each chunk so an interrupted
+/// download can be resumed from the end of the file.
+async fn write_response_to_file(
+    response: &mut Response,
+    file: &mut AsyncFile,
+    bytes_written: &mut u64,
+    path: &Path,
+) -> Result<(), Report<HttpError>> {
     while let Some(chunk) = response
         .chunk()
         .await
         .change_context(HttpError::Chunk)
-        .attach_path(destination)?
+        .attach_path(path)?
     {
-        bytes_written += chunk.len();
         file.write_all(&chunk)
             .await
             .change_context(HttpError::WriteDestination)
-            .attach_path(destination)?;
-    }
-    file.sync_all()
-        .await
-        .change_context(HttpError::WriteDestination)
-        .attach_path(destination)?;
-    if bytes_written == 0 {
-        let report = Report::new(HttpError::Size).attach_path(destination);
-        return Err(report);
+            .attach_path(path)?;
+        *bytes_written += u64::try_from(chunk.len()).expect("chunk length should fit in u64");
     }
     Ok(())
 }
@@ -156,9 +570,428 @@ fn get_content_type(response: Response) -> Option<String> {
 
 #[cfg(test)]
 mod tests {
+    #![allow(non_snake_case)]
     use super::*;
     use serde_json::Value;
+    use std::collections::BTreeMap;
     use std::time::{Duration, Instant};
+    use tokio::fs::read;
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+    use tokio::time::timeout;
+
+    fn local_client() -> HttpClient {
+        HttpClient {
+            rate_limiter: Arc::new(HttpRateLimiter::default()),
+            client: ReqwestClient::new(),
+            manual_redirect_client: ReqwestClient::builder()
+                .redirect(Policy::none())
+                .build()
+                .expect("should build client"),
+            credentials: None,
+            partial_locks: Arc::default(),
+        }
+    }
+
+    fn body() -> Vec<u8> {
+        (0..100_000_u32).flat_map(u32::to_le_bytes).collect()
+    }
+
+    /// Serve [`body`] to two connections, dropping the first after `cut` bytes.
+    ///
+    /// The second connection is sent the requested range unless `ignore_range`.
+    /// Returns the `Range` header of each request.
+    async fn serve_interrupted(
+        cut: usize,
+        ignore_range: bool,
+    ) -> (UrlWrapper, JoinHandle<Vec<Option<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("should be able to bind");
+        let address = listener.local_addr().expect("should have address");
+        let url = UrlWrapper::from_str(&format!("http://{address}/episode.mp3"))
+            .expect("URL should parse");
+        let handle = tokio::spawn(async move {
+            let body = body();
+            let mut ranges = Vec::new();
+            for index in 0..2 {
+                let (mut stream, _) = listener.accept().await.expect("should accept");
+                let mut request = Vec::new();
+                let mut buffer = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let count = stream.read(&mut buffer).await.expect("should read");
+                    request.extend_from_slice(buffer.get(..count).expect("should be in range"));
+                }
+                let request = String::from_utf8(request).expect("should be UTF-8");
+                let range = request
+                    .lines()
+                    .find_map(|line| line.strip_prefix("range: bytes="))
+                    .map(|range| range.trim_end_matches('-').to_owned());
+                let start: usize = match &range {
+                    Some(range) if !ignore_range => range.parse().expect("should be a number"),
+                    _ => 0,
+                };
+                ranges.push(range);
+                let remainder = body.get(start..).expect("should be in range");
+                let header = if start > 0 {
+                    format!(
+                        "HTTP/1.1 206 Partial Content\r\nContent-Length: {}\r\nContent-Range: bytes {start}-{}/{}\r\n\r\n",
+                        remainder.len(),
+                        body.len() - 1,
+                        body.len()
+                    )
+                } else {
+                    format!(
+                        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nETag: \"v1\"\r\n\r\n",
+                        body.len()
+                    )
+                };
+                let sent = if index == 0 {
+                    remainder.get(..cut).expect("should be in range")
+                } else {
+                    remainder
+                };
+                stream
+                    .write_all(header.as_bytes())
+                    .await
+                    .expect("should write header");
+                stream.write_all(sent).await.expect("should write body");
+                stream.flush().await.expect("should flush");
+            }
+            ranges
+        });
+        (url, handle)
+    }
+
+    /// Serve a single request with `response` and return the request headers.
+    async fn serve_once(response: String) -> (UrlWrapper, JoinHandle<String>) {
+        let (url, handle) = serve_sequence(vec![response]).await;
+        let handle = tokio::spawn(async move {
+            let requests = handle.await.expect("server should not panic");
+            requests.into_iter().next().expect("should have a request")
+        });
+        (url, handle)
+    }
+
+    /// Serve one request per response in order and return the request headers.
+    async fn serve_sequence(responses: Vec<String>) -> (UrlWrapper, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("should be able to bind");
+        let address = listener.local_addr().expect("should have address");
+        let url =
+            UrlWrapper::from_str(&format!("http://{address}/feed.xml")).expect("URL should parse");
+        let handle = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for response in responses {
+                let (mut stream, _) = listener.accept().await.expect("should accept");
+                let mut request = Vec::new();
+                let mut buffer = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let count = stream.read(&mut buffer).await.expect("should read");
+                    request.extend_from_slice(buffer.get(..count).expect("should be in range"));
+                }
+                stream
+                    .write_all(response.as_bytes())
+                    .await
+                    .expect("should write response");
+                stream.flush().await.expect("should flush");
+                requests.push(String::from_utf8(request).expect("should be UTF-8"));
+            }
+            requests
+        });
+        (url, handle)
+    }
+
+    fn credentials() -> PodcastCredentials {
+        PodcastCredentials {
+            auth: Some(PodcastAuth::Bearer {
+                token: "secret".to_owned(),
+            }),
+            headers: BTreeMap::from([("x-api-key".to_owned(), "key".to_owned())]),
+        }
+    }
+
+    #[tokio::test]
+    pub async fn with_credentials__cross_origin_redirect() {
+        // Arrange
+        let (other_url, other) =
+            serve_once("HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_owned()).await;
+        let (feed_url, feed) = serve_once(format!(
+            "HTTP/1.1 302 Found\r\nLocation: {other_url}\r\nContent-Length: 0\r\n\r\n"
+        ))
+        .await;
+        let http = local_client().with_credentials(Some(credentials()), &feed_url);
+
+        // Act
+        let result = http.get_string(&feed_url).await;
+
+        // Assert
+        let body = result.assert_ok_debug();
+        assert_eq!(body, "ok");
+        let feed_request = feed.await.expect("server should not panic");
+        assert!(feed_request.contains("authorization: Bearer secret"));
+        assert!(feed_request.contains("x-api-key: key"));
+        let other_request = other.await.expect("server should not panic");
+        assert!(!other_request.contains("authorization"));
+        assert!(!other_request.contains("x-api-key"));
+    }
+
+    #[tokio::test]
+    pub async fn with_credentials__other_origin() {
+        // Arrange
+        let (url, server) =
+            serve_once("HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok".to_owned()).await;
+        let feed_url =
+            UrlWrapper::from_str("https://example.com/feed.xml").expect("URL should parse");
+        let http = local_client().with_credentials(Some(credentials()), &feed_url);
+
+        // Act
+        let result = http.get_string(&url).await;
+
+        // Assert
+        result.assert_ok_debug();
+        let request = server.await.expect("server should not panic");
+        assert!(!request.contains("authorization"));
+        assert!(!request.contains("x-api-key"));
+    }
+
+    #[tokio::test]
+    pub async fn download__resume() {
+        // Arrange
+        let http = local_client();
+        let (url, server) = serve_interrupted(150_000, false).await;
+        let dir = TempDirectory::default()
+            .create()
+            .expect("should be able to create temp dir");
+        let destination = dir.join("episode.mp3");
+
+        // Act
+        let result = http.download(&url, destination.clone()).await;
+
+        // Assert
+        result.assert_ok_debug();
+        let ranges = server.await.expect("server should not panic");
+        assert_eq!(ranges, vec![None, Some("150000".to_owned())]);
+        let bytes = read(&destination).await.expect("should read file");
+        assert!(bytes == body(), "downloaded file should match the body");
+        let mut entries = read_dir(&dir).await.expect("should read dir");
+        let mut count = 0;
+        while entries
+            .next_entry()
+            .await
+            .expect("should read entry")
+            .is_some()
+        {
+            count += 1;
+        }
+        assert_eq!(count, 1, "temp file should be renamed into place");
+    }
+
+    #[tokio::test]
+    pub async fn download__range_ignored() {
+        // Arrange
+        let http = local_client();
+        let (url, server) = serve_interrupted(150_000, true).await;
+        let dir = TempDirectory::default()
+            .create()
+            .expect("should be able to create temp dir");
+        let destination = dir.join("episode.mp3");
+
+        // Act
+        let result = http.download(&url, destination.clone()).await;
+
+        // Assert
+        result.assert_ok_debug();
+        let ranges = server.await.expect("server should not panic");
+        assert_eq!(ranges.len(), 2);
+        let bytes = read(&destination).await.expect("should read file");
+        assert!(bytes == body(), "downloaded file should match the body");
+    }
+
+    #[tokio::test]
+    pub async fn download__resume_partial() {
+        // Arrange
+        let http = local_client();
+        let (url, server) = serve_once(
+            "HTTP/1.1 206 Partial Content\r\nContent-Length: 5\r\nContent-Range: bytes 6-10/11\r\n\r\nworld"
+                .to_owned(),
+        )
+        .await;
+        let dir = TempDirectory::default()
+            .create()
+            .expect("should be able to create temp dir");
+        let destination = dir.join("episode.mp3");
+        let partial = get_partial_path(&destination).expect("should have file name");
+        write(&partial, "hello ")
+            .await
+            .expect("should write partial");
+        write(
+            get_validator_path(&partial).expect("should have file name"),
+            "\"v1\"",
+        )
+        .await
+        .expect("should write validator");
+
+        // Act
+        let result = http.download(&url, destination.clone()).await;
+
+        // Assert
+        result.assert_ok_debug();
+        let request = server.await.expect("server should not panic");
+        assert!(request.contains("range: bytes=6-"));
+        assert!(request.contains("if-range: \"v1\""));
+        let contents = read_to_string(&destination)
+            .await
+            .expect("should read file");
+        assert_eq!(contents, "hello world");
+        assert!(!partial.exists(), "partial file should be moved into place");
+        assert!(
+            !get_validator_path(&partial)
+                .expect("should have file name")
+                .exists(),
+            "validator should be removed"
+        );
+    }
+
+    #[tokio::test]
+    pub async fn download__content_range_mismatch() {
+        // Arrange
+        let http = local_client();
+        let (url, server) = serve_sequence(vec![
+            "HTTP/1.1 206 Partial Content\r\nContent-Length: 5\r\nContent-Range: bytes 0-4/11\r\n\r\nhello"
+                .to_owned(),
+            "HTTP/1.1 200 OK\r\nContent-Length: 11\r\n\r\nhello world".to_owned(),
+        ])
+        .await;
+        let dir = TempDirectory::default()
+            .create()
+            .expect("should be able to create temp dir");
+        let destination = dir.join("episode.mp3");
+        let partial = get_partial_path(&destination).expect("should have file name");
+        write(&partial, "hello ")
+            .await
+            .expect("should write partial");
+        write(
+            get_validator_path(&partial).expect("should have file name"),
+            "\"v1\"",
+        )
+        .await
+        .expect("should write validator");
+
+        // Act
+        let result = http.download(&url, destination.clone()).await;
+
+        // Assert
+        result.assert_ok_debug();
+        let requests = server.await.expect("server should not panic");
+        assert!(
+            requests
+                .first()
+                .is_some_and(|request| request.contains("range: bytes=6-"))
+        );
+        assert!(
+            requests
+                .get(1)
+                .is_some_and(|request| !request.contains("range:"))
+        );
+        let contents = read_to_string(&destination)
+            .await
+            .expect("should read file");
+        assert_eq!(contents, "hello world");
+    }
+
+    #[tokio::test]
+    pub async fn download__partial_without_validator() {
+        // Arrange
+        let http = local_client();
+        let (url, server) =
+            serve_once("HTTP/1.1 200 OK\r\nContent-Length: 11\r\n\r\nhello world".to_owned()).await;
+        let dir = TempDirectory::default()
+            .create()
+            .expect("should be able to create temp dir");
+        let destination = dir.join("episode.mp3");
+        write(
+            get_partial_path(&destination).expect("should have file name"),
+            "stale",
+        )
+        .await
+        .expect("should write partial");
+
+        // Act
+        let result = http.download(&url, destination.clone()).await;
+
+        // Assert
+        result.assert_ok_debug();
+        let request = server.await.expect("server should not panic");
+        assert!(!request.contains("range:"));
+        let contents = read_to_string(&destination)
+            .await
+            .expect("should read file");
+        assert_eq!(contents, "hello world");
+    }
+
+    #[tokio::test]
+    pub async fn download__no_file_name() {
+        // Arrange
+        let http = local_client();
+        let url = UrlWrapper::from_str("http://127.0.0.1:1/episode.mp3").expect("URL should parse");
+        let dir = TempDirectory::default()
+            .create()
+            .expect("should be able to create temp dir");
+
+        // Act
+        let result = http.download(&url, dir.join("..")).await;
+
+        // Assert
+        let report = result.expect_err("should not download without a file name");
+        assert_eq!(report.current_context(), &HttpError::DestinationFileName);
+    }
+
+    #[tokio::test]
+    pub async fn remove_partial() {
+        // Arrange
+        let http = local_client();
+        let dir = TempDirectory::default()
+            .create()
+            .expect("should be able to create temp dir");
+        let destination = dir.join("episode.mp3");
+        let partial = get_partial_path(&destination).expect("should have file name");
+        let validator = get_validator_path(&partial).expect("should have file name");
+        write(&partial, "hello ")
+            .await
+            .expect("should write partial");
+        write(&validator, "\"v1\"")
+            .await
+            .expect("should write validator");
+
+        // Act
+        let result = http.remove_partial(&destination).await;
+
+        // Assert
+        result.assert_ok_debug();
+        assert!(!partial.exists(), "partial file should be removed");
+        assert!(!validator.exists(), "validator should be removed");
+    }
+
+    #[tokio::test]
+    pub async fn lock_partial__waits_for_other_download() {
+        // Arrange
+        let http = local_client();
+        let partial = PathBuf::from("/podcasts/episode.mp3.partial");
+        let guard = http.lock_partial(&partial).await;
+
+        // Act
+        let waiting = timeout(Duration::from_millis(50), http.lock_partial(&partial)).await;
+        http.unlock_partial(&partial, guard).await;
+        let released = timeout(Duration::from_millis(50), http.lock_partial(&partial)).await;
+
+        // Assert
+        assert!(waiting.is_err(), "should wait while the partial is locked");
+        let guard = released.expect("should lock once released");
+        http.unlock_partial(&partial, guard).await;
+        assert!(http.partial_locks.lock().await.is_empty());
+    }
 
     #[tokio::test]
     #[ignore = "uses example.com"]
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+
+/// Validators from a previous response used to make a conditional request.
+///
+/// - <https://developer.mozilla.org/en-US/docs/Web/HTTP/Guides/Conditional_requests>
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CacheValidators {
+    /// `ETag` header sent as `If-None-Match`
+    pub etag: Option<String>,
+    /// `Last-Modified` header sent as `If-Modified-Since`
+    pub last_modified: Option<String>,
+}
+
+/// Response from a conditional GET request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConditionalResponse {
+    /// Server responded `304 Not Modified`
+    NotModified,
+    /// Server responded with a body
+    Modified {
+        body: String,
+        validators: CacheValidators,
+        /// HTTP status code
+        status: u16,
+        /// URL of the response after HTTP redirects
+        url: UrlWrapper,
+    },
+}
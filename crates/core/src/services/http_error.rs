@@ -18,8 +18,12 @@ pub enum HttpError {
     WriteDestination,
     #[error("File size is zero")]
     Size,
-    #[error("Unable to remove existing file")]
-    RemoveExisting,
+    #[error("Unable to move downloaded file into place")]
+    MoveDestination,
+    #[error("Destination has no file name")]
+    DestinationFileName,
+    #[error("Too many redirects or an invalid redirect location")]
+    Redirect,
 }
 
 fn get_reason(number: &u16) -> &str {
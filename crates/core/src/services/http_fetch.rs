@@ -13,15 +13,44 @@ pub trait HttpFetch: Send + Sync {
     /// Fetch the response body as a string.
     async fn get_string(&self, url: &UrlWrapper) -> Result<String, Report<HttpError>>;
 
+    /// Fetch the response body as a string unless it has not changed.
+    ///
+    /// - Sends `If-None-Match` and `If-Modified-Since` from `validators`
+    /// - Returns [`ConditionalResponse::NotModified`] for a `304` response
+    async fn get_conditional(
+        &self,
+        url: &UrlWrapper,
+        validators: &CacheValidators,
+    ) -> Result<ConditionalResponse, Report<HttpError>>;
+
     /// Perform a HEAD request and return the Content-Type header value.
     async fn head(&self, url: &UrlWrapper) -> Result<String, Report<HttpError>>;
 
     /// Download a file from a URL to a destination path.
+    ///
+    /// - Writes to a `.partial` file that is moved into place once complete
+    /// - An interrupted partial file is kept so a later call can resume it
+    /// - Waits for any other download to the same destination to finish
     async fn download(
         &self,
         url: &UrlWrapper,
         destination: PathBuf,
     ) -> Result<(), Report<HttpError>>;
+
+    /// Remove the partial file of an interrupted download to a destination.
+    ///
+    /// Use when the download won't be retried so the partial file isn't left behind.
+    async fn remove_partial(&self, destination: &Path) -> Result<(), Report<HttpError>>;
+
+    /// Get a client that applies `credentials` to requests for the feed.
+    ///
+    /// Credentials are only sent to the origin (scheme, host and port) of
+    /// `feed_url`, and are dropped on redirects to another origin.
+    fn with_credentials(
+        &self,
+        credentials: Option<PodcastCredentials>,
+        feed_url: &UrlWrapper,
+    ) -> Arc<dyn HttpFetch>;
 }
 
 /// Extension trait for [`HttpFetch`] providing generic convenience methods.
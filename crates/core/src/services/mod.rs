@@ -1,5 +1,7 @@
 #[cfg(feature = "server")]
 mod http_client;
+#[cfg(feature = "server")]
+mod http_conditional;
 mod http_error;
 #[cfg(feature = "server")]
 mod http_fetch;
@@ -16,6 +18,8 @@ mod with_core;
 
 #[cfg(feature = "server")]
 pub use http_client::*;
+#[cfg(feature = "server")]
+pub use http_conditional::*;
 pub use http_error::*;
 #[cfg(feature = "server")]
 pub use http_fetch::*;
@@ -26,6 +26,74 @@ pub struct AppOptions {
     ///
     /// Default: None
     pub expect_country: Option<String>,
+    /// Minutes between scheduled feed refreshes by the web server.
+    ///
+    /// Podcasts are only refreshed on a schedule if this or the podcast
+    /// `refresh_interval` is set.
+    ///
+    /// Default: None
+    pub refresh_interval: Option<u32>,
+    /// Maximum minutes of random delay added to each scheduled refresh.
+    ///
+    /// Spreads refreshes out so every feed is not fetched at once.
+    ///
+    /// Default: 10% of the refresh interval
+    pub refresh_jitter: Option<u32>,
+    /// Default number of the most recently published downloads to keep per podcast.
+    ///
+    /// Default: None
+    pub retention_keep_latest: Option<u32>,
+    /// Default number of days of downloads to keep per podcast.
+    ///
+    /// Default: None
+    pub retention_keep_days: Option<u32>,
+    /// Only keep downloads of episodes that have not been played.
+    ///
+    /// Default: false
+    pub retention_unplayed_only: Option<bool>,
+    /// Default maximum total size of downloads per podcast in megabytes.
+    ///
+    /// Default: None
+    pub retention_max_size_mb: Option<u64>,
+    /// Default template for the paths of downloaded episodes.
+    ///
+    /// See [`PathTemplate`] for the placeholders.
+    ///
+    /// Default: `{slug}/S{season:2}/{year}/{stem}`
+    pub path_template: Option<PathTemplate>,
+    /// Optional values added to the tags of downloaded episodes.
+    ///
+    /// Comma separated list of [`TagField`] in kebab case.
+    /// An empty value disables every optional field.
+    ///
+    /// Default: all fields
+    pub tag_fields: Option<Vec<TagField>>,
+}
+
+impl AppOptions {
+    /// Get the default retention rules for podcasts without their own.
+    ///
+    /// Returns `None` if no retention option is set.
+    #[must_use]
+    pub fn get_retention(&self) -> Option<RetentionRules> {
+        let rules = RetentionRules {
+            keep_latest: self.retention_keep_latest,
+            keep_days: self.retention_keep_days,
+            unplayed_only: self.retention_unplayed_only.unwrap_or_default(),
+            max_size_mb: self.retention_max_size_mb,
+        };
+        (!rules.is_empty()).then_some(rules)
+    }
+
+    /// Get the optional values added to the tags of downloaded episodes.
+    ///
+    /// Returns every [`TagField`] if the option is not set.
+    #[must_use]
+    pub fn get_tag_fields(&self) -> Vec<TagField> {
+        self.tag_fields
+            .clone()
+            .unwrap_or_else(|| TagField::ALL.to_vec())
+    }
 }
 
 impl FromServices for AppOptions {
@@ -7,6 +7,7 @@ const RSS_FILE_NAME: &str = "feed.rss";
 pub const METADATA_DB: &str = "metadata.db";
 const BANNER_FILE_NAME: &str = "banner.jpg";
 const COVER_FILE_NAME: &str = "cover.jpg";
+const VALIDATE_DIR: &str = "validate";
 
 /// Service for providing file paths and URL.
 #[derive(Clone)]
@@ -108,6 +109,16 @@ impl PathProvider {
             .join(BANNER_FILE_NAME)
     }
 
+    /// Absolute path to where artwork is downloaded to check its dimensions.
+    ///
+    /// Example: `$HOME/.cache/alnwick/validate/irl`
+    #[must_use]
+    pub fn get_validate_artwork_path(&self, podcast_slug: &Slug) -> PathBuf {
+        self.cache_dir
+            .join(VALIDATE_DIR)
+            .join(podcast_slug.as_str())
+    }
+
     /// Create all the cache and data directories.
     pub fn create_dirs(&self) -> Result<(), Report<PathProviderError>> {
         let dirs = vec![
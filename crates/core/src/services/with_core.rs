@@ -21,14 +21,29 @@ impl WithCore for ServiceBuilder {
             .with_type::<IpInfoProvider>()
             .with_trait_async::<dyn HttpFetch, HttpClient>()
             .with_type_async::<MetadataRepository>()
+            .with_type_async::<SimplecastFeedSource>()
+            .with_type_async::<FeedSources>()
             .with_type_async::<PodcastSelector>()
             .with_type_async::<CliRunner>()
+            .with_type::<CommandQueue>()
+            .with_type_async::<DownloadQueue>()
             .with_type_async::<AddHandler>()
             .with_type_async::<AddCliCommand>()
             .with_type_async::<FetchCliCommand>()
             .with_type_async::<DownloadCliCommand>()
             .with_type_async::<EmulateCliCommand>()
             .with_type_async::<CoverCliCommand>()
+            .with_type_async::<ImportPlanner>()
+            .with_type_async::<ImportCliCommand>()
+            .with_type_async::<OpmlExporter>()
+            .with_type_async::<ExportCliCommand>()
+            .with_type_async::<HistoryCliCommand>()
+            .with_type_async::<ValidateHandler>()
+            .with_type_async::<ValidateCliCommand>()
+            .with_type_async::<ConfigureCliCommand>()
+            .with_type_async::<PruneCliCommand>()
+            .with_type_async::<RelayoutCliCommand>()
+            .with_type_async::<RefreshScheduler>()
             .with_type_async::<SubcommandHandler>()
     }
 }
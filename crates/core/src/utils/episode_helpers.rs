@@ -35,7 +35,7 @@ pub fn get_episode_file_stem(
 pub fn get_episode_file_extenson(content_type: &str) -> Option<String> {
     let extension = match content_type {
         "audio/mpeg" => MP3_EXTENSION,
-        "audio/x-m4a" => "m4a",
+        "audio/x-m4a" | "audio/mp4" => "m4a",
         "video/quicktime" => "mov",
         "video/mp4" => "mp4",
         "video/x-m4v" => "m4v",
@@ -16,7 +16,7 @@ impl<E: Error + Send + Sync + 'static> CustomReportExt<E> for Report<E> {
         self.attach("Episode", episode)
     }
     fn attach_url(self, url: &UrlWrapper) -> Report<E> {
-        self.attach("URL", url)
+        self.attach("URL", url.to_redacted_string())
     }
 }
 
@@ -25,6 +25,6 @@ impl<T, E: Error + Send + Sync + 'static> CustomResultExt<T, E> for Result<T, Re
         self.attach_with("Episode", || episode)
     }
     fn attach_url(self, url: &UrlWrapper) -> Result<T, Report<E>> {
-        self.attach_with("URL", || url)
+        self.attach_with("URL", || url.to_redacted_string())
     }
 }
@@ -8,6 +8,8 @@ mod logging;
 mod mount_provider_linux;
 #[cfg(all(feature = "server", not(target_os = "linux")))]
 mod mount_provider_other;
+#[cfg(feature = "server")]
+mod opml;
 mod path_safety;
 #[cfg(feature = "server")]
 mod resize;
@@ -31,6 +33,8 @@ pub use logging::*;
 pub use mount_provider_linux::*;
 #[cfg(all(feature = "server", not(target_os = "linux")))]
 pub use mount_provider_other::*;
+#[cfg(feature = "server")]
+pub use opml::*;
 pub use path_safety::*;
 #[cfg(feature = "server")]
 pub use resize::*;
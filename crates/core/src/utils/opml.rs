@@ -0,0 +1,175 @@
+use crate::prelude::*;
+use quick_xml::de::from_str as from_xml;
+use quick_xml::se::to_string as to_xml;
+
+/// Current OPML version.
+const OPML_VERSION: &str = "2.0";
+
+/// An OPML document listing podcast subscriptions.
+///
+/// - <https://opml.org/spec2.opml>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename = "opml")]
+pub struct Opml {
+    #[serde(rename = "@version", default)]
+    pub version: String,
+    #[serde(default)]
+    pub head: OpmlHead,
+    #[serde(default)]
+    pub body: OpmlBody,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct OpmlHead {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct OpmlBody {
+    #[serde(rename = "outline", default)]
+    pub outlines: Vec<OpmlOutline>,
+}
+
+/// An outline element.
+///
+/// Subscriptions have an `xmlUrl`. Outlines without one are usually folders.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct OpmlOutline {
+    #[serde(rename = "@text", default)]
+    pub text: String,
+    #[serde(rename = "@title", default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(rename = "@type", default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(rename = "@xmlUrl", default, skip_serializing_if = "Option::is_none")]
+    pub xml_url: Option<String>,
+    #[serde(rename = "@htmlUrl", default, skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(rename = "outline", default, skip_serializing_if = "Vec::is_empty")]
+    pub outlines: Vec<OpmlOutline>,
+}
+
+impl Opml {
+    /// Create an OPML 2.0 document.
+    #[must_use]
+    pub fn new(title: Option<String>, outlines: Vec<OpmlOutline>) -> Self {
+        Self {
+            version: OPML_VERSION.to_owned(),
+            head: OpmlHead { title },
+            body: OpmlBody { outlines },
+        }
+    }
+
+    /// Parse an OPML document.
+    pub fn parse(xml: &str) -> Result<Self, Report<OpmlError>> {
+        from_xml(xml).change_context(OpmlError::Parse)
+    }
+
+    /// Write the OPML document including the XML declaration.
+    pub fn write(&self) -> Result<String, Report<OpmlError>> {
+        let xml = to_xml(self).change_context(OpmlError::Write)?;
+        Ok(format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{xml}"))
+    }
+
+    /// Get every outline with an `xmlUrl` including those nested in folders.
+    #[must_use]
+    pub fn get_subscriptions(&self) -> Vec<&OpmlOutline> {
+        let mut subscriptions = Vec::new();
+        let mut stack: Vec<&OpmlOutline> = self.body.outlines.iter().rev().collect();
+        while let Some(outline) = stack.pop() {
+            if outline.xml_url.is_some() {
+                subscriptions.push(outline);
+            }
+            stack.extend(outline.outlines.iter().rev());
+        }
+        subscriptions
+    }
+}
+
+impl OpmlOutline {
+    /// Create an `rss` outline for a subscription.
+    #[must_use]
+    pub fn rss(title: String, xml_url: String, html_url: Option<String>) -> Self {
+        Self {
+            text: title.clone(),
+            title: Some(title),
+            kind: Some("rss".to_owned()),
+            xml_url: Some(xml_url),
+            html_url,
+            outlines: Vec::new(),
+        }
+    }
+
+    /// Get the title falling back to the text.
+    #[must_use]
+    pub fn get_title(&self) -> &str {
+        match &self.title {
+            Some(title) if !title.trim().is_empty() => title,
+            _ => &self.text,
+        }
+    }
+}
+
+/// Errors from reading or writing [`Opml`].
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum OpmlError {
+    #[error("Unable to parse OPML")]
+    Parse,
+    #[error("Unable to write OPML")]
+    Write,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OPML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<opml version="2.0">
+  <head><title>Subscriptions</title></head>
+  <body>
+    <outline text="Podcasts">
+      <outline type="rss" text="First Podcast" xmlUrl="https://example.com/first.xml" htmlUrl="https://example.com/first"/>
+      <outline type="rss" text="Second" title="Second Podcast" xmlUrl="https://example.com/second.xml"/>
+    </outline>
+    <outline type="rss" text="Third Podcast" xmlUrl="https://example.com/third.xml"/>
+  </body>
+</opml>"#;
+
+    #[test]
+    fn get_subscriptions() {
+        // Arrange
+        let opml = Opml::parse(OPML).expect("should be able to parse OPML");
+
+        // Act
+        let subscriptions = opml.get_subscriptions();
+
+        // Assert
+        let titles: Vec<&str> = subscriptions.iter().map(|o| o.get_title()).collect();
+        assert_eq!(
+            titles,
+            vec!["First Podcast", "Second Podcast", "Third Podcast"]
+        );
+    }
+
+    #[test]
+    fn write() {
+        // Arrange
+        let opml = Opml::new(
+            Some("Alnwick".to_owned()),
+            vec![OpmlOutline::rss(
+                "Podcast & Friends".to_owned(),
+                "https://example.com/feed.xml".to_owned(),
+                Some("https://example.com/".to_owned()),
+            )],
+        );
+
+        // Act
+        let xml = opml.write().expect("should be able to write OPML");
+
+        // Assert
+        assert_snapshot!(xml);
+        let parsed = Opml::parse(&xml).expect("should be able to parse written OPML");
+        assert_eq!(parsed, opml);
+    }
+}
@@ -58,7 +58,7 @@ impl MockFeeds {
     ///
     /// This is a real URL.
     #[must_use]
-    pub(super) fn episode_file_url() -> UrlWrapper {
+    pub fn episode_file_url() -> UrlWrapper {
         let bytes = BASE64_STANDARD
             .decode(Self::EPISODE_FILE_URL)
             .expect("should be valid base64");
@@ -45,6 +45,7 @@ impl MockFeedsFactory {
                         let ordinal = season_i * 100 + episode * 7;
                         let mut episode = EpisodeInfo {
                             title: format!("S{season:02}E{episode:02} of {slug}"),
+                            source_id: format!("{slug}-s{season:02}e{episode:02}"),
                             published_at: date(year, ordinal),
                             season: Some(season),
                             episode: Some(episode),
@@ -1,15 +1,20 @@
 use crate::prelude::*;
 use tokio::fs::copy;
+use url::Origin;
 
 /// Mock implementation of [`HttpFetch`] for testing.
 ///
 /// Stores URL-to-response mappings for strings and file paths. String
 /// mappings are returned by `get_html` and `get_string`. File mappings
 /// are copied to the destination by `download`.
+#[derive(Clone)]
 pub struct MockHttpClient {
     strings: HashMap<String, String>,
     files: HashMap<String, PathBuf>,
     content_types: HashMap<String, String>,
+    etags: HashMap<String, String>,
+    required_credentials: HashMap<String, PodcastCredentials>,
+    credentials: Option<(Origin, PodcastCredentials)>,
 }
 
 impl MockHttpClient {
@@ -20,6 +25,9 @@ impl MockHttpClient {
             strings: HashMap::new(),
             files: HashMap::new(),
             content_types: HashMap::new(),
+            etags: HashMap::new(),
+            required_credentials: HashMap::new(),
+            credentials: None,
         }
     }
 
@@ -45,13 +53,49 @@ impl MockHttpClient {
         self
     }
 
+    /// Set the `ETag` returned by `get_conditional` for a URL.
+    ///
+    /// Requests with a matching `If-None-Match` receive `304 Not Modified`.
+    #[must_use]
+    pub fn with_etag(mut self, url: &str, etag: &str) -> Self {
+        self.etags.insert(String::from(url), String::from(etag));
+        self
+    }
+
+    /// Require credentials for a URL.
+    ///
+    /// Requests without matching credentials receive `401 Unauthorized`.
+    #[must_use]
+    pub fn with_required_credentials(mut self, url: &str, credentials: PodcastCredentials) -> Self {
+        self.required_credentials
+            .insert(String::from(url), credentials);
+        self
+    }
+
+    fn check_credentials(&self, url: &UrlWrapper) -> Result<(), Report<HttpError>> {
+        let Some(required) = self.required_credentials.get(url.as_str()) else {
+            return Ok(());
+        };
+        let credentials = self
+            .credentials
+            .as_ref()
+            .filter(|(origin, _)| &url.origin() == origin)
+            .map(|(_, credentials)| credentials);
+        if credentials != Some(required) {
+            return Err(Report::new(HttpError::Status(401)).attach_url(url));
+        }
+        Ok(())
+    }
+
     fn get_string_value(&self, url: &UrlWrapper) -> Result<&String, Report<HttpError>> {
+        self.check_credentials(url)?;
         self.strings
             .get(url.as_str())
             .ok_or_else(|| Report::new(HttpError::Request).attach_url(url))
     }
 
     fn get_file_value(&self, url: &UrlWrapper) -> Result<&PathBuf, Report<HttpError>> {
+        self.check_credentials(url)?;
         self.files
             .get(url.as_str())
             .ok_or_else(|| Report::new(HttpError::Request).attach_url(url))
@@ -70,7 +114,30 @@ impl HttpFetch for MockHttpClient {
         Ok(body.clone())
     }
 
+    async fn get_conditional(
+        &self,
+        url: &UrlWrapper,
+        validators: &CacheValidators,
+    ) -> Result<ConditionalResponse, Report<HttpError>> {
+        let etag = self.etags.get(url.as_str());
+        if etag.is_some() && etag == validators.etag.as_ref() {
+            return Ok(ConditionalResponse::NotModified);
+        }
+        let body = self.get_string_value(url)?.clone();
+        let validators = CacheValidators {
+            etag: etag.cloned(),
+            last_modified: None,
+        };
+        Ok(ConditionalResponse::Modified {
+            body,
+            validators,
+            status: 200,
+            url: url.clone(),
+        })
+    }
+
     async fn head(&self, url: &UrlWrapper) -> Result<String, Report<HttpError>> {
+        self.check_credentials(url)?;
         if let Some(content_type) = self.content_types.get(url.as_str()) {
             return Ok(content_type.clone());
         }
@@ -99,4 +166,18 @@ impl HttpFetch for MockHttpClient {
             .attach_path(&destination)?;
         Ok(())
     }
+
+    async fn remove_partial(&self, _destination: &Path) -> Result<(), Report<HttpError>> {
+        Ok(())
+    }
+
+    fn with_credentials(
+        &self,
+        credentials: Option<PodcastCredentials>,
+        feed_url: &UrlWrapper,
+    ) -> Arc<dyn HttpFetch> {
+        let mut client = self.clone();
+        client.credentials = credentials.map(|credentials| (feed_url.origin(), credentials));
+        Arc::new(client)
+    }
 }
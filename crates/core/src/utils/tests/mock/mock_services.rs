@@ -46,6 +46,13 @@ impl MockServices {
         self
     }
 
+    /// Add custom responses to the mock HTTP.
+    #[must_use]
+    pub fn with_http(mut self, edit: impl FnOnce(MockHttpClient) -> MockHttpClient) -> Self {
+        self.mock_http = edit(self.mock_http);
+        self
+    }
+
     /// Prime the mock HTTP with RSS feed data.
     ///
     /// - Generates RSS XML from the first mock podcast
@@ -13,6 +13,15 @@ impl SampleFixtures {
         ))
     }
 
+    /// 1-second AAC in an MP4 container.
+    #[must_use]
+    pub fn m4a() -> PathBuf {
+        PathBuf::from(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/samples/sample.m4a"
+        ))
+    }
+
     /// 1400x1400 solid colour PNG.
     #[must_use]
     pub fn png() -> PathBuf {
@@ -25,6 +25,9 @@ where
     /// Unit displayed at the end of the field.
     #[props(default)]
     pub unit: Option<String>,
+    /// Type of the input element.
+    #[props(default = "text".to_owned())]
+    pub input_type: String,
 }
 
 #[component]
@@ -67,7 +70,7 @@ where
                             validate(input_value);
                         },
                         class: get_class(message),
-                        r#type: "text",
+                        r#type: props.input_type,
                         placeholder: props.placeholder,
                         value: field_value,
                     }
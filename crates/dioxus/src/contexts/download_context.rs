@@ -54,6 +54,28 @@ impl DownloadContext {
             .expect("should be able to queue download");
     }
 
+    /// Set the download status of every job in the persistent queue.
+    ///
+    /// Events are only sent while the page is open so the queue is read first
+    /// to include downloads queued before the page loaded or the server restarted.
+    async fn sync_queue(&mut self) {
+        let jobs = match get_queue().await {
+            Ok(jobs) => jobs,
+            Err(e) => {
+                error!("Failed to get download queue: {e:?}");
+                return;
+            }
+        };
+        for job in jobs {
+            let status = match job.status {
+                DownloadJobStatus::Queued => DownloadStatus::Queued,
+                DownloadJobStatus::Running => DownloadStatus::Downloading,
+                DownloadJobStatus::Failed => DownloadStatus::Failed,
+            };
+            self.set(job.get_request(), status);
+        }
+    }
+
     /// Watch the event stream and update the download status.
     #[allow(irrefutable_let_patterns)]
     pub fn watch_events() {
@@ -65,6 +87,7 @@ impl DownloadContext {
                 return;
             };
             trace!("Started event stream");
+            DownloadContext::consume().sync_queue().await;
             while let Some(result) = stream.next().await {
                 let event = match result {
                     Ok(event) => event,
@@ -109,15 +132,55 @@ pub enum DownloadStatus {
 async fn queue_download(podcast: PodcastKey, episode: EpisodeKey) -> Result<(), ServerFnError> {
     let request = DownloadRequest::new(podcast, episode, false);
     trace!("Adding to queue {request}");
-    let runner = get_runner().await;
-    if let Err(error) = runner.queue_request(request).await {
-        error!("{}", error.render());
-        Err(ServerFnError::new(error.to_string()))
-    } else {
-        Ok(())
+    let queue = get_download_queue().await;
+    match queue.add(request).await {
+        Ok(_) => Ok(()),
+        Err(error) => {
+            error!("{}", error.render());
+            Err(ServerFnError::new(error.to_string()))
+        }
     }
 }
 
+#[get("/api/queue")]
+async fn get_queue() -> Result<Vec<DownloadJob>, ServerFnError> {
+    let queue = get_download_queue().await;
+    queue.get_jobs().await.map_err(|error| {
+        error!("{}", error.render());
+        ServerFnError::new(error.to_string())
+    })
+}
+
+/// Move a job to a position in the download queue.
+#[post("/api/queue/:job/move")]
+pub async fn move_queued_download(job: DownloadJobKey, position: u32) -> Result<(), ServerFnError> {
+    let queue = get_download_queue().await;
+    queue.move_to(job, position).await.map_err(|error| {
+        error!("{}", error.render());
+        ServerFnError::new(error.to_string())
+    })
+}
+
+/// Remove a job that is not running from the download queue.
+#[post("/api/queue/:job/cancel")]
+pub async fn cancel_queued_download(job: DownloadJobKey) -> Result<(), ServerFnError> {
+    let queue = get_download_queue().await;
+    queue.cancel(job).await.map_err(|error| {
+        error!("{}", error.render());
+        ServerFnError::new(error.to_string())
+    })
+}
+
+/// Retry a failed job in the download queue.
+#[post("/api/queue/:job/retry")]
+pub async fn retry_queued_download(job: DownloadJobKey) -> Result<(), ServerFnError> {
+    let queue = get_download_queue().await;
+    queue.retry(job).await.map_err(|error| {
+        error!("{}", error.render());
+        ServerFnError::new(error.to_string())
+    })
+}
+
 #[get("/api/events")]
 #[allow(clippy::unused_async)]
 async fn event_stream() -> Result<Streaming<CommandEvent, JsonEncoding>> {
@@ -1,14 +1,28 @@
 use crate::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::sync::OnceCell;
 use tokio::sync::broadcast::Receiver;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time::{Duration, Instant, MissedTickBehavior, interval, interval_at};
 
 const WORKERS: usize = 4;
 
+/// Seconds between checks for podcasts that are due a scheduled refresh.
+const REFRESH_TICK: u64 = 60;
+
+/// Seconds between applying the retention rules of every podcast.
+const PRUNE_TICK: u64 = 60 * 60;
+
 static SERVICES: OnceCell<Arc<ServiceProvider>> = OnceCell::const_new();
 static RUNNER: OnceCell<Arc<CommandRunner<CommandInfo>>> = OnceCell::const_new();
 static MEDIATOR: OnceCell<Arc<CommandMediator<CommandInfo>>> = OnceCell::const_new();
 static METADATA: OnceCell<Arc<MetadataRepository>> = OnceCell::const_new();
 static ADD_HANDLER: OnceCell<Arc<AddHandler>> = OnceCell::const_new();
+static IMPORT_PLANNER: OnceCell<Arc<ImportPlanner>> = OnceCell::const_new();
+static OPML_EXPORTER: OnceCell<Arc<OpmlExporter>> = OnceCell::const_new();
+static REFRESH_SCHEDULER: OnceCell<Arc<RefreshScheduler>> = OnceCell::const_new();
+static DOWNLOAD_QUEUE: OnceCell<Arc<DownloadQueue>> = OnceCell::const_new();
+static SCHEDULER_STARTED: AtomicBool = AtomicBool::new(false);
 
 /// Build, initialize, and install the shared [`ServiceProvider`].
 ///
@@ -76,3 +90,135 @@ async fn init_add_handler() -> Arc<AddHandler> {
 pub async fn get_add_handler() -> &'static Arc<AddHandler> {
     ADD_HANDLER.get_or_init(init_add_handler).await
 }
+
+async fn init_import_planner() -> Arc<ImportPlanner> {
+    get_services().expect_async::<ImportPlanner>().await
+}
+
+pub async fn get_import_planner() -> &'static Arc<ImportPlanner> {
+    IMPORT_PLANNER.get_or_init(init_import_planner).await
+}
+
+async fn init_opml_exporter() -> Arc<OpmlExporter> {
+    get_services().expect_async::<OpmlExporter>().await
+}
+
+pub async fn get_opml_exporter() -> &'static Arc<OpmlExporter> {
+    OPML_EXPORTER.get_or_init(init_opml_exporter).await
+}
+
+async fn init_refresh_scheduler() -> Arc<RefreshScheduler> {
+    get_services().expect_async::<RefreshScheduler>().await
+}
+
+pub async fn get_refresh_scheduler() -> &'static Arc<RefreshScheduler> {
+    REFRESH_SCHEDULER.get_or_init(init_refresh_scheduler).await
+}
+
+async fn init_download_queue() -> Arc<DownloadQueue> {
+    get_services().expect_async::<DownloadQueue>().await
+}
+
+pub async fn get_download_queue() -> &'static Arc<DownloadQueue> {
+    DOWNLOAD_QUEUE.get_or_init(init_download_queue).await
+}
+
+/// Spawn the background tasks that queue scheduled refreshes and pruning
+/// and that run the persistent download queue.
+///
+/// - Restores the [`DownloadQueue`] then updates it as downloads complete
+/// - Checks for due podcasts every [`REFRESH_TICK`] seconds
+/// - Queues a [`FetchRequest`] on the shared [`CommandRunner`] for each
+/// - Queues a [`PruneRequest`] for every podcast every [`PRUNE_TICK`] seconds,
+///   starting one period after startup
+/// - Only the first call spawns the tasks
+pub fn start_scheduler() {
+    if SCHEDULER_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    tokio::spawn(async move {
+        run_download_queue().await;
+    });
+    tokio::spawn(async move {
+        let scheduler = get_refresh_scheduler().await;
+        let mut ticks = interval(Duration::from_secs(REFRESH_TICK));
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        loop {
+            ticks.tick().await;
+            queue_due_refreshes(scheduler).await;
+        }
+    });
+    tokio::spawn(async move {
+        let period = Duration::from_secs(PRUNE_TICK);
+        let mut ticks = interval_at(Instant::now() + period, period);
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        loop {
+            ticks.tick().await;
+            queue_prunes().await;
+        }
+    });
+}
+
+async fn run_download_queue() {
+    let mut receiver = subscribe_to_events().await;
+    get_runner().await;
+    let queue = get_download_queue().await;
+    if let Err(error) = queue.restore().await {
+        error!("{}", error.render());
+    }
+    loop {
+        let event = match receiver.recv().await {
+            Err(RecvError::Lagged(count)) => {
+                warn!("Download queue missed {count} events due to lagging");
+                continue;
+            }
+            Err(RecvError::Closed) => {
+                error!("Event pipe was closed. Download queue can't proceed.");
+                break;
+            }
+            Ok(event) => event,
+        };
+        if let Err(error) = queue.handle_event(&event).await {
+            error!("{}", error.render());
+        }
+    }
+}
+
+async fn queue_due_refreshes(scheduler: &RefreshScheduler) {
+    let due = match scheduler.take_due(Utc::now().fixed_offset()).await {
+        Ok(due) => due,
+        Err(error) => {
+            error!("{}", error.render());
+            return;
+        }
+    };
+    let runner = get_runner().await;
+    for slug in due {
+        let request = FetchRequest { slug };
+        trace!("Adding to queue {request}");
+        if let Err(error) = runner.queue_request(request).await {
+            error!("{}", error.render());
+        }
+    }
+}
+
+async fn queue_prunes() {
+    let slugs = match get_metadata().await.get_all_podcast_slugs().await {
+        Ok(slugs) => slugs,
+        Err(error) => {
+            error!("{error:?}");
+            return;
+        }
+    };
+    let runner = get_runner().await;
+    for slug in slugs {
+        let request = PruneRequest {
+            slug,
+            dry_run: false,
+        };
+        trace!("Adding to queue {request}");
+        if let Err(error) = runner.queue_request(request).await {
+            error!("{}", error.render());
+        }
+    }
+}
@@ -1,11 +1,22 @@
 use crate::layout::App;
 use crate::prelude::*;
+#[cfg(target_arch = "wasm32")]
 use dioxus::launch;
+#[cfg(not(target_arch = "wasm32"))]
+use dioxus::server::{router, serve};
 
 pub fn start() {
     #[cfg(not(target_arch = "wasm32"))]
-    init_server();
+    {
+        init_server();
+        serve(|| async move {
+            start_scheduler();
+            Ok(router(App))
+        });
+    }
     #[cfg(target_arch = "wasm32")]
-    init_wasm();
-    launch(App);
+    {
+        init_wasm();
+        launch(App);
+    }
 }
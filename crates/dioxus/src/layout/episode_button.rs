@@ -26,9 +26,15 @@ fn PlayButton(request: DownloadRequest, path: PathBuf) -> Element {
     rsx! {
         a { class: "button is-small",
             onclick: move |e: Event<MouseData>| {
-                e.stop_propagation();
-                e.prevent_default();
-                trace!(%request, path = %path.display(), "Play button clicked");
+                let path = path.clone();
+                async move {
+                    e.stop_propagation();
+                    e.prevent_default();
+                    trace!(%request, path = %path.display(), "Play button clicked");
+                    if let Err(error) = mark_played(request.episode).await {
+                        warn!(%request, %error, "Failed to mark episode as played");
+                    }
+                }
             },
             Icon {
                 class: "fa-play",
@@ -77,3 +83,18 @@ fn StatusButton(request: DownloadRequest, status: DownloadStatus) -> Element {
         }
     }
 }
+
+#[post("/api/episodes/:episode/played")]
+async fn mark_played(episode: EpisodeKey) -> Result<(), ServerFnError> {
+    let metadata = get_metadata().await;
+    match metadata
+        .set_played_at(episode, Utc::now().fixed_offset())
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(error) => {
+            error!("{error:?}");
+            Err(ServerFnError::new(error.to_string()))
+        }
+    }
+}
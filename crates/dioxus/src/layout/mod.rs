@@ -2,6 +2,8 @@ mod app;
 mod episode_button;
 mod head;
 mod player;
+mod removed_from_feed_tag;
 pub use app::*;
 pub use episode_button::*;
 pub use head::*;
+pub use removed_from_feed_tag::*;
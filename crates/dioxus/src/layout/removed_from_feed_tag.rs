@@ -0,0 +1,12 @@
+use crate::prelude::*;
+
+/// Tag shown for episodes that are no longer in the podcast feed.
+#[component]
+pub fn RemovedFromFeedTag(removed_at: DateTime<FixedOffset>) -> Element {
+    let title = format!("Removed from feed on {}", removed_at.format("%-d %B %Y"));
+    rsx! {
+        span { class: "tag is-warning", title: "{title}",
+            "Removed from feed"
+        }
+    }
+}
@@ -9,6 +9,8 @@ use crate::prelude::*;
 pub fn AddPodcastPage() -> Element {
     let slug: Signal<Option<Slug>> = use_signal(|| None);
     let url: Signal<Option<UrlWrapper>> = use_signal(|| None);
+    let username: Signal<Option<String>> = use_signal(|| None);
+    let password: Signal<Option<String>> = use_signal(|| None);
     let mut is_loading = use_signal(|| false);
     let mut error_message: Signal<Option<String>> = use_signal(|| None);
     let nav = navigator();
@@ -18,9 +20,17 @@ pub fn AddPodcastPage() -> Element {
         let (Some(slug_val), Some(url_val)) = (slug.cloned(), url.cloned()) else {
             return;
         };
+        let credentials = username
+            .cloned()
+            .map(|username| PodcastCredentials::basic(username, password.cloned()));
+        let request = AddRequest {
+            slug: slug_val.clone(),
+            feed_url: url_val,
+            credentials,
+        };
         is_loading.set(true);
         error_message.set(None);
-        match add_podcast(slug_val.clone(), url_val).await {
+        match add_podcast(request).await {
             Ok(_response) => {
                 nav.push(Route::Podcast { slug: slug_val });
             }
@@ -50,6 +60,21 @@ pub fn AddPodcastPage() -> Element {
                     from_string: url_from_string,
                     to_string: url_to_string,
                 }
+                Field::<String> {
+                    label: "Username",
+                    placeholder: "Optional, for a private feed",
+                    global_value: username,
+                    from_string: text_from_string,
+                    to_string: text_to_string,
+                }
+                Field::<String> {
+                    label: "Password",
+                    placeholder: "Optional, for a private feed",
+                    global_value: password,
+                    from_string: text_from_string,
+                    to_string: text_to_string,
+                    input_type: "password",
+                }
                 if let Some(msg) = error_message.read().as_ref() {
                     article { class: "message is-danger",
                         div { class: "message-body", "{msg}" }
@@ -70,6 +95,9 @@ pub fn AddPodcastPage() -> Element {
                     }
                 }
             }
+            div { class: "block",
+                Link { to: Route::ImportPodcasts, "Import podcasts from an OPML file" }
+            }
         }
     }
 }
@@ -90,9 +118,17 @@ fn url_to_string(value: Option<UrlWrapper>) -> String {
     value.map(|v| v.to_string()).unwrap_or_default()
 }
 
+#[expect(clippy::unnecessary_wraps, reason = "signature is required by Field")]
+fn text_from_string(input: String) -> Result<String, String> {
+    Ok(input)
+}
+
+fn text_to_string(value: Option<String>) -> String {
+    value.unwrap_or_default()
+}
+
 #[post("/api/podcasts")]
-async fn add_podcast(slug: Slug, feed_url: UrlWrapper) -> Result<AddResponse, ServerFnError> {
-    let request = AddRequest { slug, feed_url };
+async fn add_podcast(request: AddRequest) -> Result<AddResponse, ServerFnError> {
     let handler = get_add_handler().await;
     match handler.execute(&request).await {
         Ok(response) => Ok(response),
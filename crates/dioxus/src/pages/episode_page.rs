@@ -81,6 +81,9 @@ fn Episode(episode: EpisodePartial) -> Element {
                     image_src: image,
                     image_size: ImageSize::_128,
                     icon: "fa-image",
+                    if let Some(removed_at) = episode.removed_from_feed_at {
+                        RemovedFromFeedTag { removed_at }
+                    }
                 }
                 if let Some(description) = description {
                     article {
@@ -0,0 +1,185 @@
+use crate::prelude::*;
+#[cfg(feature = "server")]
+use dioxus::fullstack::body::Body;
+#[cfg(feature = "server")]
+use dioxus::fullstack::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use dioxus::fullstack::response::Response;
+
+/// Content type of exported OPML files.
+#[cfg(feature = "server")]
+const OPML_CONTENT_TYPE: &str = "text/x-opml; charset=utf-8";
+
+/// Page for adding podcasts from an OPML file.
+#[expect(
+    clippy::absolute_paths,
+    reason = "generated by the Dioxus #[post] macro"
+)]
+#[component]
+pub fn ImportPodcastsPage() -> Element {
+    let mut opml: Signal<Option<String>> = use_signal(|| None);
+    let mut is_loading = use_signal(|| false);
+    let mut error_message: Signal<Option<String>> = use_signal(|| None);
+    let mut entries: Signal<Vec<ImportEntry>> = use_signal(Vec::new);
+    let on_change = move |event: FormEvent| async move {
+        error_message.set(None);
+        let Some(file) = event.files().into_iter().next() else {
+            opml.set(None);
+            return;
+        };
+        match file.read_string().await {
+            Ok(contents) => opml.set(Some(contents)),
+            Err(e) => {
+                opml.set(None);
+                error_message.set(Some(e.to_string()));
+            }
+        }
+    };
+    let on_submit = move |event: FormEvent| async move {
+        event.prevent_default();
+        let Some(contents) = opml.cloned() else {
+            return;
+        };
+        is_loading.set(true);
+        error_message.set(None);
+        match import_podcasts(contents).await {
+            Ok(response) => entries.set(response),
+            Err(e) => error_message.set(Some(e.to_string())),
+        }
+        is_loading.set(false);
+    };
+    rsx! {
+        Page {
+            title: "Import podcasts",
+            subtitle: "From an OPML file",
+            form {
+                onsubmit: on_submit,
+                div { class: "field",
+                    label { class: "label", "OPML file" }
+                    div { class: "control",
+                        input {
+                            class: "input",
+                            r#type: "file",
+                            accept: ".opml,.xml,text/x-opml,application/xml,text/xml",
+                            onchange: on_change,
+                        }
+                    }
+                }
+                if let Some(msg) = error_message.read().as_ref() {
+                    article { class: "message is-danger",
+                        div { class: "message-body", "{msg}" }
+                    }
+                }
+                div { class: "field",
+                    div { class: "control",
+                        button {
+                            class: "button is-primary",
+                            r#type: "submit",
+                            disabled: opml.read().is_none() || is_loading(),
+                            if is_loading() {
+                                "Importing..."
+                            } else {
+                                "Import Podcasts"
+                            }
+                        }
+                    }
+                }
+            }
+            for entry in entries.read().iter().cloned() {
+                ImportEntryItem { entry }
+            }
+            ExportLinks {}
+        }
+    }
+}
+
+#[component]
+fn ImportEntryItem(entry: ImportEntry) -> Element {
+    let (class, status) = match &entry.status {
+        ImportStatus::Queued(slug) => ("has-text-success", format!("Queued as {slug}")),
+        ImportStatus::Added(slug) => ("has-text-success", format!("Added as {slug}")),
+        ImportStatus::Duplicate(slug) => ("has-text-warning", format!("Duplicate of {slug}")),
+        ImportStatus::Failed(reason) => ("has-text-danger", format!("Failed: {reason}")),
+    };
+    rsx! {
+        div { class: "block",
+            p { class: "has-text-weight-semibold", "{entry.title}" }
+            p { class: "is-size-7", "{entry.feed_url}" }
+            p { class: "is-size-7 {class}", "{status}" }
+        }
+    }
+}
+
+#[component]
+fn ExportLinks() -> Element {
+    rsx! {
+        div { class: "block",
+            p { class: "label", "Export" }
+            div { class: "buttons",
+                a {
+                    class: "button",
+                    href: "/api/export/opml?emulated=false",
+                    download: "alnwick.opml",
+                    "Download OPML"
+                }
+                a {
+                    class: "button",
+                    href: "/api/export/opml?emulated=true",
+                    download: "alnwick-emulated.opml",
+                    "Download OPML of emulated feeds"
+                }
+            }
+        }
+    }
+}
+
+#[post("/api/import")]
+async fn import_podcasts(opml: String) -> Result<Vec<ImportEntry>, ServerFnError> {
+    let planner = get_import_planner().await;
+    let plan = match planner.plan(&opml).await {
+        Ok(plan) => plan,
+        Err(e) => {
+            error!("{}", e.render());
+            return Err(ServerFnError::new(e.to_string()));
+        }
+    };
+    let runner = get_runner().await;
+    let mut entries = plan.entries;
+    for request in plan.requests {
+        trace!("Adding to queue {request}");
+        if let Err(error) = runner.queue_request(request.clone()).await {
+            error!("{}", error.render());
+            let entry = entries
+                .iter_mut()
+                .find(|entry| entry.status == ImportStatus::Queued(request.slug.clone()));
+            if let Some(entry) = entry {
+                entry.status = ImportStatus::Failed(error.to_string());
+            }
+        }
+    }
+    Ok(entries)
+}
+
+#[get("/api/export/opml?emulated")]
+async fn export_opml(emulated: bool) -> Result<Response, ServerFnError> {
+    let exporter = get_opml_exporter().await;
+    let xml = match exporter.export(emulated).await {
+        Ok(xml) => xml,
+        Err(e) => {
+            error!("{}", e.render());
+            return Err(ServerFnError::new(e.to_string()));
+        }
+    };
+    let file_name = if emulated {
+        "alnwick-emulated.opml"
+    } else {
+        "alnwick.opml"
+    };
+    Response::builder()
+        .header(CONTENT_TYPE, OPML_CONTENT_TYPE)
+        .header(
+            CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{file_name}\""),
+        )
+        .body(Body::from(xml))
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
@@ -1,13 +1,17 @@
 pub use add_podcast_page::*;
 pub use episode_page::*;
+pub use import_podcasts_page::*;
 pub use index_page::*;
 pub use player_settings_page::*;
+pub use podcast_history_page::*;
 pub use podcast_page::*;
 pub use settings_page::*;
 
 mod add_podcast_page;
 mod episode_page;
+mod import_podcasts_page;
 mod index_page;
 mod player_settings_page;
+mod podcast_history_page;
 mod podcast_page;
 mod settings_page;
@@ -0,0 +1,94 @@
+use crate::prelude::*;
+
+/// Maximum number of fetches shown on the page.
+const HISTORY_LIMIT: u64 = 50;
+
+/// Page listing the most recent fetches of a podcast and the episode changes they found.
+#[component]
+pub fn PodcastHistoryPage(slug: Slug) -> Element {
+    let slug_clone = slug.clone();
+    let resource = use_resource(move || {
+        let slug_clone = slug_clone.clone();
+        async move { get_fetch_history(slug_clone).await }
+    });
+    match (*resource.read()).clone() {
+        None => rsx! {
+            Page {
+                title: "Loading...",
+            }
+        },
+        Some(Err(error)) => Err(error.into()),
+        Some(Ok(history)) => History(HistoryProps { slug, history }),
+    }
+}
+
+#[component]
+fn History(slug: Slug, history: Vec<FetchHistoryInfo>) -> Element {
+    let subtitle = format!("{} fetches · {slug}", history.len());
+    rsx! {
+        Page {
+            title: "Fetch history",
+            subtitle: subtitle,
+            if history.is_empty() {
+                p { class: "block", "This podcast has not been fetched yet." }
+            }
+            for entry in history {
+                HistoryEntry { entry }
+            }
+        }
+    }
+}
+
+#[component]
+fn HistoryEntry(entry: FetchHistoryInfo) -> Element {
+    let fetched_at = entry.fetched_at.format("%-d %B %Y %H:%M").to_string();
+    let summary = if entry.modified {
+        format!(
+            "{} inserted · {} updated · {} removed",
+            entry.episodes_inserted, entry.episodes_updated, entry.episodes_removed
+        )
+    } else {
+        "Not modified".to_owned()
+    };
+    rsx! {
+        div { class: "block item",
+            p { class: "has-text-weight-semibold",
+                "{fetched_at} · {entry.status}"
+            }
+            if let Some(url) = &entry.url {
+                p { class: "is-size-7", "{url}" }
+            }
+            p { class: "is-size-7", "{summary}" }
+            for change in entry.changes.0 {
+                div { class: "content is-size-7",
+                    p { class: "has-text-weight-semibold", "{change.title}" }
+                    ul {
+                        for field in change.fields {
+                            li { "{format_field_change(&field)}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn format_field_change(change: &FieldChange) -> String {
+    match (change.field, &change.old, &change.new) {
+        (EpisodeField::Description, _, _) => format!("Changed {}", change.field),
+        (_, Some(old), Some(new)) => format!("Changed {} from {old} to {new}", change.field),
+        (_, old, new) => format!("Changed {} from {old:?} to {new:?}", change.field),
+    }
+}
+
+#[get("/api/podcasts/:slug/history")]
+async fn get_fetch_history(slug: Slug) -> Result<Vec<FetchHistoryInfo>, ServerFnError> {
+    let metadata = get_metadata().await;
+    match metadata.get_fetch_history(slug, HISTORY_LIMIT).await {
+        Ok(history) => Ok(history),
+        Err(error) => {
+            error!("{error:?}");
+            Err(ServerFnError::new(error.to_string()))
+        }
+    }
+}
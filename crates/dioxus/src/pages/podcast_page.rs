@@ -68,6 +68,13 @@ fn Podcast(podcast: PodcastPartial, episodes: Vec<EpisodePartial>) -> Element {
                 image_size: ImageSize::_128,
                 icon: "fa-image",
             }
+            div { class: "block",
+                Link { class: "button is-small",
+                    to: Route::PodcastHistory { slug: podcast.slug.clone() },
+                    "Fetch history"
+                }
+            }
+            RefreshStatus { slug: podcast.slug.clone() }
             for episode in episodes {
                 div { class: "block item",
                     Link {
@@ -82,6 +89,9 @@ fn Podcast(podcast: PodcastPartial, episodes: Vec<EpisodePartial>) -> Element {
                             image_src: episode.image.clone().or_else(|| podcast.image.clone()),
                             image_size: ImageSize::_64,
                             icon: "fa-image",
+                            if let Some(removed_at) = episode.removed_from_feed_at {
+                                RemovedFromFeedTag { removed_at }
+                            }
                             EpisodeButton { episode: episode.clone(), podcast: podcast.clone() }
                         }
                     }
@@ -91,6 +101,31 @@ fn Podcast(podcast: PodcastPartial, episodes: Vec<EpisodePartial>) -> Element {
     }
 }
 
+#[component]
+fn RefreshStatus(slug: Slug) -> Element {
+    let resource = use_resource(move || {
+        let slug = slug.clone();
+        async move { get_refresh_schedule(slug).await }
+    });
+    let Some(Ok(Some(schedule))) = (*resource.read()).clone() else {
+        return rsx! {};
+    };
+    rsx! {
+        p { class: "block is-size-7", "{format_schedule(&schedule)}" }
+    }
+}
+
+fn format_schedule(schedule: &RefreshSchedule) -> String {
+    let last = schedule.last_refresh.map_or_else(
+        || "Never refreshed".to_owned(),
+        |last| format!("Last refreshed {}", last.format("%-d %B %Y %H:%M")),
+    );
+    match schedule.next_refresh {
+        Some(next) => format!("{last} · Next refresh {}", next.format("%-d %B %Y %H:%M")),
+        None => format!("{last} · Scheduled refresh disabled"),
+    }
+}
+
 #[get("/api/podcasts/:slug")]
 async fn get_podcast(
     slug: Slug,
@@ -104,3 +139,15 @@ async fn get_podcast(
         }
     }
 }
+
+#[get("/api/podcasts/:slug/refresh")]
+async fn get_refresh_schedule(slug: Slug) -> Result<Option<RefreshSchedule>, ServerFnError> {
+    let scheduler = get_refresh_scheduler().await;
+    match scheduler.get_podcast_schedule(&slug).await {
+        Ok(option) => Ok(option),
+        Err(error) => {
+            error!("{}", error.render());
+            Err(ServerFnError::new(error.to_string()))
+        }
+    }
+}
@@ -7,6 +7,8 @@ pub enum Route {
     Index,
     #[route("/podcasts/:slug")]
     Podcast { slug: Slug },
+    #[route("/podcasts/:slug/history")]
+    PodcastHistory { slug: Slug },
     #[route("/podcasts/:podcast_slug/:episode_key")]
     Episode {
         podcast_slug: Slug,
@@ -18,6 +20,8 @@ pub enum Route {
     PlayerSettings,
     #[route("/add")]
     AddPodcast,
+    #[route("/import")]
+    ImportPodcasts,
 }
 
 impl Route {
@@ -38,6 +42,17 @@ impl Route {
                 breadcrumbs: vec![Route::Index, Route::Podcast { slug: slug.clone() }],
                 path: format!("/podcasts/{slug}"),
             },
+            Route::PodcastHistory { slug } => RouteInfo {
+                title: "History".to_owned(),
+                icon: "fa-clock-rotate-left".to_owned(),
+                previous: Some(Route::Podcast { slug: slug.clone() }),
+                breadcrumbs: vec![
+                    Route::Index,
+                    Route::Podcast { slug: slug.clone() },
+                    Route::PodcastHistory { slug: slug.clone() },
+                ],
+                path: format!("/podcasts/{slug}/history"),
+            },
             Route::Episode {
                 podcast_slug,
                 episode_key,
@@ -77,6 +92,13 @@ impl Route {
                 breadcrumbs: vec![Route::AddPodcast],
                 path: "/add".to_owned(),
             },
+            Route::ImportPodcasts => RouteInfo {
+                title: "Import Podcasts".to_owned(),
+                icon: "fa-file-import".to_owned(),
+                previous: Some(Route::AddPodcast),
+                breadcrumbs: vec![Route::AddPodcast, Route::ImportPodcasts],
+                path: "/import".to_owned(),
+            },
         }
     }
 }
@@ -91,6 +113,11 @@ fn Podcast(slug: Slug) -> Element {
     PodcastPage(PodcastPageProps { slug })
 }
 
+#[component]
+fn PodcastHistory(slug: Slug) -> Element {
+    PodcastHistoryPage(PodcastHistoryPageProps { slug })
+}
+
 #[component]
 fn Episode(podcast_slug: Slug, episode_key: u32) -> Element {
     EpisodePage(EpisodePageProps {
@@ -113,3 +140,8 @@ fn PlayerSettings() -> Element {
 fn AddPodcast() -> Element {
     AddPodcastPage()
 }
+
+#[component]
+fn ImportPodcasts() -> Element {
+    ImportPodcastsPage()
+}